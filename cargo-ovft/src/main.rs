@@ -1,7 +1,15 @@
 use anyhow::{Context, Result};
+use clap::parser::ValueSource;
 use clap::{Arg, ArgMatches, Command};
-use ovft_core::{Config, Tracer};
+use ovft_core::reporters::{
+    AspecExporter, BacklogExporter, ColorChoice, ConsoleReporter, CsvExporter, DeltaReporter,
+    DotExporter, GithubReporter, JsonExporter, JunitReporter, PlantumlExporter, TextDetail,
+    TextReporter, XlsxExporter,
+};
+use ovft_core::core::DefectBaseline;
+use ovft_core::{Config, SpecificationItemId, Tracer};
 use std::env;
+use std::io;
 use std::path::{Path, PathBuf};
 
 fn main() -> Result<()> {
@@ -37,15 +45,32 @@ fn main() -> Result<()> {
                         .long("format")
                         .value_name("FORMAT")
                         .help("Output format")
-                        .value_parser(["html", "json"])
+                        .value_parser([
+                            "html", "json", "aspec", "text", "junit", "dot", "plantuml", "github",
+                            "delta",
+                        ])
                         .default_value("html"),
                 )
                 .arg(
                     Arg::new("verbose")
                         .short('v')
                         .long("verbose")
-                        .help("Enable verbose output")
-                        .action(clap::ArgAction::SetTrue),
+                        .value_name("LEVEL")
+                        .help(
+                            "Enable verbose output; for the text format also the items shown: \
+                             summary, failures (default) or all",
+                        )
+                        .num_args(0..=1)
+                        .value_parser(["summary", "failures", "all"])
+                        .default_missing_value("failures"),
+                )
+                .arg(
+                    Arg::new("color")
+                        .long("color")
+                        .value_name("WHEN")
+                        .help("Colorize the console summary")
+                        .value_parser(["auto", "always", "never"])
+                        .default_value("auto"),
                 )
                 .arg(
                     Arg::new("config")
@@ -54,6 +79,88 @@ fn main() -> Result<()> {
                         .help("Path to configuration file (.ovft.toml)")
                         .required(false),
                 )
+                .arg(
+                    Arg::new("backlog")
+                        .long("backlog")
+                        .value_name("PATH")
+                        .help("Export uncovered items as CSV (.csv) or as an issue template directory")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("export-csv")
+                        .long("export-csv")
+                        .value_name("DIR")
+                        .help("Export all items and links as items.csv and links.csv")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("export-xlsx")
+                        .long("export-xlsx")
+                        .value_name("FILE")
+                        .help("Export a traceability matrix and all items as an Excel workbook")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("export-artifacts")
+                        .long("export-artifacts")
+                        .value_name("FILE")
+                        .help("Export the items for other projects to trace against")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("write-baseline")
+                        .long("write-baseline")
+                        .value_name("FILE")
+                        .help("Accept all current defects in a defect baseline file")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("defect-baseline")
+                        .long("defect-baseline")
+                        .value_name("FILE")
+                        .help("Only fail on defects not listed in the defect baseline file")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("graph-type")
+                        .long("graph-type")
+                        .value_name("TYPE")
+                        .help("Limit the DOT graph to items of an artifact type and their links")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("graph-root")
+                        .long("graph-root")
+                        .value_name("ID")
+                        .help("Limit the DOT graph to an item and the items covering it")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("at-rev")
+                        .long("at-rev")
+                        .value_name("REV")
+                        .help("Trace the files of a git branch, tag or commit instead of the working tree")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("diff-against")
+                        .long("diff-against")
+                        .value_name("REV")
+                        .help("Base revision of the delta format (default: HEAD)")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("no-history")
+                        .long("no-history")
+                        .help("Do not record this run in the run history")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("audit")
+                        .long("audit")
+                        .help("Write a checksum manifest covering the report and trace result")
+                        .action(clap::ArgAction::SetTrue),
+                )
                 .arg(
                     Arg::new("check")
                         .short('c')
@@ -75,9 +182,23 @@ fn run_ovft(matches: &ArgMatches) -> Result<()> {
     let input_dir = matches.get_one::<String>("input").unwrap();
     let output_file = matches.get_one::<String>("output").unwrap();
     let format = matches.get_one::<String>("format").unwrap();
-    let verbose = matches.get_flag("verbose");
+    let detail = matches.get_one::<String>("verbose");
+    let verbose = detail.is_some();
     let check_mode = matches.get_flag("check");
+    let color: ColorChoice = matches.get_one::<String>("color").unwrap().parse()?;
     let config_file = matches.get_one::<String>("config");
+    let backlog = matches.get_one::<String>("backlog");
+    let csv_dir = matches.get_one::<String>("export-csv");
+    let xlsx_path = matches.get_one::<String>("export-xlsx");
+    let artifacts_path = matches.get_one::<String>("export-artifacts");
+    let write_baseline = matches.get_one::<String>("write-baseline");
+    let defect_baseline = matches.get_one::<String>("defect-baseline");
+    let graph_type = matches.get_one::<String>("graph-type");
+    let graph_root = matches.get_one::<String>("graph-root");
+    let no_history = matches.get_flag("no-history");
+    let audit = matches.get_flag("audit");
+    let revision = matches.get_one::<String>("at-rev");
+    let diff_base = matches.get_one::<String>("diff-against");
 
     if verbose {
         println!("🔍 Running OVFT requirements traceability analysis");
@@ -126,60 +247,206 @@ fn run_ovft(matches: &ArgMatches) -> Result<()> {
     
     config.verbose = verbose;
 
+    if no_history {
+        config.history_dir = None;
+    }
+
+    if let Some(defect_baseline) = defect_baseline {
+        config.defect_baseline = Some(PathBuf::from(defect_baseline));
+    }
+    // The new baseline accepts all defects, including the ones of the old baseline
+    if write_baseline.is_some() {
+        config.defect_baseline = None;
+    }
+
+    if audit {
+        config.audit.enabled = true;
+    }
+
     // Run the tracer
     let tracer = Tracer::new(config);
-    let trace_result = tracer
-        .trace()
-        .context("Failed to run requirements traceability analysis")?;
+    let trace_result = match revision {
+        Some(revision) => tracer.trace_at_revision(revision),
+        None => tracer.trace(),
+    }
+    .context("Failed to run requirements traceability analysis")?;
 
     if verbose {
         println!("✅ Analysis complete!");
-        println!("📊 Requirements found: {}", trace_result.items.len());
-        println!("🔗 Total items: {}", trace_result.total_items);
-
-        if trace_result.defect_count > 0 {
-            println!("❌ Defects found: {}", trace_result.defect_count);
-            for defect in &trace_result.defects {
-                println!("   - {:?}: {}", defect.defect_type, defect.description);
-            }
-        }
+        let console = ConsoleReporter::new().with_color(color.enabled(&io::stdout()));
+        print!("{}", console.render(&trace_result));
+    }
 
-        // Print coverage summary
-        for (artifact_type, summary) in &trace_result.coverage_summary {
-            println!(
-                "📊 {}: {}/{} ({:.1}% coverage)",
-                artifact_type, summary.covered, summary.total, summary.percentage
-            );
-        }
+    match tracer.record_history(&trace_result) {
+        Ok(Some(delta)) => print!("📈 {}", delta),
+        Ok(None) => {}
+        Err(e) => eprintln!("⚠️  Could not update run history: {}", e),
     }
 
     // Generate report
-    if format == "html" {
+    let mut report_files = vec![PathBuf::from(output_file)];
+    if format == "text" {
+        let detail: TextDetail = match detail {
+            Some(detail) => detail.parse()?,
+            None => TextDetail::default(),
+        };
+        let reporter = TextReporter::new(detail);
+        // Without an explicit --output, the text report goes to stdout
+        if matches.value_source("output") == Some(ValueSource::CommandLine) {
+            reporter
+                .write(&trace_result, Path::new(output_file))
+                .context("Failed to generate text report")?;
+            println!("📄 Text report generated: {}", output_file);
+        } else {
+            print!("{}", reporter.render(&trace_result));
+            report_files.clear();
+        }
+    } else if format == "delta" {
+        let base = diff_base.map(String::as_str).unwrap_or("HEAD");
+        let previous = tracer
+            .trace_at_revision(base)
+            .with_context(|| format!("Failed to trace {}", base))?;
+        let diff = trace_result.diff(&previous);
+        let reporter = DeltaReporter::new().with_language(tracer.config().report_language);
+        // Without an explicit --output, the delta report goes to stdout, e.g. for a PR comment
+        if matches.value_source("output") == Some(ValueSource::CommandLine) {
+            reporter
+                .write(&diff, Path::new(output_file))
+                .context("Failed to generate delta report")?;
+            println!("📄 Delta report generated: {}", output_file);
+        } else {
+            print!("{}", reporter.render(&diff));
+            report_files.clear();
+        }
+    } else if format == "github" {
+        let reporter = GithubReporter::new();
+        // Without an explicit --output, the annotations go to stdout for the workflow runner
+        if matches.value_source("output") == Some(ValueSource::CommandLine) {
+            reporter
+                .write(&trace_result, Path::new(output_file))
+                .context("Failed to generate GitHub annotations")?;
+            println!("📄 GitHub annotations generated: {}", output_file);
+        } else {
+            print!("{}", reporter.render(&trace_result));
+            report_files.clear();
+        }
+    } else if format == "html" {
         let output_path = PathBuf::from(output_file);
         tracer
             .generate_html_report(&trace_result, &output_path)
             .context("Failed to generate HTML report")?;
         println!("📄 HTML report generated: {}", output_file);
+
+        for path in tracer
+            .generate_compliance_reports(&trace_result)
+            .context("Failed to generate compliance matrices")?
+        {
+            println!("📄 Compliance matrix generated: {}", path.display());
+            report_files.push(path);
+        }
+    } else if format == "dot" {
+        let mut exporter = DotExporter::new();
+        if let Some(graph_type) = graph_type {
+            exporter = exporter.with_artifact_type(graph_type.clone());
+        }
+        if let Some(graph_root) = graph_root {
+            exporter = exporter.with_root(
+                SpecificationItemId::parse(graph_root).context("Invalid --graph-root")?,
+            );
+        }
+        exporter
+            .write(&trace_result, Path::new(output_file))
+            .context("Failed to generate DOT graph")?;
+        println!("📄 DOT graph generated: {}", output_file);
+    } else if format == "plantuml" {
+        PlantumlExporter::new()
+            .write(&trace_result, Path::new(output_file))
+            .context("Failed to generate PlantUML diagram")?;
+        println!("📄 PlantUML diagram generated: {}", output_file);
+    } else if format == "junit" {
+        JunitReporter::new()
+            .write(&trace_result, Path::new(output_file))
+            .context("Failed to generate JUnit report")?;
+        println!("📄 JUnit report generated: {}", output_file);
+    } else if format == "aspec" {
+        AspecExporter::new()
+            .write(&trace_result, Path::new(output_file))
+            .context("Failed to generate aspec export")?;
+        println!("📄 aspec export generated: {}", output_file);
     } else {
-        // For JSON format, output the trace result data
-        let json_data = serde_json::json!({
-            "total_items": trace_result.total_items,
-            "defect_count": trace_result.defect_count,
-            "defects": trace_result.defects,
-            "coverage_summary": trace_result.coverage_summary,
-            "is_success": trace_result.is_success,
-            "coverage_percentage": trace_result.coverage_percentage()
-        });
-
-        let json = serde_json::to_string_pretty(&json_data)
-            .context("Failed to serialize result to JSON")?;
-        std::fs::write(output_file, json).context("Failed to write JSON output")?;
+        // For JSON format, export the summary and all linked items
+        JsonExporter::new()
+            .write(&trace_result, Path::new(output_file))
+            .context("Failed to write JSON output")?;
         println!("📄 JSON report generated: {}", output_file);
     }
 
+    if let Some(manifest) = tracer
+        .write_audit_manifest(&trace_result, &report_files)
+        .context("Failed to write audit manifest")?
+    {
+        println!("🔏 Audit manifest written: {}", manifest.display());
+    }
+
+    if let Some(backlog) = backlog {
+        let report_url = Path::new(output_file)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let exporter = BacklogExporter::new(report_url);
+        let backlog_path = Path::new(backlog);
+        if backlog_path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+        {
+            exporter.write_csv(&trace_result, backlog_path)
+        } else {
+            exporter.write_issue_bundle(&trace_result, backlog_path)
+        }
+        .context("Failed to export backlog")?;
+        println!("📋 Backlog exported: {}", backlog);
+    }
+
+    if let Some(csv_dir) = csv_dir {
+        CsvExporter::new()
+            .write(&trace_result, Path::new(csv_dir))
+            .context("Failed to export CSV")?;
+        println!("📋 Items and links exported: {}", csv_dir);
+    }
+
+    if let Some(xlsx_path) = xlsx_path {
+        XlsxExporter::new()
+            .write(&trace_result, Path::new(xlsx_path))
+            .context("Failed to export Excel workbook")?;
+        println!("📋 Traceability workbook exported: {}", xlsx_path);
+    }
+
+    if let Some(artifacts_path) = artifacts_path {
+        tracer
+            .export_artifacts(&trace_result, Path::new(artifacts_path))
+            .context("Failed to export artifacts")?;
+        println!("📋 Artifacts exported: {}", artifacts_path);
+    }
+
+    if let Some(baseline_path) = write_baseline {
+        DefectBaseline::from_defects(&trace_result.defects)
+            .save(baseline_path)
+            .context("Failed to write defect baseline")?;
+        println!(
+            "📋 Defect baseline with {} accepted defects written: {}",
+            trace_result.defect_count, baseline_path
+        );
+        return Ok(());
+    }
+
     // Check mode: exit with error if issues found
     if check_mode {
-        if trace_result.defect_count > 0 {
+        if !trace_result.is_success {
+            // Without --verbose, the summary has not been shown yet
+            if !verbose {
+                let console = ConsoleReporter::new().with_color(color.enabled(&io::stderr()));
+                eprint!("{}", console.render(&trace_result));
+            }
             eprintln!(
                 "❌ Found {} defects in requirements traceability",
                 trace_result.defect_count
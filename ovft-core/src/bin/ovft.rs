@@ -1,6 +1,14 @@
-use ovft_core::{Config, Tracer};
+use ovft_core::core::{Baseline, DefectBaseline, Explanation, Renamer};
+use ovft_core::reporters::{
+    AspecExporter, BacklogExporter, ColorChoice, ConsoleReporter, CsvExporter, DeltaReporter,
+    DotExporter, GithubReporter, JsonExporter, JunitReporter, PlantumlExporter, TextDetail,
+    TextReporter, XlsxExporter,
+};
+use ovft_core::importers::InputFormat;
+use ovft_core::{Config, SourceDir, SpecificationItemId, Tracer};
 use std::env;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::process;
 
 fn main() {
@@ -13,10 +21,38 @@ fn main() {
 
     let mut source_dirs = Vec::new();
     let mut spec_dirs = Vec::new();
-    let mut output_path = PathBuf::from("requirements_report.html");
+    let mut source_files = Vec::new();
+    let mut spec_files = Vec::new();
+    let mut output_path: Option<PathBuf> = None;
+    let mut format = String::from("html");
+    let mut detail = TextDetail::default();
+    let mut color = ColorChoice::default();
     let mut config_file = None;
+    let mut dry_run = false;
+    let mut no_history = false;
+    let mut audit = false;
+    let mut offline = false;
+    let mut revision: Option<String> = None;
+    let mut diff_base: Option<String> = None;
+    let mut backlog_path: Option<PathBuf> = None;
+    let mut csv_dir: Option<PathBuf> = None;
+    let mut xlsx_path: Option<PathBuf> = None;
+    let mut artifacts_path: Option<PathBuf> = None;
+    let mut defect_baseline: Option<PathBuf> = None;
+    let mut write_baseline: Option<PathBuf> = None;
+    let mut graph_type: Option<String> = None;
+    let mut graph_root: Option<SpecificationItemId> = None;
+    let mut read_stdin = false;
+    let mut spec_format: Option<InputFormat> = None;
+    let mut positional = Vec::new();
 
-    let mut i = 1;
+    // Optional subcommand as first argument
+    let command = match args[1].as_str() {
+        "rename" | "explain" | "show" | "freeze" => Some(args[1].clone()),
+        _ => None,
+    };
+
+    let mut i = if command.is_some() { 2 } else { 1 };
     while i < args.len() {
         match args[i].as_str() {
             "--source-dirs" => {
@@ -37,15 +73,108 @@ fn main() {
                     process::exit(1);
                 }
             }
+            "--source-files" => {
+                if i + 1 < args.len() {
+                    source_files = args[i + 1].split(',').map(PathBuf::from).collect();
+                    i += 2;
+                } else {
+                    eprintln!("Error: --source-files requires a value");
+                    process::exit(1);
+                }
+            }
+            "--spec-files" => {
+                if i + 1 < args.len() {
+                    spec_files = args[i + 1].split(',').map(PathBuf::from).collect();
+                    i += 2;
+                } else {
+                    eprintln!("Error: --spec-files requires a value");
+                    process::exit(1);
+                }
+            }
+            "--at-rev" => {
+                if i + 1 < args.len() {
+                    revision = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --at-rev requires a value");
+                    process::exit(1);
+                }
+            }
+            "--diff-against" => {
+                if i + 1 < args.len() {
+                    diff_base = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --diff-against requires a value");
+                    process::exit(1);
+                }
+            }
             "--output" => {
                 if i + 1 < args.len() {
-                    output_path = PathBuf::from(&args[i + 1]);
+                    output_path = Some(PathBuf::from(&args[i + 1]));
                     i += 2;
                 } else {
                     eprintln!("Error: --output requires a value");
                     process::exit(1);
                 }
             }
+            "--format" => {
+                if i + 1 < args.len() {
+                    format = args[i + 1].clone();
+                    if !matches!(
+                        format.as_str(),
+                        "html"
+                            | "aspec"
+                            | "text"
+                            | "junit"
+                            | "json"
+                            | "dot"
+                            | "plantuml"
+                            | "github"
+                            | "delta"
+                    ) {
+                        eprintln!(
+                            "Error: --format must be 'html', 'aspec', 'text', 'junit', \
+                             'json', 'dot', 'plantuml', 'github' or 'delta'"
+                        );
+                        process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: --format requires a value");
+                    process::exit(1);
+                }
+            }
+            "-v" | "--verbosity" => {
+                if i + 1 < args.len() {
+                    detail = match args[i + 1].parse() {
+                        Ok(detail) => detail,
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: {} requires a value", args[i]);
+                    process::exit(1);
+                }
+            }
+            "--color" => {
+                if i + 1 < args.len() {
+                    color = match args[i + 1].parse() {
+                        Ok(color) => color,
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: --color requires a value");
+                    process::exit(1);
+                }
+            }
             "--config" => {
                 if i + 1 < args.len() {
                     config_file = Some(PathBuf::from(&args[i + 1]));
@@ -55,10 +184,136 @@ fn main() {
                     process::exit(1);
                 }
             }
+            "--backlog" => {
+                if i + 1 < args.len() {
+                    backlog_path = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --backlog requires a value");
+                    process::exit(1);
+                }
+            }
+            "--export-csv" => {
+                if i + 1 < args.len() {
+                    csv_dir = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --export-csv requires a value");
+                    process::exit(1);
+                }
+            }
+            "--defect-baseline" => {
+                if i + 1 < args.len() {
+                    defect_baseline = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --defect-baseline requires a value");
+                    process::exit(1);
+                }
+            }
+            "--write-baseline" => {
+                if i + 1 < args.len() {
+                    write_baseline = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --write-baseline requires a value");
+                    process::exit(1);
+                }
+            }
+            "--export-xlsx" => {
+                if i + 1 < args.len() {
+                    xlsx_path = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --export-xlsx requires a value");
+                    process::exit(1);
+                }
+            }
+            "--export-artifacts" => {
+                if i + 1 < args.len() {
+                    artifacts_path = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --export-artifacts requires a value");
+                    process::exit(1);
+                }
+            }
+            "--graph-type" => {
+                if i + 1 < args.len() {
+                    graph_type = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --graph-type requires a value");
+                    process::exit(1);
+                }
+            }
+            "--graph-root" => {
+                if i + 1 < args.len() {
+                    graph_root = match SpecificationItemId::parse(&args[i + 1]) {
+                        Ok(id) => Some(id),
+                        Err(e) => {
+                            eprintln!("Error: invalid --graph-root: {}", e);
+                            process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: --graph-root requires a value");
+                    process::exit(1);
+                }
+            }
+            "--spec-file" => {
+                if i + 1 < args.len() {
+                    if args[i + 1] != "-" {
+                        eprintln!("Error: --spec-file only supports '-'; see --spec-files");
+                        process::exit(1);
+                    }
+                    read_stdin = true;
+                    i += 2;
+                } else {
+                    eprintln!("Error: --spec-file requires a value");
+                    process::exit(1);
+                }
+            }
+            "--spec-format" => {
+                if i + 1 < args.len() {
+                    spec_format = match args[i + 1].parse() {
+                        Ok(format) => Some(format),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: --spec-format requires a value");
+                    process::exit(1);
+                }
+            }
+            "--dry-run" => {
+                dry_run = true;
+                i += 1;
+            }
+            "--audit" => {
+                audit = true;
+                i += 1;
+            }
+            "--offline" => {
+                offline = true;
+                i += 1;
+            }
+            "--no-history" => {
+                no_history = true;
+                i += 1;
+            }
             "--help" => {
                 print_help(&args[0]);
                 process::exit(0);
             }
+            arg if command.is_some() && !arg.starts_with("--") => {
+                positional.push(arg.to_string());
+                i += 1;
+            }
             _ => {
                 eprintln!("Error: Unknown option '{}'", args[i]);
                 process::exit(1);
@@ -66,6 +321,32 @@ fn main() {
         }
     }
 
+    if read_stdin && revision.is_some() {
+        eprintln!("Error: --spec-file - cannot be combined with --at-rev");
+        process::exit(1);
+    }
+
+    if diff_base.is_some() && format != "delta" {
+        eprintln!("Error: --diff-against requires --format delta");
+        process::exit(1);
+    }
+
+    // Without --output, the text report, GitHub annotations and delta report go to stdout
+    let print_text = format == "text" && output_path.is_none();
+    let print_annotations = format == "github" && output_path.is_none();
+    let print_delta = format == "delta" && output_path.is_none();
+    let output_path = output_path.unwrap_or_else(|| match format.as_str() {
+        "aspec" => PathBuf::from("requirements.aspec"),
+        "text" => PathBuf::from("requirements_report.txt"),
+        "junit" => PathBuf::from("requirements-junit.xml"),
+        "json" => PathBuf::from("requirements.json"),
+        "dot" => PathBuf::from("requirements.dot"),
+        "plantuml" => PathBuf::from("requirements.puml"),
+        "github" => PathBuf::from("requirements-annotations.txt"),
+        "delta" => PathBuf::from("requirements-delta.md"),
+        _ => PathBuf::from("requirements_report.html"),
+    });
+
     // Load configuration - either from specified file, auto-discover .ovft.toml, or use defaults
     let mut config = if let Some(config_path) = config_file {
         match Config::from_file(&config_path) {
@@ -88,22 +369,69 @@ fn main() {
 
     // Override configuration with command line arguments
     if !source_dirs.is_empty() {
-        config.source_dirs = source_dirs;
+        config.source_dirs = source_dirs.into_iter().map(SourceDir::new).collect();
     }
     
     if !spec_dirs.is_empty() {
         config.spec_dirs = spec_dirs;
     }
     
+    if !source_files.is_empty() {
+        config.source_files = source_files;
+    }
+
+    if !spec_files.is_empty() {
+        config.spec_files = spec_files;
+    }
+
     if let Some(output_parent) = output_path.parent() {
         config.output_dir = Some(output_parent.to_path_buf());
     }
 
+    if no_history {
+        config.history_dir = None;
+    }
+
+    if let Some(defect_baseline) = defect_baseline {
+        config.defect_baseline = Some(defect_baseline);
+    }
+    // The new baseline accepts all defects, including the ones of the old baseline
+    if write_baseline.is_some() {
+        config.defect_baseline = None;
+    }
+
+    if audit {
+        config.audit.enabled = true;
+    }
+
+    if offline {
+        config.offline = true;
+    }
+
+    if let Some(command) = command {
+        match command.as_str() {
+            "rename" => run_rename(&config, &positional, dry_run),
+            "explain" => run_explain(config, &positional),
+            "show" => run_show(config, &positional, &format),
+            "freeze" => run_freeze(config, &positional),
+            _ => unreachable!(),
+        }
+        return;
+    }
+
     // Create tracer and run analysis
     let tracer = Tracer::new(config);
 
     println!("Running requirements tracing...");
-    let trace_result = match tracer.trace() {
+    let trace_result = if read_stdin {
+        let format = spec_format.unwrap_or(InputFormat::Markdown);
+        tracer.trace_with_reader(io::stdin().lock(), format)
+    } else if let Some(revision) = &revision {
+        tracer.trace_at_revision(revision)
+    } else {
+        tracer.trace()
+    };
+    let trace_result = match trace_result {
         Ok(result) => result,
         Err(e) => {
             eprintln!("Error during tracing: {}", e);
@@ -111,53 +439,480 @@ fn main() {
         }
     };
 
-    // Print summary
-    println!("Found {} items", trace_result.total_items);
-    println!("Defects: {}", trace_result.defect_count);
-    println!("Success: {}", trace_result.is_success);
+    // Print summary; the text report lists items and defects itself
+    let console = ConsoleReporter::new()
+        .with_color(color.enabled(&io::stdout()))
+        .with_defects(format != "text");
+    print!("{}", console.render(&trace_result));
+
+    match tracer.record_history(&trace_result) {
+        Ok(Some(delta)) => print!("\n{}", delta),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: could not update run history: {}", e),
+    }
 
-    if trace_result.defect_count > 0 {
-        println!("\nDefects found:");
-        for defect in &trace_result.defects {
-            println!("  - {:?}: {}", defect.defect_type, defect.description);
+    let mut report_files = vec![output_path.clone()];
+    if format == "delta" {
+        let base = diff_base.as_deref().unwrap_or("HEAD");
+        let diff = match tracer.trace_at_revision(base) {
+            Ok(previous) => trace_result.diff(&previous),
+            Err(e) => {
+                eprintln!("Error tracing {}: {}", base, e);
+                process::exit(1);
+            }
+        };
+        let reporter = DeltaReporter::new().with_language(tracer.config().report_language);
+        if print_delta {
+            print!("\n{}", reporter.render(&diff));
+            report_files.clear();
+        } else {
+            if let Err(e) = reporter.write(&diff, &output_path) {
+                eprintln!("Error generating delta report: {}", e);
+                process::exit(1);
+            }
+            println!("Delta report generated at {}", output_path.display());
         }
+    } else if print_text {
+        print!("\n{}", TextReporter::new(detail).render(&trace_result));
+        report_files.clear();
+    } else if print_annotations {
+        print!("{}", GithubReporter::new().render(&trace_result));
+        report_files.clear();
+    } else if format == "github" {
+        if let Err(e) = GithubReporter::new().write(&trace_result, &output_path) {
+            eprintln!("Error generating GitHub annotations: {}", e);
+            process::exit(1);
+        }
+        println!("GitHub annotations generated at {}", output_path.display());
+    } else if format == "text" {
+        if let Err(e) = TextReporter::new(detail).write(&trace_result, &output_path) {
+            eprintln!("Error generating text report: {}", e);
+            process::exit(1);
+        }
+        println!("Text report generated at {}", output_path.display());
+    } else if format == "json" {
+        if let Err(e) = JsonExporter::new().write(&trace_result, &output_path) {
+            eprintln!("Error generating JSON export: {}", e);
+            process::exit(1);
+        }
+        println!("JSON export generated at {}", output_path.display());
+    } else if format == "dot" {
+        let mut exporter = DotExporter::new();
+        if let Some(graph_type) = graph_type {
+            exporter = exporter.with_artifact_type(graph_type);
+        }
+        if let Some(graph_root) = graph_root {
+            exporter = exporter.with_root(graph_root);
+        }
+        if let Err(e) = exporter.write(&trace_result, &output_path) {
+            eprintln!("Error generating DOT graph: {}", e);
+            process::exit(1);
+        }
+        println!("DOT graph generated at {}", output_path.display());
+    } else if format == "plantuml" {
+        if let Err(e) = PlantumlExporter::new().write(&trace_result, &output_path) {
+            eprintln!("Error generating PlantUML diagram: {}", e);
+            process::exit(1);
+        }
+        println!("PlantUML diagram generated at {}", output_path.display());
+    } else if format == "junit" {
+        if let Err(e) = JunitReporter::new().write(&trace_result, &output_path) {
+            eprintln!("Error generating JUnit report: {}", e);
+            process::exit(1);
+        }
+        println!("JUnit report generated at {}", output_path.display());
+    } else if format == "aspec" {
+        println!("Generating aspec export at {}...", output_path.display());
+        if let Err(e) = AspecExporter::new().write(&trace_result, &output_path) {
+            eprintln!("Error generating aspec export: {}", e);
+            process::exit(1);
+        }
+    } else {
+        // Generate HTML report
+        println!("Generating HTML report at {}...", output_path.display());
+        if let Err(e) = tracer.generate_html_report(&trace_result, &output_path) {
+            eprintln!("Error generating HTML report: {}", e);
+            process::exit(1);
+        }
+
+        println!("HTML report generated successfully!");
+
+        match tracer.generate_compliance_reports(&trace_result) {
+            Ok(paths) => {
+                for path in &paths {
+                    println!("Compliance matrix generated at {}", path.display());
+                }
+                report_files.extend(paths);
+            }
+            Err(e) => {
+                eprintln!("Error generating compliance matrices: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    match tracer.write_audit_manifest(&trace_result, &report_files) {
+        Ok(Some(manifest)) => println!("Audit manifest written to {}", manifest.display()),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Error writing audit manifest: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if let Some(backlog_path) = backlog_path {
+        write_backlog(&trace_result, &output_path, &backlog_path);
     }
 
-    // Generate HTML report
-    println!("Generating HTML report at {}...", output_path.display());
-    if let Err(e) = tracer.generate_html_report(&trace_result, &output_path) {
-        eprintln!("Error generating HTML report: {}", e);
+    if let Some(csv_dir) = csv_dir {
+        match CsvExporter::new().write(&trace_result, &csv_dir) {
+            Ok(()) => println!("Items and links exported to {}", csv_dir.display()),
+            Err(e) => {
+                eprintln!("Error exporting CSV: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Some(xlsx_path) = xlsx_path {
+        match XlsxExporter::new().write(&trace_result, &xlsx_path) {
+            Ok(()) => println!("Traceability workbook exported to {}", xlsx_path.display()),
+            Err(e) => {
+                eprintln!("Error exporting workbook: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Some(artifacts_path) = artifacts_path {
+        match tracer.export_artifacts(&trace_result, &artifacts_path) {
+            Ok(()) => println!("Artifacts exported to {}", artifacts_path.display()),
+            Err(e) => {
+                eprintln!("Error exporting artifacts: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Some(baseline_path) = &write_baseline {
+        match DefectBaseline::from_defects(&trace_result.defects).save(baseline_path) {
+            Ok(()) => println!(
+                "Defect baseline with {} accepted defects written to {}",
+                trace_result.defect_count,
+                baseline_path.display()
+            ),
+            Err(e) => {
+                eprintln!("Error writing defect baseline: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if !trace_result.is_success {
+        process::exit(1); // Exit with error code if defects found or gates failed
+    }
+}
+
+/// Export uncovered items as CSV (for `.csv` paths) or as an issue template bundle directory
+fn write_backlog(trace_result: &ovft_core::TraceResult, report_path: &Path, backlog_path: &Path) {
+    let report_url = report_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let exporter = BacklogExporter::new(report_url);
+
+    let is_csv = backlog_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let result = if is_csv {
+        exporter.write_csv(trace_result, backlog_path)
+    } else {
+        exporter.write_issue_bundle(trace_result, backlog_path)
+    };
+
+    match result {
+        Ok(()) => println!("Backlog exported to {}", backlog_path.display()),
+        Err(e) => {
+            eprintln!("Error exporting backlog: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Trace the project and explain the state of a single item
+fn run_explain(config: Config, positional: &[String]) {
+    if positional.len() != 1 {
+        eprintln!("Error: explain requires <item-id>");
         process::exit(1);
     }
 
-    println!("HTML report generated successfully!");
+    let id = match SpecificationItemId::parse(&positional[0]) {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
 
-    if trace_result.defect_count > 0 {
-        process::exit(1); // Exit with error code if defects found
+    let trace_result = match Tracer::new(config).trace() {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error during tracing: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match Explanation::for_item(&trace_result, &id) {
+        Ok(explanation) => print!("{}", explanation),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Print the coverage chain below an item as a tree, or as JSON with `--format json`
+fn run_show(config: Config, positional: &[String], format: &str) {
+    if positional.len() != 1 {
+        eprintln!("Error: show requires <item-id>");
+        process::exit(1);
+    }
+
+    let id = match SpecificationItemId::parse(&positional[0]) {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let trace_result = match Tracer::new(config).trace() {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error during tracing: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let chain = match trace_result.chain(&id) {
+        Ok(chain) => chain,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+    if format == "json" {
+        match serde_json::to_string_pretty(&chain) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+    } else {
+        print!("{}", chain);
+    }
+}
+
+/// Trace the project and freeze all approved items in a baseline file
+fn run_freeze(config: Config, positional: &[String]) {
+    let baseline_path = match (positional.first(), &config.baseline_file) {
+        (Some(path), _) => PathBuf::from(path),
+        (None, Some(path)) => path.clone(),
+        (None, None) => {
+            eprintln!("Error: freeze requires a baseline path or baseline_file in the configuration");
+            process::exit(1);
+        }
+    };
+
+    let trace_result = match Tracer::new(config).trace() {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error during tracing: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let baseline = Baseline::from_items(&trace_result.items);
+    if let Err(e) = baseline.save(&baseline_path) {
+        eprintln!("Error writing baseline: {}", e);
+        process::exit(1);
+    }
+    println!(
+        "Froze {} approved item(s) in {}",
+        baseline.items.len(),
+        baseline_path.display()
+    );
+}
+
+/// Rename a specification item in all spec and source files
+fn run_rename(config: &Config, positional: &[String], dry_run: bool) {
+    if positional.len() != 2 {
+        eprintln!("Error: rename requires <old-id> and <new-id>");
+        process::exit(1);
+    }
+
+    let parse_id = |id: &str| match SpecificationItemId::parse(id) {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+    let old_id = parse_id(&positional[0]);
+    let new_id = parse_id(&positional[1]);
+
+    let renamer = Renamer::new(config);
+    let result = if dry_run {
+        renamer.plan(&old_id, &new_id)
+    } else {
+        renamer.rename(&old_id, &new_id)
+    };
+
+    let edits = match result {
+        Ok(edits) => edits,
+        Err(e) => {
+            eprintln!("Error during rename: {}", e);
+            process::exit(1);
+        }
+    };
+
+    for edit in &edits {
+        print!("{}", edit);
+    }
+
+    let line_count: usize = edits.iter().map(|edit| edit.changes.len()).sum();
+    if dry_run {
+        println!(
+            "Dry run: {} line(s) in {} file(s) would be changed",
+            line_count,
+            edits.len()
+        );
+    } else {
+        println!(
+            "Renamed {} to {} in {} line(s) across {} file(s)",
+            old_id,
+            new_id,
+            line_count,
+            edits.len()
+        );
     }
 }
 
 fn print_usage(program_name: &str) {
-    println!("Usage: {} [OPTIONS]", program_name);
+    println!("Usage: {} [COMMAND] [OPTIONS]", program_name);
+    println!("Commands:");
+    println!("  rename <old-id> <new-id>   Rename an item in all spec and source files");
+    println!("  explain <item-id>          Explain why an item is (not) defective");
+    println!("  show <item-id>             Print the coverage chain below an item");
+    println!("  freeze [baseline-file]     Freeze all approved items in a baseline file");
     println!("Options:");
     println!("  --source-dirs <dirs>   Source directories to scan (comma separated)");
     println!("  --spec-dirs <dirs>     Specification directories to scan (comma separated)");
-    println!("  --output <file>        Output HTML file path");
+    println!("  --source-files <files> Individual source files to scan (comma separated)");
+    println!("  --spec-files <files>   Individual specification files to read (comma separated)");
+    println!("  --spec-file -          Also read specification content from stdin");
+    println!("  --spec-format <format> Format of stdin content (default: markdown)");
+    println!("  --at-rev <rev>         Trace the files of a git revision instead of the working tree");
+    println!("  --output <file>        Output file path");
+    println!("  --format <format>      Output format: html, aspec, text, junit, json, dot,");
+    println!("                         plantuml, github or delta");
+    println!("  --diff-against <rev>   Base revision of the delta report (default: HEAD)");
+    println!("  -v, --verbosity <level> Detail of the text report: summary, failures or all");
+    println!("  --color <when>         Colorize the console summary: auto, always or never");
     println!("  --config <file>        Path to configuration file (.ovft.toml)");
+    println!("  --backlog <path>       Export uncovered items (.csv file or issue template directory)");
+    println!("  --export-csv <dir>     Export all items and links as items.csv and links.csv");
+    println!("  --export-xlsx <file>   Export a traceability matrix as an Excel workbook");
+    println!("  --export-artifacts <file> Export the items for other projects to trace against");
+    println!("  --write-baseline <file> Accept all current defects in a defect baseline file");
+    println!("  --defect-baseline <file> Only fail on defects not listed in the defect baseline");
+    println!("  --graph-type <type>    Limit the DOT graph to items of an artifact type");
+    println!("  --graph-root <id>      Limit the DOT graph to the coverage tree of an item");
+    println!("  --dry-run              Show changes without writing files (rename)");
+    println!("  --no-history           Do not record this run in the run history");
+    println!("  --offline              Use cached copies of remote spec documents");
+    println!("  --audit                Write a checksum manifest for the report");
     println!("  --help                 Show this help message");
 }
 
 fn print_help(program_name: &str) {
     println!("Open Very Fast Trace - Requirements Tracing Tool");
     println!();
-    println!("Usage: {} [OPTIONS]", program_name);
+    println!("Usage: {} [COMMAND] [OPTIONS]", program_name);
+    println!();
+    println!("Commands:");
+    println!("  rename <old-id> <new-id>   Rewrite the item definition, covers references and code tags");
+    println!("                             (use --dry-run to only print the diff)");
+    println!("  explain <item-id>          Print the needs of an item, which items satisfy them,");
+    println!("                             and which links are broken and why");
+    println!("  show <item-id>             Print the items covering an item directly or indirectly");
+    println!("                             as a tree with link statuses (--format json for JSON)");
+    println!("  freeze [baseline-file]     Record fingerprints of all approved items; once baseline_file");
+    println!("                             is configured, changing a frozen item without a revision");
+    println!("                             bump is reported as a defect");
     println!();
     println!("Options:");
     println!("  --source-dirs <dirs>   Source directories to scan (comma separated)");
     println!("  --spec-dirs <dirs>     Specification directories to scan (comma separated)");
-    println!("  --output <file>        Output HTML file path (default: requirements_report.html)");
+    println!("  --source-files <files> Individual source files to scan in addition to the source");
+    println!("                         directories, e.g. the files changed in a build (comma separated)");
+    println!("  --spec-files <files>   Individual specification files to read in addition to the");
+    println!("                         specification directories (comma separated)");
+    println!("  --spec-file -          Also trace specification content read from stdin, e.g.");
+    println!("                         git show rev:spec.md | ovft --spec-file -");
+    println!("  --spec-format <format> Format of the stdin content: markdown (default), source,");
+    println!("                         reqif, org, yaml or csv");
+    println!("  --at-rev <rev>         Read source and spec files from a git branch, tag or commit");
+    println!("                         instead of the working tree, e.g. --at-rev v1.2.0");
+    println!("  --output <file>        Output file path (default: requirements_report.html,");
+    println!("                         requirements.aspec, requirements-junit.xml,");
+    println!("                         requirements.json, requirements.dot or requirements.puml)");
+    println!("  --format <format>      Output format: html (default); aspec, the OpenFastTrace");
+    println!("                         specobject XML consumed by OFT report pipelines, or text,");
+    println!("                         the OFT plain-text tracing report (printed to stdout");
+    println!("                         unless --output is given), or junit, JUnit XML with one");
+    println!("                         test case per item for CI test dashboards, or json, the");
+    println!("                         summary and all linked items with their links, or dot,");
+    println!("                         a Graphviz graph of items and links colored by status, or");
+    println!("                         plantuml, a PlantUML diagram of covers/depends relations,");
+    println!("                         or github, GitHub Actions error/warning annotations per");
+    println!("                         defect (printed to stdout unless --output is given), or");
+    println!("                         delta, the defects, items and coverage that changed since");
+    println!("                         the --diff-against revision as Markdown (printed to stdout");
+    println!("                         unless --output is given)");
+    println!("  --diff-against <rev>   Git branch, tag or commit the delta report compares the");
+    println!("                         trace against (default: HEAD), e.g. --diff-against main");
+    println!("  -v, --verbosity <level> Items shown by the text report: summary (footer only),");
+    println!("                         failures (defective items, default) or all");
+    println!("  --color <when>         Colorize the console summary: auto (default, only on a");
+    println!("                         terminal and without NO_COLOR), always or never");
     println!("  --config <file>        Path to configuration file (.ovft.toml)");
     println!("                         If not specified, looks for .ovft.toml in current or parent directories");
+    println!("  --backlog <path>       Export uncovered items as CSV (.csv) or as a directory of");
+    println!("                         GitHub/GitLab issue templates");
+    println!("  --export-csv <dir>     Write items.csv with all item fields and links.csv with");
+    println!("                         all link statuses to the directory, e.g. for Excel pivots");
+    println!("  --export-xlsx <file>   Write an Excel workbook with a requirements/coverage");
+    println!("                         matrix and one sheet per artifact type, e.g. for audits");
+    println!("  --export-artifacts <file> Write the items of this project as compact JSON that");
+    println!("                         other repositories list in external_artifacts, e.g. to");
+    println!("                         cover system requirements kept in another repository");
+    println!("  --write-baseline <file> Write all current defects to a JSON defect baseline and");
+    println!("                         exit successfully, e.g. when adopting ovft in legacy projects");
+    println!("  --defect-baseline <file> Report the defects listed in the defect baseline as");
+    println!("                         warnings so only new defects fail (overrides config)");
+    println!("  --graph-type <type>    Only show items of this artifact type in the DOT graph,");
+    println!("                         together with the items linked to them");
+    println!("  --graph-root <id>      Only show this item and the items covering it, directly or");
+    println!("                         indirectly, in the DOT graph");
+    println!("  --dry-run              Show changes without writing files (rename)");
+    println!("  --no-history           Do not record this run in the run history");
+    println!("  --offline              Do not fetch https:// spec_dirs entries; use the copies cached");
+    println!("                         in remote_cache_dir instead");
+    println!("  --audit                Write trace-result.json and a SHA256SUMS manifest next to the");
+    println!("                         report (signed if audit.sign_command is configured)");
     println!("  --help                 Show this help message");
     println!();
     println!("Configuration File:");
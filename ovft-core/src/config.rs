@@ -1,4 +1,7 @@
+use crate::core::{DefectType, IgnoredItems, ItemStatus, LinkStatus};
+use crate::importers::{PathPatterns, TagFormat};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// Configuration for the requirements tracing process
@@ -6,26 +9,924 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// Directories containing source code files to scan for tags
-    pub source_dirs: Vec<PathBuf>,
+    pub source_dirs: Vec<SourceDir>,
     /// Directories containing specification files (markdown)
     pub spec_dirs: Vec<PathBuf>,
+    /// Individual source files to scan in addition to `source_dirs`
+    #[serde(default)]
+    pub source_files: Vec<PathBuf>,
+    /// Individual specification files to read in addition to `spec_dirs`
+    #[serde(default)]
+    pub spec_files: Vec<PathBuf>,
+    /// Additional tag syntaxes, e.g. `@covers {id}` or `[{type}->{id}]`
+    #[serde(default)]
+    pub tag_formats: Vec<String>,
+    /// Only read tags inside comments of source files with a known comment style
+    #[serde(default = "Config::default_scan_comments_only")]
+    pub scan_comments_only: bool,
+    /// Ignore item IDs and fields inside fenced code blocks of markdown specs
+    #[serde(default = "Config::default_skip_markdown_code_blocks")]
+    pub skip_markdown_code_blocks: bool,
+    /// Apply `Needs:` lines below markdown headings to all items nested under them
+    #[serde(default)]
+    pub inherit_section_needs: bool,
+    /// Ignore tags inside string literals, e.g. example tags in parser test fixtures
+    #[serde(default)]
+    pub strict_tag_scan: bool,
+    /// Comment styles by file extension, overriding or extending the built-in ones
+    #[serde(default)]
+    pub comment_styles: BTreeMap<String, CommentStyle>,
+    /// Also read `#[ovft::covers(..)]` and `#[ovft::implements(..)]` attributes in Rust files
+    #[serde(default)]
+    pub rust_attributes: bool,
+    /// Directory storing copies of spec documents fetched from `https://` spec_dirs entries
+    #[serde(default = "Config::default_remote_cache_dir")]
+    pub remote_cache_dir: PathBuf,
+    /// Use cached copies of remote spec documents instead of fetching them
+    #[serde(default)]
+    pub offline: bool,
     /// File patterns to include when scanning source directories
     pub source_patterns: Vec<String>,
     /// File patterns to exclude when scanning
     pub exclude_patterns: Vec<String>,
     /// Additional artifact types to recognize
     pub artifact_types: Vec<String>,
+    /// Generic artifact types and the specific types satisfying a need for them,
+    /// e.g. `test = ["utest", "itest"]`
+    #[serde(default)]
+    pub artifact_type_groups: BTreeMap<String, Vec<String>>,
+    /// Alternative names of artifact types, e.g. `code = "impl"`
+    #[serde(default)]
+    pub artifact_type_aliases: BTreeMap<String, String>,
+    /// Project-specific item statuses accepted in addition to the built-in ones
+    #[serde(default)]
+    pub custom_statuses: Vec<String>,
+    /// Keys of `Key: value` lines in markdown specs kept as item attributes, e.g. `ASIL`
+    #[serde(default)]
+    pub custom_attributes: Vec<String>,
+    /// Statuses of items that neither need nor provide coverage, e.g. `rejected`
+    #[serde(default)]
+    pub excluded_statuses: Vec<String>,
+    /// ID patterns of items left out of defect analysis, e.g. `req~legacy-*~*`
+    #[serde(default)]
+    pub ignore_items: Vec<String>,
+    /// How strictly the revision of a covered item must match
+    #[serde(default)]
+    pub revision_matching: RevisionMatching,
+    /// Tags restricting which items are traced
+    #[serde(default)]
+    pub filter_tags: TagFilter,
+    /// Which link statuses and defect types fail the trace
+    #[serde(default)]
+    pub severity: SeverityPolicy,
     /// Whether to generate detailed reports
     pub verbose: bool,
     /// Output directory for reports
     pub output_dir: Option<PathBuf>,
+    /// Whether `.gitignore` and `.ovftignore` rules exclude files from scanning
+    #[serde(default = "Config::default_respect_ignore_files")]
+    pub respect_ignore_files: bool,
+    /// Whether symbolic links are followed when scanning directories
+    #[serde(default)]
+    pub follow_symlinks: bool,
+    /// Size in bytes above which files are skipped when scanning directories (`None` for no limit)
+    #[serde(default = "Config::default_max_file_size")]
+    pub max_file_size: Option<u64>,
+    /// Number of threads used to parse files (all cores if not set)
+    #[serde(default)]
+    pub threads: Option<usize>,
+    /// File caching parsed items between runs (disabled if not set)
+    #[serde(default)]
+    pub cache_file: Option<PathBuf>,
+    /// Terminology rules checked against item text
+    #[serde(default)]
+    pub glossary: GlossaryConfig,
+    /// Rules for the names in item IDs
+    #[serde(default)]
+    pub naming: NamingRules,
+    /// Stricter coverage gates for items carrying a specific tag
+    #[serde(default)]
+    pub tag_gates: Vec<TagGate>,
+    /// Minimum coverage percentages, overall and per artifact type
+    #[serde(default)]
+    pub min_coverage: MinCoverage,
+    /// Accepted findings that do not block a release
+    #[serde(default)]
+    pub waivers: Vec<Waiver>,
+    /// JUnit XML test result files considered for the release verdict
+    #[serde(default)]
+    pub test_results: Vec<PathBuf>,
+    /// Namespace prefixes applied to the item IDs of individual directories
+    #[serde(default)]
+    pub namespaces: Vec<Namespace>,
+    /// Directory where a summary of each run is stored (`None` disables the run history)
+    #[serde(default = "Config::default_history_dir")]
+    pub history_dir: Option<PathBuf>,
+    /// Checksum manifest and signature settings for archived reports
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Compliance matrices generated next to the report
+    #[serde(default)]
+    pub compliance_matrices: Vec<ComplianceMatrix>,
+    /// Baseline file listing frozen items that must not change without a revision bump
+    #[serde(default)]
+    pub baseline_file: Option<PathBuf>,
+    /// Defects accepted when adopting tracing; only defects not listed there fail the trace
+    #[serde(default)]
+    pub defect_baseline: Option<PathBuf>,
+    /// Git repository whose commit messages are scanned for coverage tags
+    #[serde(default)]
+    pub commit_coverage: Option<CommitCoverage>,
+    /// GitLab project issues and group epics to include in the trace
+    #[serde(default)]
+    pub gitlab: Option<GitLabConfig>,
+    /// JSON specobject exports of the Java OpenFastTrace tool to include in the trace
+    #[serde(default)]
+    pub oft_exports: Vec<PathBuf>,
+    /// Artifact files exported by other projects, e.g. the system requirements repository
+    #[serde(default)]
+    pub external_artifacts: Vec<PathBuf>,
+    /// Output of `cargo doc --output-format json` whose documentation tags to include
+    #[serde(default)]
+    pub rustdoc_json: Vec<PathBuf>,
+    /// How `.csv` files in the spec directories are read
+    #[serde(default)]
+    pub csv: CsvConfig,
+    /// Sheets of `.xlsx` files in the spec directories to import (empty imports every sheet)
+    #[serde(default)]
+    pub xlsx_sheets: Vec<SheetMapping>,
+    /// lcov or Cobertura XML reports used to check that implementation tags are executed
+    #[serde(default)]
+    pub code_coverage_reports: Vec<PathBuf>,
+    /// Directory with a `report.css` and/or handlebars `report.hbs` replacing the embedded ones
+    #[serde(default)]
+    pub report_template_dir: Option<PathBuf>,
+    /// URL pattern linking report locations to the source, with `{rev}`, `{path}` and `{line}`
+    #[serde(default)]
+    pub source_link_base: Option<String>,
+    /// Revision substituted for `{rev}` in source links (default: the checked out commit)
+    #[serde(default)]
+    pub source_link_rev: Option<String>,
+    /// Split the HTML report into an index page and one page per group of items
+    #[serde(default)]
+    pub report_split: ReportSplit,
+    /// Language of the HTML and delta reports
+    #[serde(default)]
+    pub report_language: ReportLanguage,
+}
+
+/// Comment syntax of a source language
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommentStyle {
+    /// Markers starting a comment that runs to the end of the line, e.g. `//` or `#`
+    pub line: Vec<String>,
+    /// Start and end markers of block comments, e.g. `["/*", "*/"]`
+    pub block: Vec<(String, String)>,
+}
+
+impl CommentStyle {
+    /// Comment style with the given line and block markers
+    pub fn new(line: &[&str], block: &[(&str, &str)]) -> Self {
+        Self {
+            line: line.iter().map(|marker| marker.to_string()).collect(),
+            block: block
+                .iter()
+                .map(|(start, end)| (start.to_string(), end.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Built-in comment styles by lowercase file extension
+    pub fn defaults() -> BTreeMap<String, CommentStyle> {
+        let c_like = Self::new(&["//"], &[("/*", "*/")]);
+        let hash = Self::new(&["#"], &[]);
+        let markup = Self::new(&[], &[("<!--", "-->")]);
+        let dash = Self::new(&["--"], &[]);
+
+        let mut styles = BTreeMap::new();
+        for extension in [
+            "rs", "c", "h", "cc", "cpp", "hpp", "cs", "java", "kt", "kts", "scala", "go", "js",
+            "jsx", "ts", "tsx", "swift", "dart", "proto",
+        ] {
+            styles.insert(extension.to_string(), c_like.clone());
+        }
+        for extension in ["py", "sh", "bash", "rb", "pl", "r", "toml", "yaml", "yml", "cmake"] {
+            styles.insert(extension.to_string(), hash.clone());
+        }
+        for extension in ["html", "htm", "xml", "svg"] {
+            styles.insert(extension.to_string(), markup.clone());
+        }
+        for extension in ["hs", "elm", "ada", "adb", "ads"] {
+            styles.insert(extension.to_string(), dash.clone());
+        }
+        styles.insert("sql".to_string(), Self::new(&["--"], &[("/*", "*/")]));
+        styles.insert("lua".to_string(), Self::new(&["--"], &[("--[[", "]]")]));
+        styles
+    }
+}
+
+/// Column mapping for one spreadsheet sheet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SheetMapping {
+    /// Name of the sheet
+    pub sheet: String,
+    /// Row holding the column headers (1-based)
+    #[serde(default = "SheetMapping::default_header_row")]
+    pub header_row: usize,
+    /// Which column holds which item field
+    #[serde(default)]
+    pub columns: ColumnMapping,
+}
+
+impl SheetMapping {
+    /// Import a sheet with headers in the first row and the default column names
+    pub fn new<S: Into<String>>(sheet: S) -> Self {
+        Self {
+            sheet: sheet.into(),
+            header_row: Self::default_header_row(),
+            columns: ColumnMapping::default(),
+        }
+    }
+
+    fn default_header_row() -> usize {
+        1
+    }
+}
+
+/// Tags restricting a trace to a subset of the items
+///
+/// An item is traced if it has one of the `include` tags, or any tags when
+/// `include` is empty, and none of the `exclude` tags. The pseudo tag `_`
+/// stands for items without tags, e.g. untagged implementations and tests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagFilter {
+    /// Tags of which an item needs at least one
+    pub include: Vec<String>,
+    /// Tags of which an item must have none
+    pub exclude: Vec<String>,
+}
+
+impl TagFilter {
+    /// Pseudo tag matching items without tags
+    pub const UNTAGGED: &'static str = "_";
+
+    /// Check whether the filter lets all items pass
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Check whether an item with the given tags is traced
+    pub fn matches(&self, tags: &[String]) -> bool {
+        let has = |filter: &[String]| {
+            filter.iter().any(|tag| {
+                if tag == Self::UNTAGGED {
+                    tags.is_empty()
+                } else {
+                    tags.contains(tag)
+                }
+            })
+        };
+        (self.include.is_empty() || has(&self.include)) && !has(&self.exclude)
+    }
+}
+
+/// How a link status or defect type is treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Makes the item a defect and fails the trace
+    Error,
+    /// Reported, but does not fail the trace
+    Warning,
+    /// Not reported at all
+    Ignore,
+}
+
+/// Project policy on which link statuses and defect types fail the trace
+///
+/// Link statuses and defect types are named as in the reports, e.g. `predated`,
+/// `covered outdated` or `dangling`. By default broken outgoing links are
+/// errors and all other link statuses are ignored. Defect types keep the
+/// severity they are found with unless overridden.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeverityPolicy {
+    pub link_statuses: BTreeMap<String, Severity>,
+    pub defect_types: BTreeMap<String, Severity>,
+}
+
+impl SeverityPolicy {
+    /// Set the severity of a link status
+    pub fn link_status(mut self, status: LinkStatus, severity: Severity) -> Self {
+        self.link_statuses.insert(status.to_string(), severity);
+        self
+    }
+
+    /// Set the severity of a defect type
+    pub fn defect_type(mut self, defect_type: DefectType, severity: Severity) -> Self {
+        self.defect_types.insert(defect_type.to_string(), severity);
+        self
+    }
+
+    /// The severity of a link, as configured or by default
+    pub fn link_severity(&self, status: &LinkStatus) -> Severity {
+        if let Some(severity) = self.link_statuses.get(&status.to_string()) {
+            return *severity;
+        }
+        match status {
+            LinkStatus::Orphaned
+            | LinkStatus::Ambiguous
+            | LinkStatus::Outdated
+            | LinkStatus::Predated
+            | LinkStatus::Duplicate
+            | LinkStatus::BrokenDependency => Severity::Error,
+            _ => Severity::Ignore,
+        }
+    }
+
+    /// The configured severity of a defect type, `None` to keep the one it was found with
+    pub fn defect_severity(&self, defect_type: &DefectType) -> Option<Severity> {
+        self.defect_types.get(&defect_type.to_string()).copied()
+    }
+
+    /// Reject names that are neither a link status nor a defect type
+    fn validate(&self) -> crate::Result<()> {
+        let unknown = |names: &BTreeMap<String, Severity>, known: Vec<String>, kind: &str| {
+            match names.keys().find(|name| !known.contains(name)) {
+                Some(name) => Err(crate::Error::Config(format!(
+                    "Unknown {} '{}' in severity policy (expected one of: {})",
+                    kind,
+                    name,
+                    known.join(", ")
+                ))),
+                None => Ok(()),
+            }
+        };
+        unknown(
+            &self.link_statuses,
+            LinkStatus::ALL.iter().map(LinkStatus::to_string).collect(),
+            "link status",
+        )?;
+        unknown(
+            &self.defect_types,
+            DefectType::ALL.iter().map(DefectType::to_string).collect(),
+            "defect type",
+        )
+    }
+}
+
+/// Settings for importing `.csv` specification files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvConfig {
+    /// Field delimiter (e.g. ';' for spreadsheet exports in some locales)
+    pub delimiter: char,
+    /// Which column holds which item field
+    pub columns: ColumnMapping,
+}
+
+impl Default for CsvConfig {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            columns: ColumnMapping::default(),
+        }
+    }
+}
+
+/// Header names of the table columns holding the item fields
+///
+/// Headers are matched case-insensitively; columns missing from a table are
+/// ignored except for the ID column.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnMapping {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub rationale: String,
+    pub comment: String,
+    pub covers: String,
+    pub depends: String,
+    pub needs: String,
+    pub tags: String,
+    pub status: String,
+    pub module: String,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            id: "id".to_string(),
+            title: "title".to_string(),
+            description: "description".to_string(),
+            rationale: "rationale".to_string(),
+            comment: "comment".to_string(),
+            covers: "covers".to_string(),
+            depends: "depends".to_string(),
+            needs: "needs".to_string(),
+            tags: "tags".to_string(),
+            status: "status".to_string(),
+            module: "module".to_string(),
+        }
+    }
+}
+
+/// Settings for importing coverage tags from git commit messages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitCoverage {
+    /// Repository to read the history from
+    pub repository: PathBuf,
+    /// Artifact type of the evidence items created for commits
+    #[serde(default = "CommitCoverage::default_artifact_type")]
+    pub artifact_type: String,
+}
+
+impl CommitCoverage {
+    /// Import commit coverage from a repository as `impl` items
+    pub fn new<P: Into<PathBuf>>(repository: P) -> Self {
+        Self {
+            repository: repository.into(),
+            artifact_type: Self::default_artifact_type(),
+        }
+    }
+
+    fn default_artifact_type() -> String {
+        "impl".to_string()
+    }
+}
+
+/// Settings for importing GitLab issues and epics as specification items
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitLabConfig {
+    /// Base URL of the GitLab instance
+    #[serde(default = "GitLabConfig::default_url")]
+    pub url: String,
+    /// Project path (e.g. `group/project`) or ID whose issues are imported
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Group path or ID whose epics are imported
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Only import issues and epics carrying all of these labels
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Environment variable holding the access token
+    #[serde(default = "GitLabConfig::default_token_env")]
+    pub token_env: String,
+    /// Artifact type of the items created for issues
+    #[serde(default = "GitLabConfig::default_issue_artifact_type")]
+    pub issue_artifact_type: String,
+    /// Artifact type of the items created for epics
+    #[serde(default = "GitLabConfig::default_epic_artifact_type")]
+    pub epic_artifact_type: String,
+}
+
+impl GitLabConfig {
+    /// Import the issues of a project on gitlab.com
+    pub fn for_project<S: Into<String>>(project: S) -> Self {
+        Self {
+            project: Some(project.into()),
+            ..Self::default()
+        }
+    }
+
+    fn default_url() -> String {
+        "https://gitlab.com".to_string()
+    }
+
+    fn default_token_env() -> String {
+        "GITLAB_TOKEN".to_string()
+    }
+
+    fn default_issue_artifact_type() -> String {
+        "req".to_string()
+    }
+
+    fn default_epic_artifact_type() -> String {
+        "feat".to_string()
+    }
+}
+
+impl Default for GitLabConfig {
+    fn default() -> Self {
+        Self {
+            url: Self::default_url(),
+            project: None,
+            group: None,
+            labels: Vec::new(),
+            token_env: Self::default_token_env(),
+            issue_artifact_type: Self::default_issue_artifact_type(),
+            epic_artifact_type: Self::default_epic_artifact_type(),
+        }
+    }
+}
+
+/// How the HTML report is split into several files for large projects
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportSplit {
+    /// A single file with all items
+    #[default]
+    None,
+    /// One page per artifact type
+    ArtifactType,
+    /// One page per document the items are defined in
+    Document,
+}
+
+/// How a `covers` link to another revision of an existing item is resolved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RevisionMatching {
+    /// The revision must match exactly
+    #[default]
+    Strict,
+    /// A link to an older revision is satisfied by the newer revision of the item
+    Newer,
+    /// Revisions are ignored, links are resolved by artifact type and name
+    Any,
+}
+
+/// Language of the user-facing strings of the reports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportLanguage {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "de")]
+    German,
+}
+
+/// Certification standard a compliance matrix is laid out for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComplianceStandard {
+    /// DO-178C trace data (high-level to low-level requirements, code and tests)
+    #[serde(rename = "do-178c")]
+    Do178c,
+    /// ISO 26262 safety requirement verification
+    #[serde(rename = "iso-26262")]
+    Iso26262,
+}
+
+impl ComplianceStandard {
+    /// Title of the generated matrix
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Do178c => "DO-178C Trace Data",
+            Self::Iso26262 => "ISO 26262 Safety Requirement Verification",
+        }
+    }
+
+    /// Heading of the column listing the traced items
+    pub fn item_heading(&self) -> &'static str {
+        match self {
+            Self::Do178c => "High-Level Requirement",
+            Self::Iso26262 => "Safety Requirement",
+        }
+    }
+
+    /// Columns used when the matrix does not configure its own
+    pub fn default_columns(&self) -> Vec<ComplianceColumn> {
+        match self {
+            Self::Do178c => vec![
+                ComplianceColumn::new("Low-Level Requirement", &["dsn"]),
+                ComplianceColumn::new("Source Code", &["impl"]),
+                ComplianceColumn::new("Test Case", &["utest", "itest", "stest"]),
+            ],
+            Self::Iso26262 => vec![
+                ComplianceColumn::new("Technical Safety Requirement", &["arch", "dsn"]),
+                ComplianceColumn::new("Implementation", &["impl"]),
+                ComplianceColumn::new("Verification", &["utest", "itest", "stest"]),
+            ],
+        }
+    }
+}
+
+/// Compliance matrix mapping trace data onto the table layout of a standard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceMatrix {
+    /// Standard defining the table layout
+    pub standard: ComplianceStandard,
+    /// Output HTML file (relative paths are resolved against the output directory)
+    pub output: PathBuf,
+    /// Artifact type of the items listed as rows
+    #[serde(default = "ComplianceMatrix::default_artifact_type")]
+    pub artifact_type: String,
+    /// Columns overriding the standard's default columns
+    #[serde(default)]
+    pub columns: Vec<ComplianceColumn>,
+}
+
+impl ComplianceMatrix {
+    /// Create a matrix with the standard's default layout
+    pub fn new<P: Into<PathBuf>>(standard: ComplianceStandard, output: P) -> Self {
+        Self {
+            standard,
+            output: output.into(),
+            artifact_type: Self::default_artifact_type(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Get the configured columns or the standard's defaults
+    pub fn effective_columns(&self) -> Vec<ComplianceColumn> {
+        if self.columns.is_empty() {
+            self.standard.default_columns()
+        } else {
+            self.columns.clone()
+        }
+    }
+
+    fn default_artifact_type() -> String {
+        "req".to_string()
+    }
+}
+
+/// Column of a compliance matrix listing the tracing items of some artifact types
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceColumn {
+    /// Column heading
+    pub heading: String,
+    /// Artifact types listed in the column
+    pub artifact_types: Vec<String>,
+}
+
+impl ComplianceColumn {
+    pub fn new(heading: &str, artifact_types: &[&str]) -> Self {
+        Self {
+            heading: heading.to_string(),
+            artifact_types: artifact_types.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
+
+/// Settings for tamper-evident audit output
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// Whether to write a checksum manifest next to the report
+    pub enabled: bool,
+    /// Command creating a detached signature of the manifest, `{file}` is
+    /// replaced by the manifest path (e.g. "gpg --detach-sign --armor {file}")
+    pub sign_command: Option<String>,
+}
+
+/// Source directory to scan for tags, optionally with its own scan rules
+///
+/// Written as a plain path or as a table like
+/// `{ path = "tests", patterns = ["*.rs"], artifact_type = "itest" }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "SourceDirEntry", into = "SourceDirEntry")]
+pub struct SourceDir {
+    /// The directory to scan
+    pub path: PathBuf,
+    /// File patterns to include instead of the global `source_patterns`
+    pub patterns: Vec<String>,
+    /// File patterns to exclude in addition to the global `exclude_patterns`
+    pub exclude_patterns: Vec<String>,
+    /// Artifact type of items whose tags name none (tag formats without
+    /// `{type}`), instead of `impl`
+    pub artifact_type: Option<String>,
+}
+
+impl SourceDir {
+    /// Scan a directory with the global rules
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            artifact_type: None,
+        }
+    }
+
+    /// Include only files matching the pattern, replacing the global `source_patterns`
+    pub fn pattern<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Also exclude files matching the pattern
+    pub fn exclude_pattern<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.exclude_patterns.push(pattern.into());
+        self
+    }
+
+    /// Give items whose tags name no artifact type this one
+    pub fn artifact_type<S: Into<String>>(mut self, artifact_type: S) -> Self {
+        self.artifact_type = Some(artifact_type.into());
+        self
+    }
+}
+
+impl AsRef<std::path::Path> for SourceDir {
+    fn as_ref(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+/// A `source_dirs` entry as written in the configuration file
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum SourceDirEntry {
+    Path(PathBuf),
+    Rules {
+        path: PathBuf,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        patterns: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        exclude_patterns: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        artifact_type: Option<String>,
+    },
+}
+
+impl From<SourceDirEntry> for SourceDir {
+    fn from(entry: SourceDirEntry) -> Self {
+        match entry {
+            SourceDirEntry::Path(path) => Self::new(path),
+            SourceDirEntry::Rules {
+                path,
+                patterns,
+                exclude_patterns,
+                artifact_type,
+            } => Self {
+                path,
+                patterns,
+                exclude_patterns,
+                artifact_type,
+            },
+        }
+    }
+}
+
+impl From<SourceDir> for SourceDirEntry {
+    fn from(source_dir: SourceDir) -> Self {
+        if source_dir.patterns.is_empty()
+            && source_dir.exclude_patterns.is_empty()
+            && source_dir.artifact_type.is_none()
+        {
+            return Self::Path(source_dir.path);
+        }
+        Self::Rules {
+            path: source_dir.path,
+            patterns: source_dir.patterns,
+            exclude_patterns: source_dir.exclude_patterns,
+            artifact_type: source_dir.artifact_type,
+        }
+    }
+}
+
+/// Namespace prefix for all items imported from one source or spec directory
+///
+/// Items of a namespaced directory get IDs like `req~billing.login~1`, so
+/// subprojects defining the same names can be traced together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Namespace {
+    /// Source or spec directory the prefix applies to
+    pub dir: PathBuf,
+    /// Prefix prepended to the item names (e.g. "billing")
+    pub prefix: String,
+}
+
+/// Accepted finding for an item that must not block a release
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Waiver {
+    /// ID of the waived item (e.g. "req~legacy-export~1")
+    pub item: String,
+    /// Why the finding is acceptable
+    pub reason: String,
+}
+
+/// Coverage gate that applies to all items carrying a tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagGate {
+    /// Tag selecting the items the gate applies to (e.g. "safety")
+    pub tag: String,
+    /// Minimum percentage of tagged items that must be covered
+    #[serde(default = "TagGate::full_coverage")]
+    pub min_coverage: f64,
+    /// Whether items must be covered transitively down the whole chain
+    #[serde(default)]
+    pub deep: bool,
+    /// Statuses tagged items are allowed to have (empty allows any status)
+    #[serde(default)]
+    pub required_status: Vec<String>,
+}
+
+impl TagGate {
+    /// Create a gate requiring full coverage of all items with the given tag
+    pub fn new<S: Into<String>>(tag: S) -> Self {
+        Self {
+            tag: tag.into(),
+            min_coverage: Self::full_coverage(),
+            deep: false,
+            required_status: Vec::new(),
+        }
+    }
+
+    fn full_coverage() -> f64 {
+        100.0
+    }
+}
+
+/// Minimum coverage percentages a trace must reach, e.g. `utest = 90.0`
+///
+/// Lets a project accept some uncovered items as long as enough are covered.
+/// Uncovered items are still defects unless the severity policy lowers
+/// `uncovered` to a warning.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MinCoverage {
+    /// Minimum percentage of all items that must be covered
+    pub overall: Option<f64>,
+    /// Minimum percentage of covered items per artifact type
+    pub artifact_types: BTreeMap<String, f64>,
+}
+
+impl MinCoverage {
+    /// Require a minimum percentage of all items to be covered
+    pub fn overall(mut self, percentage: f64) -> Self {
+        self.overall = Some(percentage);
+        self
+    }
+
+    /// Require a minimum percentage of the items of an artifact type to be covered
+    pub fn artifact_type<S: Into<String>>(mut self, artifact_type: S, percentage: f64) -> Self {
+        self.artifact_types.insert(artifact_type.into(), percentage);
+        self
+    }
+
+    /// Check whether no threshold is configured
+    pub fn is_empty(&self) -> bool {
+        self.overall.is_none() && self.artifact_types.is_empty()
+    }
+}
+
+/// Terminology rules applied to item titles, descriptions, rationale and comments
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GlossaryConfig {
+    /// Discouraged terms mapped to the term that should be used instead
+    pub preferred_terms: BTreeMap<String, String>,
+    /// Words that must not appear in item text
+    pub forbidden_words: Vec<String>,
+    /// Modal verbs of which at least one must appear, per artifact type (e.g. `req = ["shall"]`)
+    pub required_modals: BTreeMap<String, Vec<String>>,
+}
+
+impl GlossaryConfig {
+    /// Check whether any rule is configured
+    pub fn is_empty(&self) -> bool {
+        self.preferred_terms.is_empty()
+            && self.forbidden_words.is_empty()
+            && self.required_modals.is_empty()
+    }
+}
+
+/// Rules for the names in item IDs, e.g. `login-form` in `req~login-form~1`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamingRules {
+    /// Regular expression the whole name must match
+    pub pattern: Option<String>,
+    /// Maximum number of characters of a name
+    pub max_length: Option<usize>,
+    /// Whether names must be lowercase words separated by hyphens, e.g. `user-login`
+    pub kebab_case: bool,
+    /// Prefixes names must not start with, e.g. `tmp` or `test-`
+    pub reserved_prefixes: Vec<String>,
+}
+
+impl NamingRules {
+    /// Check whether any rule is configured
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_none()
+            && self.max_length.is_none()
+            && !self.kebab_case
+            && self.reserved_prefixes.is_empty()
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            source_dirs: vec![PathBuf::from("src")],
+            source_dirs: vec![SourceDir::new("src")],
             spec_dirs: vec![PathBuf::from("docs")],
+            source_files: vec![],
+            spec_files: vec![],
+            tag_formats: Vec::new(),
+            scan_comments_only: Self::default_scan_comments_only(),
+            skip_markdown_code_blocks: Self::default_skip_markdown_code_blocks(),
+            inherit_section_needs: false,
+            strict_tag_scan: false,
+            comment_styles: BTreeMap::new(),
+            rust_attributes: false,
+            remote_cache_dir: Self::default_remote_cache_dir(),
+            offline: false,
             source_patterns: vec![
                 // Rust files
                 "*.rs".to_string(),
@@ -66,8 +967,47 @@ impl Default for Config {
                 "uman".to_string(),
                 "oman".to_string(),
             ],
+            artifact_type_groups: BTreeMap::new(),
+            artifact_type_aliases: BTreeMap::new(),
+            custom_statuses: Vec::new(),
+            custom_attributes: Vec::new(),
+            excluded_statuses: Vec::new(),
+            ignore_items: Vec::new(),
+            revision_matching: RevisionMatching::default(),
+            filter_tags: TagFilter::default(),
+            severity: SeverityPolicy::default(),
             verbose: false,
             output_dir: Some(PathBuf::from("target")),
+            respect_ignore_files: Self::default_respect_ignore_files(),
+            follow_symlinks: false,
+            max_file_size: Self::default_max_file_size(),
+            threads: None,
+            cache_file: None,
+            glossary: GlossaryConfig::default(),
+            naming: NamingRules::default(),
+            tag_gates: Vec::new(),
+            min_coverage: MinCoverage::default(),
+            waivers: Vec::new(),
+            test_results: Vec::new(),
+            namespaces: Vec::new(),
+            history_dir: Self::default_history_dir(),
+            audit: AuditConfig::default(),
+            compliance_matrices: Vec::new(),
+            baseline_file: None,
+            defect_baseline: None,
+            commit_coverage: None,
+            gitlab: None,
+            oft_exports: Vec::new(),
+            external_artifacts: Vec::new(),
+            rustdoc_json: Vec::new(),
+            csv: CsvConfig::default(),
+            xlsx_sheets: Vec::new(),
+            code_coverage_reports: Vec::new(),
+            report_template_dir: None,
+            source_link_base: None,
+            source_link_rev: None,
+            report_split: ReportSplit::default(),
+            report_language: ReportLanguage::default(),
         }
     }
 }
@@ -95,12 +1035,20 @@ impl Config {
             ],
             verbose: false,
             output_dir: Some(PathBuf::from("target")),
+            history_dir: None,
+            ..Self::default()
         }
     }
 
     /// Add a source directory to scan for tags
     pub fn add_source_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
-        self.source_dirs.push(dir.into());
+        self.source_dirs.push(SourceDir::new(dir));
+        self
+    }
+
+    /// Add a source directory scanned with its own patterns or artifact type
+    pub fn add_source_dir_with_rules(mut self, source_dir: SourceDir) -> Self {
+        self.source_dirs.push(source_dir);
         self
     }
 
@@ -110,6 +1058,18 @@ impl Config {
         self
     }
 
+    /// Add a single source file to scan, e.g. a file changed in the current build
+    pub fn add_source_file<P: Into<PathBuf>>(mut self, file: P) -> Self {
+        self.source_files.push(file.into());
+        self
+    }
+
+    /// Add a single specification file to read
+    pub fn add_spec_file<P: Into<PathBuf>>(mut self, file: P) -> Self {
+        self.spec_files.push(file.into());
+        self
+    }
+
     /// Add a file pattern to include when scanning
     pub fn add_source_pattern<S: Into<String>>(mut self, pattern: S) -> Self {
         self.source_patterns.push(pattern.into());
@@ -128,6 +1088,68 @@ impl Config {
         self
     }
 
+    /// Let a need for `group` be satisfied by items of the given artifact types
+    pub fn add_artifact_type_group<S: Into<String>>(mut self, group: S, members: &[&str]) -> Self {
+        self.artifact_type_groups.insert(
+            group.into(),
+            members.iter().map(|member| member.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Treat `alias` as another name of `artifact_type`
+    pub fn add_artifact_type_alias<S: Into<String>, T: Into<String>>(
+        mut self,
+        alias: S,
+        artifact_type: T,
+    ) -> Self {
+        self.artifact_type_aliases
+            .insert(alias.into(), artifact_type.into());
+        self
+    }
+
+    /// Accept a project-specific item status, e.g. `implemented`
+    pub fn add_custom_status<S: Into<String>>(mut self, status: S) -> Self {
+        self.custom_statuses.push(status.into());
+        self
+    }
+
+    /// Keep `Key: value` lines with the given key as item attributes, e.g. `ASIL`
+    pub fn add_custom_attribute<S: Into<String>>(mut self, key: S) -> Self {
+        self.custom_attributes.push(key.into());
+        self
+    }
+
+    /// Leave items with the given status out of coverage analysis
+    pub fn exclude_status<S: Into<String>>(mut self, status: S) -> Self {
+        self.excluded_statuses.push(status.into());
+        self
+    }
+
+    /// Leave items with IDs matching the pattern out of defect analysis
+    pub fn ignore_item<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.ignore_items.push(pattern.into());
+        self
+    }
+
+    /// Set how strictly covered revisions must match
+    pub fn revision_matching(mut self, matching: RevisionMatching) -> Self {
+        self.revision_matching = matching;
+        self
+    }
+
+    /// Only trace the items passing the tag filter
+    pub fn filter_tags(mut self, filter: TagFilter) -> Self {
+        self.filter_tags = filter;
+        self
+    }
+
+    /// Set which link statuses and defect types fail the trace
+    pub fn severity(mut self, policy: SeverityPolicy) -> Self {
+        self.severity = policy;
+        self
+    }
+
     /// Set whether to generate verbose output
     pub fn verbose(mut self, verbose: bool) -> Self {
         self.verbose = verbose;
@@ -140,16 +1162,355 @@ impl Config {
         self
     }
 
+    /// Set the terminology rules checked against item text
+    pub fn glossary(mut self, glossary: GlossaryConfig) -> Self {
+        self.glossary = glossary;
+        self
+    }
+
+    /// Set the rules checked against the names in item IDs
+    pub fn naming(mut self, naming: NamingRules) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Add a coverage gate for a tag group
+    pub fn add_tag_gate(mut self, gate: TagGate) -> Self {
+        self.tag_gates.push(gate);
+        self
+    }
+
+    /// Require minimum coverage percentages, overall and per artifact type
+    pub fn min_coverage(mut self, min_coverage: MinCoverage) -> Self {
+        self.min_coverage = min_coverage;
+        self
+    }
+
+    /// Waive findings for an item in the release verdict
+    pub fn add_waiver<S: Into<String>, R: Into<String>>(mut self, item: S, reason: R) -> Self {
+        self.waivers.push(Waiver {
+            item: item.into(),
+            reason: reason.into(),
+        });
+        self
+    }
+
+    /// Add a JUnit XML test result file for the release verdict
+    pub fn add_test_results<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.test_results.push(path.into());
+        self
+    }
+
+    /// Prefix the IDs of all items imported from a directory with a namespace
+    pub fn add_namespace<P: Into<PathBuf>, S: Into<String>>(mut self, dir: P, prefix: S) -> Self {
+        self.namespaces.push(Namespace {
+            dir: dir.into(),
+            prefix: prefix.into(),
+        });
+        self
+    }
+
+    /// Get the namespace prefix configured for a directory
+    pub fn namespace_for(&self, dir: &std::path::Path) -> Option<&str> {
+        self.namespaces
+            .iter()
+            .find(|namespace| namespace.dir == dir)
+            .map(|namespace| namespace.prefix.as_str())
+    }
+
+    /// Set the directory for the run history (`None` disables it)
+    pub fn history_dir<P: Into<PathBuf>>(mut self, dir: Option<P>) -> Self {
+        self.history_dir = dir.map(Into::into);
+        self
+    }
+
+    /// Set the checksum manifest and signature settings
+    pub fn audit(mut self, audit: AuditConfig) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Add a compliance matrix to generate with the report
+    pub fn add_compliance_matrix(mut self, matrix: ComplianceMatrix) -> Self {
+        self.compliance_matrices.push(matrix);
+        self
+    }
+
+    /// Set the baseline file listing frozen items
+    pub fn baseline_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.baseline_file = Some(path.into());
+        self
+    }
+
+    /// Set the defect baseline listing accepted defects
+    pub fn defect_baseline<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.defect_baseline = Some(path.into());
+        self
+    }
+
+    /// Scan the commit messages of a git repository for coverage tags
+    pub fn commit_coverage(mut self, commit_coverage: CommitCoverage) -> Self {
+        self.commit_coverage = Some(commit_coverage);
+        self
+    }
+
+    /// Import GitLab issues and epics
+    pub fn gitlab(mut self, gitlab: GitLabConfig) -> Self {
+        self.gitlab = Some(gitlab);
+        self
+    }
+
+    /// Include a JSON specobject export of the Java OpenFastTrace tool
+    pub fn add_oft_export<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.oft_exports.push(path.into());
+        self
+    }
+
+    /// Include the items of an artifact file exported by another project
+    pub fn add_external_artifacts<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.external_artifacts.push(path.into());
+        self
+    }
+
+    /// Include the documentation tags of a rustdoc JSON file
+    pub fn add_rustdoc_json<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.rustdoc_json.push(path.into());
+        self
+    }
+
+    /// Set how `.csv` specification files are read
+    pub fn csv(mut self, csv: CsvConfig) -> Self {
+        self.csv = csv;
+        self
+    }
+
+    /// Import a spreadsheet sheet with the given mapping
+    pub fn add_xlsx_sheet(mut self, sheet: SheetMapping) -> Self {
+        self.xlsx_sheets.push(sheet);
+        self
+    }
+
+    /// Add an lcov or Cobertura XML code coverage report
+    pub fn add_code_coverage_report<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.code_coverage_reports.push(path.into());
+        self
+    }
+
+    /// Set the directory with a custom HTML report template and stylesheet
+    pub fn report_template_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.report_template_dir = Some(dir.into());
+        self
+    }
+
+    /// Link report locations to the source, e.g.
+    /// `https://github.com/org/repo/blob/{rev}/{path}#L{line}`
+    pub fn source_link_base<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.source_link_base = Some(pattern.into());
+        self
+    }
+
+    /// Set the revision substituted for `{rev}` in source links
+    pub fn source_link_rev<S: Into<String>>(mut self, rev: S) -> Self {
+        self.source_link_rev = Some(rev.into());
+        self
+    }
+
+    /// Split the HTML report into several pages
+    pub fn report_split(mut self, split: ReportSplit) -> Self {
+        self.report_split = split;
+        self
+    }
+
+    /// Set the language of the HTML and delta reports
+    pub fn report_language(mut self, language: ReportLanguage) -> Self {
+        self.report_language = language;
+        self
+    }
+
+    /// Set whether `.gitignore` and `.ovftignore` rules are honored
+    pub fn respect_ignore_files(mut self, respect: bool) -> Self {
+        self.respect_ignore_files = respect;
+        self
+    }
+
+    /// Set whether symbolic links are followed when scanning directories
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Set the size in bytes above which files are skipped (`None` for no limit)
+    pub fn max_file_size(mut self, max_file_size: Option<u64>) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Set the number of threads used to parse files
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Cache parsed items in the given file so unchanged files are not parsed again
+    pub fn cache_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.cache_file = Some(path.into());
+        self
+    }
+
+    /// Recognize an additional tag syntax, e.g. `@covers {id}`
+    pub fn add_tag_format<S: Into<String>>(mut self, template: S) -> Self {
+        self.tag_formats.push(template.into());
+        self
+    }
+
+    /// Only read tags inside comments (`false` scans the whole file content)
+    pub fn scan_comments_only(mut self, enabled: bool) -> Self {
+        self.scan_comments_only = enabled;
+        self
+    }
+
+    /// Ignore item IDs inside fenced code blocks of markdown specs (`false` parses them)
+    pub fn skip_markdown_code_blocks(mut self, enabled: bool) -> Self {
+        self.skip_markdown_code_blocks = enabled;
+        self
+    }
+
+    /// Let `Needs:` lines below markdown headings apply to the items of their section
+    pub fn inherit_section_needs(mut self, enabled: bool) -> Self {
+        self.inherit_section_needs = enabled;
+        self
+    }
+
+    /// Ignore tags inside string literals of source files
+    pub fn strict_tag_scan(mut self, enabled: bool) -> Self {
+        self.strict_tag_scan = enabled;
+        self
+    }
+
+    /// Set the comment style of the files with the given extension
+    pub fn comment_style<S: Into<String>>(mut self, extension: S, style: CommentStyle) -> Self {
+        self.comment_styles.insert(extension.into(), style);
+        self
+    }
+
+    /// Read coverage attributes on Rust items in addition to comment tags
+    pub fn rust_attributes(mut self, enabled: bool) -> Self {
+        self.rust_attributes = enabled;
+        self
+    }
+
+    /// Set the directory storing copies of remote spec documents
+    pub fn remote_cache_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.remote_cache_dir = dir.into();
+        self
+    }
+
+    /// Only use cached copies of remote spec documents
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    fn default_scan_comments_only() -> bool {
+        true
+    }
+
+    fn default_skip_markdown_code_blocks() -> bool {
+        true
+    }
+
+    fn default_remote_cache_dir() -> PathBuf {
+        PathBuf::from(".ovft/remote")
+    }
+
+    fn default_respect_ignore_files() -> bool {
+        true
+    }
+
+    fn default_max_file_size() -> Option<u64> {
+        Some(10 * 1024 * 1024)
+    }
+
+    fn default_history_dir() -> Option<PathBuf> {
+        Some(PathBuf::from(".ovft/history"))
+    }
+
     /// Load configuration from a TOML file
+    ///
+    /// A file can build on a base configuration named by
+    /// `extends = "path/to/base.ovft.toml"`, relative to the file. Tables are
+    /// merged key by key; any other value set in the file, e.g. a list of
+    /// directories, replaces the one of the base.
     pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> crate::Result<Self> {
-        let content = std::fs::read_to_string(path)?;
-        let config: Self = toml::from_str(&content)?;
+        let table = Self::read_extended(path.as_ref(), &mut Vec::new())?;
+        let config: Self = toml::Value::Table(table).try_into()?;
+        for template in &config.tag_formats {
+            TagFormat::parse(template)?;
+        }
+        for status in &config.excluded_statuses {
+            if ItemStatus::parse_with(status, &config.custom_statuses).is_none() {
+                return Err(crate::Error::Config(format!(
+                    "Unknown excluded status '{}'; declare it in custom_statuses",
+                    status
+                )));
+            }
+        }
+        config.severity.validate()?;
+        IgnoredItems::new(&config.ignore_items)?;
         Ok(config)
     }
 
+    /// Read a configuration file merged onto the configurations it extends;
+    /// `chain` holds the files extending it, to detect cycles
+    fn read_extended(
+        path: &std::path::Path,
+        chain: &mut Vec<PathBuf>,
+    ) -> crate::Result<toml::Table> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            crate::Error::Config(format!("Cannot read configuration {}: {}", path.display(), e))
+        })?;
+        let mut table: toml::Table = content.parse()?;
+        let Some(base) = table.remove("extends") else {
+            return Ok(table);
+        };
+        let base = base.as_str().ok_or_else(|| {
+            crate::Error::Config(format!("'extends' in {} must be a path", path.display()))
+        })?;
+
+        let canonical = path.canonicalize()?;
+        if chain.contains(&canonical) {
+            return Err(crate::Error::Config(format!(
+                "Configuration {} extends itself",
+                path.display()
+            )));
+        }
+        chain.push(canonical);
+        let base_path = path
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new(""))
+            .join(base);
+        let mut merged = Self::read_extended(&base_path, chain)?;
+        Self::merge_tables(&mut merged, table);
+        Ok(merged)
+    }
+
+    /// Merge `overrides` into `base`, descending into tables present in both
+    fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+        for (key, value) in overrides {
+            match (base.get_mut(&key), value) {
+                (Some(toml::Value::Table(base_table)), toml::Value::Table(table)) => {
+                    Self::merge_tables(base_table, table);
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+
     /// Load configuration from .ovft.toml file if it exists, otherwise return default
     pub fn load_or_default() -> Self {
-        Self::load_from_current_dir().unwrap_or_else(|| Self::default())
+        Self::load_from_current_dir().unwrap_or_default()
     }
 
     /// Try to load configuration from .ovft.toml in current directory or parent directories
@@ -185,31 +1546,25 @@ impl Config {
         Ok(())
     }
 
-    /// Check if a file path matches the source patterns
+    /// Check if a path relative to a source directory matches the source patterns
     pub fn matches_source_pattern(&self, path: &std::path::Path) -> bool {
-        let path_str = path.to_string_lossy();
-
-        // Check if excluded
-        for exclude_pattern in &self.exclude_patterns {
-            if glob::Pattern::new(exclude_pattern)
-                .map(|p| p.matches(&path_str))
-                .unwrap_or(false)
-            {
-                return false;
-            }
-        }
-
-        // Check if included
-        for include_pattern in &self.source_patterns {
-            if glob::Pattern::new(include_pattern)
-                .map(|p| p.matches(&path_str))
-                .unwrap_or(false)
-            {
-                return true;
-            }
-        }
+        PathPatterns::new(&self.source_patterns, &self.exclude_patterns).matches(path)
+    }
 
-        false
+    /// Patterns selecting the files of a source directory
+    pub fn source_dir_patterns(&self, source_dir: &SourceDir) -> PathPatterns {
+        let patterns = if source_dir.patterns.is_empty() {
+            &self.source_patterns
+        } else {
+            &source_dir.patterns
+        };
+        let exclude_patterns: Vec<_> = self
+            .exclude_patterns
+            .iter()
+            .chain(&source_dir.exclude_patterns)
+            .cloned()
+            .collect();
+        PathPatterns::new(patterns, &exclude_patterns)
     }
 
     /// Check if a file is a markdown specification file
@@ -278,6 +1633,67 @@ mod tests {
         assert_eq!(config.artifact_types, deserialized.artifact_types);
     }
 
+    #[test]
+    fn test_source_dir_entries() {
+        let config: Config = toml::from_str(
+            r#"
+            source_dirs = ["src", { path = "tests", patterns = ["*.rs"], artifact_type = "itest" }]
+            spec_dirs = []
+            source_patterns = ["*.rs", "*.py"]
+            exclude_patterns = []
+            artifact_types = []
+            verbose = false
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.source_dirs,
+            vec![
+                SourceDir::new("src"),
+                SourceDir::new("tests").pattern("*.rs").artifact_type("itest"),
+            ]
+        );
+        let patterns = config.source_dir_patterns(&config.source_dirs[1]);
+        assert!(!patterns.matches(Path::new("fixture.py")));
+        let patterns = config.source_dir_patterns(&config.source_dirs[0]);
+        assert!(patterns.matches(Path::new("fixture.py")));
+    }
+
+    #[test]
+    fn test_extends_base_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = Config::empty()
+            .add_source_dir("src")
+            .naming(NamingRules {
+                kebab_case: true,
+                max_length: Some(40),
+                ..Default::default()
+            });
+        base.save_to_file(dir.path().join("base.ovft.toml")).unwrap();
+        std::fs::create_dir(dir.path().join("crate")).unwrap();
+        let crate_config = dir.path().join("crate/.ovft.toml");
+        std::fs::write(
+            &crate_config,
+            "extends = \"../base.ovft.toml\"\nspec_dirs = [\"crate/docs\"]\n\n\
+             [naming]\nmax_length = 20\n",
+        )
+        .unwrap();
+
+        let config = Config::from_file(&crate_config).unwrap();
+        assert_eq!(config.source_dirs, base.source_dirs);
+        assert_eq!(config.spec_dirs, vec![PathBuf::from("crate/docs")]);
+        assert!(config.naming.kebab_case);
+        assert_eq!(config.naming.max_length, Some(20));
+
+        std::fs::write(
+            dir.path().join("base.ovft.toml"),
+            "extends = \"crate/.ovft.toml\"\n",
+        )
+        .unwrap();
+        assert!(Config::from_file(&crate_config).is_err());
+    }
+
     #[test]
     fn test_load_or_default() {
         // This should not panic and return a valid config
@@ -0,0 +1,123 @@
+use crate::config::Config;
+use crate::core::{SpecificationItem, SpecificationItemId};
+use std::collections::BTreeMap;
+
+/// Vocabulary of artifact types: generic types satisfied by specific ones, and aliases
+///
+/// With the group `test = ["utest", "itest"]` an item needing `test` is covered
+/// by `utest` and `itest` items. Groups may contain other groups. Aliases are
+/// replaced by their artifact type when items are imported, so `code~login~1`
+/// and `impl~login~1` are the same item if `code` is an alias of `impl`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArtifactTypes {
+    groups: BTreeMap<String, Vec<String>>,
+    aliases: BTreeMap<String, String>,
+}
+
+impl ArtifactTypes {
+    pub fn new(groups: BTreeMap<String, Vec<String>>, aliases: BTreeMap<String, String>) -> Self {
+        Self { groups, aliases }
+    }
+
+    /// The artifact type vocabulary of a configuration
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(
+            config.artifact_type_groups.clone(),
+            config.artifact_type_aliases.clone(),
+        )
+    }
+
+    /// Resolve an alias to its artifact type
+    pub fn canonical<'a>(&'a self, artifact_type: &'a str) -> &'a str {
+        self.aliases
+            .get(artifact_type)
+            .map(String::as_str)
+            .unwrap_or(artifact_type)
+    }
+
+    /// Replace the aliases in the ID, needs and links of an item
+    pub fn canonicalize(&self, item: &mut SpecificationItem) {
+        if self.aliases.is_empty() {
+            return;
+        }
+        let canonical_id = |id: &mut SpecificationItemId| {
+            id.artifact_type = self.canonical(&id.artifact_type).to_string();
+        };
+        canonical_id(&mut item.id);
+        item.covers.iter_mut().for_each(canonical_id);
+        item.depends.iter_mut().for_each(canonical_id);
+        for needed_type in &mut item.needs {
+            *needed_type = self.canonical(needed_type).to_string();
+        }
+    }
+
+    /// The specific artifact types satisfying a need, empty if it is no group
+    pub fn satisfying_types(&self, needed_type: &str) -> Vec<String> {
+        let mut types = Vec::new();
+        let mut pending = vec![needed_type];
+        while let Some(group) = pending.pop() {
+            for member in self.groups.get(group).into_iter().flatten() {
+                let member = self.canonical(member);
+                // Nested groups are resolved, cycles ignored
+                if member != needed_type && !types.iter().any(|known| known == member) {
+                    types.push(member.to_string());
+                    pending.push(member);
+                }
+            }
+        }
+        types
+    }
+
+    /// Check whether an item of `artifact_type` satisfies a need for `needed_type`
+    pub fn satisfies(&self, needed_type: &str, artifact_type: &str) -> bool {
+        needed_type == artifact_type
+            || self
+                .satisfying_types(needed_type)
+                .iter()
+                .any(|member| member == artifact_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups_and_aliases() {
+        let types = ArtifactTypes::new(
+            BTreeMap::from([
+                (
+                    "test".to_string(),
+                    vec!["utest".to_string(), "systest".to_string()],
+                ),
+                (
+                    "systest".to_string(),
+                    vec!["itest".to_string(), "stest".to_string()],
+                ),
+            ]),
+            BTreeMap::from([("code".to_string(), "impl".to_string())]),
+        );
+        assert!(types.satisfies("test", "utest"));
+        assert!(types.satisfies("test", "stest"));
+        assert!(types.satisfies("impl", "impl"));
+        assert!(!types.satisfies("utest", "test"));
+        assert!(types.satisfying_types("impl").is_empty());
+
+        let mut item = SpecificationItem::builder(SpecificationItemId::new(
+            "code".to_string(),
+            "login".to_string(),
+            1,
+        ))
+        .needs("code".to_string())
+        .covers(SpecificationItemId::new(
+            "code".to_string(),
+            "base".to_string(),
+            1,
+        ))
+        .build();
+        types.canonicalize(&mut item);
+        assert_eq!(item.id.to_string(), "impl~login~1");
+        assert_eq!(item.needs, vec!["impl".to_string()]);
+        assert_eq!(item.covers[0].to_string(), "impl~base~1");
+    }
+}
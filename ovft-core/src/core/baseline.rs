@@ -0,0 +1,150 @@
+use crate::core::{Defect, DefectType, ItemStatus, LinkedSpecificationItem, SpecificationItem};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Approved item whose content must not change without a revision bump
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrozenItem {
+    /// Item ID including the revision
+    pub id: String,
+    /// SHA-256 fingerprint of the item content
+    pub fingerprint: String,
+}
+
+/// Set of frozen items stored in a baseline file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default)]
+    pub items: Vec<FrozenItem>,
+}
+
+impl Baseline {
+    /// Freeze all approved items
+    pub fn from_items(items: &[LinkedSpecificationItem]) -> Self {
+        let mut frozen: Vec<_> = items
+            .iter()
+            .filter(|item| item.item.status == ItemStatus::Approved)
+            .map(|item| FrozenItem {
+                id: item.id().to_string(),
+                fingerprint: Self::fingerprint(&item.item),
+            })
+            .collect();
+        frozen.sort_by(|a, b| a.id.cmp(&b.id));
+        frozen.dedup_by(|a, b| a.id == b.id);
+
+        Self { items: frozen }
+    }
+
+    /// Load a baseline from a TOML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// Save the baseline to a TOML file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Report frozen items whose content changed while the revision stayed the same
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let frozen: HashMap<_, _> = self
+            .items
+            .iter()
+            .map(|item| (item.id.as_str(), item.fingerprint.as_str()))
+            .collect();
+
+        items
+            .iter()
+            .filter_map(|item| {
+                let id = item.id().to_string();
+                let fingerprint = frozen.get(id.as_str())?;
+                if *fingerprint == Self::fingerprint(&item.item) {
+                    return None;
+                }
+
+                let location = item
+                    .item
+                    .location
+                    .as_ref()
+                    .map(|location| format!(" (at {})", location))
+                    .unwrap_or_default();
+                Some(Defect {
+                    defect_type: DefectType::FrozenItemChanged,
+                    description: format!(
+                        "Item {} is frozen but its content changed without a revision bump{}",
+                        id, location
+                    ),
+                    item_id: Some(item.id().clone()),
+                    location: item.item.location.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Hash the content of an item that is subject to change control
+    ///
+    /// Location, status and module are not part of the content, so moving an
+    /// item to another file does not count as a change.
+    pub fn fingerprint(item: &SpecificationItem) -> String {
+        let mut hasher = Sha256::new();
+        for field in [
+            &item.title,
+            &item.description,
+            &item.rationale,
+            &item.comment,
+        ] {
+            hasher.update(field.as_deref().unwrap_or_default());
+            hasher.update([0]);
+        }
+        for list in [&item.needs, &item.tags] {
+            hasher.update(list.join(","));
+            hasher.update([0]);
+        }
+        for ids in [&item.covers, &item.depends] {
+            let ids: Vec<_> = ids.iter().map(|id| id.to_string()).collect();
+            hasher.update(ids.join(","));
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SpecificationItemId;
+
+    fn item(description: &str, status: ItemStatus) -> LinkedSpecificationItem {
+        LinkedSpecificationItem::new(
+            SpecificationItem::builder(SpecificationItemId::parse("req~export~1").unwrap())
+                .description(description.to_string())
+                .status(status)
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_frozen_item_change_is_defect() {
+        let baseline = Baseline::from_items(&[item("Export as CSV.", ItemStatus::Approved)]);
+        assert_eq!(baseline.items.len(), 1);
+        assert!(
+            Baseline::from_items(&[item("Export as CSV.", ItemStatus::Draft)])
+                .items
+                .is_empty()
+        );
+
+        assert!(baseline
+            .check(&[item("Export as CSV.", ItemStatus::Approved)])
+            .is_empty());
+
+        let defects = baseline.check(&[item("Export as CSV and XLSX.", ItemStatus::Approved)]);
+        assert_eq!(defects.len(), 1);
+        assert_eq!(defects[0].defect_type, DefectType::FrozenItemChanged);
+    }
+}
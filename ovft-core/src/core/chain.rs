@@ -0,0 +1,144 @@
+use crate::core::{
+    CoverageStatus, LinkStatus, LinkedSpecificationItem, Location, SpecificationItemId, TraceResult,
+};
+use crate::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Coverage subtree of an item: the items covering it, directly or indirectly
+///
+/// Used for impact analysis, e.g. which design, code and tests are affected by
+/// a change of a feature.
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceChain {
+    /// ID of the item
+    pub id: SpecificationItemId,
+    /// Title of the item
+    pub title: String,
+    /// Where the item is defined
+    pub location: Option<Location>,
+    /// Coverage status of the item
+    pub coverage_status: CoverageStatus,
+    /// Status of the link to the parent item, `None` for the root
+    pub link_status: Option<LinkStatus>,
+    /// Items covering this item
+    pub children: Vec<TraceChain>,
+}
+
+impl TraceChain {
+    /// Extract the chain below the item with the given ID
+    pub fn for_item(trace_result: &TraceResult, root_id: &SpecificationItemId) -> Result<Self> {
+        let items_by_id: HashMap<_, _> = trace_result
+            .items
+            .iter()
+            .map(|item| (item.id(), item))
+            .collect();
+        let root = items_by_id
+            .get(root_id)
+            .ok_or_else(|| crate::Error::RequirementNotFound(root_id.to_string()))?;
+
+        Ok(Self::node(root, None, &items_by_id, &mut Vec::new()))
+    }
+
+    fn node<'a>(
+        item: &'a LinkedSpecificationItem,
+        link_status: Option<LinkStatus>,
+        items_by_id: &HashMap<&SpecificationItemId, &'a LinkedSpecificationItem>,
+        path: &mut Vec<&'a SpecificationItemId>,
+    ) -> Self {
+        let sources: Vec<_> = item
+            .incoming_links
+            .iter()
+            .filter_map(|link| Some((*items_by_id.get(link.source_id.as_ref()?)?, link)))
+            // Circular coverage would never end
+            .filter(|(source, _)| source.id() != item.id() && !path.contains(&source.id()))
+            .collect();
+
+        path.push(item.id());
+        let children = sources
+            .into_iter()
+            .map(|(source, link)| Self::node(source, Some(link.status.clone()), items_by_id, path))
+            .collect();
+        path.pop();
+
+        Self {
+            id: item.id().clone(),
+            title: item.title(),
+            location: item.item.location.clone(),
+            coverage_status: item.coverage_status.clone(),
+            link_status,
+            children,
+        }
+    }
+
+    /// Number of items in the chain, including the root
+    pub fn item_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(TraceChain::item_count)
+            .sum::<usize>()
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{}", "  ".repeat(depth))?;
+        if let Some(status) = &self.link_status {
+            write!(f, "|<-- ({}) ", status)?;
+        }
+        write!(f, "{} [{}] {}", self.id, self.coverage_status, self.title)?;
+        if let Some(location) = &self.location {
+            write!(f, " ({})", location)?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.write_tree(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TraceChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+
+    #[test]
+    fn test_chain_of_root_item() {
+        let id = |artifact_type: &str, name: &str, revision: u32| {
+            SpecificationItemId::new(artifact_type.to_string(), name.to_string(), revision)
+        };
+        let items = vec![
+            SpecificationItem::builder(id("feat", "auth", 1))
+                .needs("req".to_string())
+                .build(),
+            SpecificationItem::builder(id("req", "login", 1))
+                .covers(id("feat", "auth", 1))
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(id("impl", "login", 1))
+                .covers(id("req", "login", 1))
+                .build(),
+            SpecificationItem::builder(id("req", "unrelated", 1)).build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+
+        let chain = trace_result.chain(&id("feat", "auth", 1)).unwrap();
+        assert_eq!(chain.item_count(), 3);
+        assert_eq!(
+            chain.children[0].children[0].link_status,
+            Some(LinkStatus::CoveredShallow)
+        );
+        assert!(chain
+            .to_string()
+            .contains("    |<-- (covered shallow) impl~login~1 [covered]"));
+        assert!(trace_result.chain(&id("feat", "missing", 1)).is_err());
+    }
+}
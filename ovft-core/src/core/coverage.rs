@@ -0,0 +1,108 @@
+use crate::core::{LinkedSpecificationItem, SpecificationItemId};
+use std::collections::{HashMap, HashSet};
+
+/// Resolves transitive ("deep") coverage over a set of linked items
+///
+/// An item is deeply covered when it is covered itself and every item
+/// covering it is deeply covered as well.
+pub struct DeepCoverage<'a> {
+    items_by_id: HashMap<&'a SpecificationItemId, &'a LinkedSpecificationItem>,
+}
+
+impl<'a> DeepCoverage<'a> {
+    pub fn new(items: &'a [LinkedSpecificationItem]) -> Self {
+        Self {
+            items_by_id: items.iter().map(|item| (item.id(), item)).collect(),
+        }
+    }
+
+    /// Check whether the item with the given ID is deeply covered
+    pub fn is_deeply_covered(&self, id: &SpecificationItemId) -> bool {
+        self.check(id, &mut HashSet::new())
+    }
+
+    /// Check whether the item is covered by the given artifact type and all
+    /// covering items of that type are deeply covered
+    pub fn is_deeply_covered_by(&self, id: &SpecificationItemId, artifact_type: &str) -> bool {
+        let Some(item) = self.items_by_id.get(id) else {
+            return false;
+        };
+
+        let mut sources = item
+            .incoming_links
+            .iter()
+            .filter(|link| link.status.is_valid_coverage())
+            .filter_map(|link| link.source_id.as_ref())
+            .filter(|source_id| item.satisfies_need(artifact_type, &source_id.artifact_type))
+            .peekable();
+        sources.peek().is_some() && sources.all(|source_id| self.is_deeply_covered(source_id))
+    }
+
+    fn check<'b>(
+        &self,
+        id: &'b SpecificationItemId,
+        visited: &mut HashSet<&'b SpecificationItemId>,
+    ) -> bool
+    where
+        'a: 'b,
+    {
+        // Cycles are reported separately, do not fail deep coverage because of them
+        if !visited.insert(id) {
+            return true;
+        }
+
+        let Some(item) = self.items_by_id.get(id) else {
+            return false;
+        };
+
+        item.is_covered()
+            && item
+                .incoming_links
+                .iter()
+                .filter(|link| link.status.is_valid_coverage())
+                .all(|link| match &link.source_id {
+                    Some(source_id) => self.check(source_id, visited),
+                    None => true,
+                })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+
+    #[test]
+    fn test_deep_coverage() {
+        let feat = SpecificationItemId::new("feat".to_string(), "login".to_string(), 1);
+        let req = SpecificationItemId::new("req".to_string(), "login".to_string(), 1);
+        let dsn = SpecificationItemId::new("dsn".to_string(), "login".to_string(), 1);
+
+        let items = vec![
+            SpecificationItem::builder(feat.clone())
+                .needs("req".to_string())
+                .build(),
+            SpecificationItem::builder(req.clone())
+                .covers(feat.clone())
+                .needs("dsn".to_string())
+                .build(),
+            SpecificationItem::builder(dsn.clone())
+                .covers(req.clone())
+                .needs("impl".to_string())
+                .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let deep = DeepCoverage::new(&linked);
+
+        // feat is covered by req, but the chain breaks at dsn which lacks impl
+        assert!(linked
+            .iter()
+            .find(|li| li.item.id == feat)
+            .unwrap()
+            .is_covered());
+        assert!(!deep.is_deeply_covered(&feat));
+        assert!(!deep.is_deeply_covered(&dsn));
+        assert!(!deep.is_deeply_covered_by(&feat, "req"));
+        assert!(!deep.is_deeply_covered_by(&dsn, "impl"));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::core::{Defect, DefectType, LinkedSpecificationItem, SpecificationItemId};
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Checks item IDs mentioned in free text (description, rationale, comment)
+/// and reports those that do not resolve to any known item
+pub struct CrossReferenceChecker {
+    /// Regex for matching item IDs like req~user-login~1 anywhere in prose
+    id_regex: Regex,
+}
+
+impl CrossReferenceChecker {
+    pub fn new() -> Self {
+        Self {
+            id_regex: Regex::new(r"\b([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)\b").unwrap(),
+        }
+    }
+
+    /// Return one warning per unresolved textual reference
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let known_ids: HashSet<&SpecificationItemId> = items.iter().map(|li| li.id()).collect();
+        let mut warnings = Vec::new();
+
+        for linked_item in items {
+            let item = &linked_item.item;
+            let fields = [
+                ("description", &item.description),
+                ("rationale", &item.rationale),
+                ("comment", &item.comment),
+            ];
+
+            let mut reported = HashSet::new();
+            for (field, text) in fields {
+                let Some(text) = text else { continue };
+                for reference in self.find_references(text) {
+                    if !known_ids.contains(&reference) && reported.insert(reference.clone()) {
+                        warnings.push(Defect {
+                            defect_type: DefectType::BrokenReference,
+                            description: format!(
+                                "Item {} mentions non-existing item {} in its {}",
+                                item.id, reference, field
+                            ),
+                            item_id: Some(item.id.clone()),
+                            location: item.location.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Extract all item IDs mentioned in a piece of text
+    fn find_references(&self, text: &str) -> Vec<SpecificationItemId> {
+        self.id_regex
+            .captures_iter(text)
+            .filter_map(|captures| {
+                let revision = captures.get(3)?.as_str().parse::<u32>().ok()?;
+                Some(SpecificationItemId::new(
+                    captures.get(1)?.as_str().to_string(),
+                    captures.get(2)?.as_str().to_string(),
+                    revision,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Default for CrossReferenceChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SpecificationItem;
+
+    #[test]
+    fn test_unresolved_prose_references() {
+        let existing = SpecificationItemId::new("req".to_string(), "login".to_string(), 1);
+        let item = SpecificationItem::builder(SpecificationItemId::new(
+            "dsn".to_string(),
+            "auth".to_string(),
+            1,
+        ))
+        .description("Implements req~login~1 as described in req~logout~1.".to_string())
+        .rationale("See also req~logout~1 and arch~session~2.".to_string())
+        .build();
+
+        let items = vec![
+            LinkedSpecificationItem::new(SpecificationItem::new(existing)),
+            LinkedSpecificationItem::new(item),
+        ];
+
+        let warnings = CrossReferenceChecker::new().check(&items);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].description.contains("req~logout~1"));
+        assert!(warnings[1].description.contains("arch~session~2"));
+        assert!(warnings
+            .iter()
+            .all(|w| w.defect_type == DefectType::BrokenReference));
+    }
+}
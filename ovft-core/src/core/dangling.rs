@@ -0,0 +1,86 @@
+use crate::core::{Defect, DefectType, LinkedSpecificationItem};
+
+/// Finds items that cover nothing although other items need their artifact type
+///
+/// Such items are not part of any trace chain, e.g. a design item that no
+/// requirement is refined by, typically dead requirements or copy-paste
+/// leftovers. Items of artifact types no item needs, like features, are the
+/// roots of the chains and never dangling.
+pub struct DanglingItemChecker;
+
+impl DanglingItemChecker {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Return one warning per dangling item
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        items
+            .iter()
+            .filter(|item| item.item.covers.is_empty())
+            .filter(|item| {
+                let artifact_type = &item.id().artifact_type;
+                items.iter().any(|other| {
+                    other
+                        .item
+                        .needs
+                        .iter()
+                        .any(|needed_type| other.satisfies_need(needed_type, artifact_type))
+                })
+            })
+            .map(|item| Defect {
+                defect_type: DefectType::DanglingItem,
+                description: format!(
+                    "Item {} covers nothing, although {} items are needed to cover other items",
+                    item.id(),
+                    item.id().artifact_type
+                ),
+                item_id: Some(item.id().clone()),
+                location: item.item.location.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Default for DanglingItemChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, Location, SpecificationItem, SpecificationItemId};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_dangling_items() {
+        let id = |artifact_type: &str, name: &str| {
+            SpecificationItemId::new(artifact_type.to_string(), name.to_string(), 1)
+        };
+        let items = vec![
+            SpecificationItem::builder(id("feat", "login"))
+                .needs("req".to_string())
+                .build(),
+            SpecificationItem::builder(id("req", "login"))
+                .covers(id("feat", "login"))
+                .needs("dsn".to_string())
+                .build(),
+            SpecificationItem::builder(id("dsn", "login"))
+                .covers(id("req", "login"))
+                .build(),
+            SpecificationItem::builder(id("dsn", "leftover"))
+                .location(Location::new(PathBuf::from("docs/design.md"), 20))
+                .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+
+        let warnings = DanglingItemChecker::new().check(&linked);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].item_id, Some(id("dsn", "leftover")));
+        assert_eq!(warnings[0].defect_type, DefectType::DanglingItem);
+        let location = warnings[0].location.as_ref().unwrap();
+        assert_eq!(location.to_string(), "docs/design.md:20");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::core::{Defect, DefectType};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Defect that is known and accepted, e.g. a historical coverage gap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AcceptedDefect {
+    /// Type of the defect
+    pub defect_type: DefectType,
+    /// ID of the affected item, if any
+    #[serde(default)]
+    pub item_id: Option<String>,
+    /// Description of the defect when it was accepted
+    pub description: String,
+}
+
+/// Defects accepted when adopting tracing, stored in a JSON file
+///
+/// Only defects that are not in the baseline fail the trace, so that a legacy
+/// project can start tracing without fixing all existing gaps first. Defects of
+/// an item are matched by defect type and item ID, since their descriptions
+/// change with the coverage of the item; other defects by their description.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefectBaseline {
+    #[serde(default)]
+    pub defects: Vec<AcceptedDefect>,
+}
+
+impl DefectBaseline {
+    /// Accept all given defects
+    pub fn from_defects(defects: &[Defect]) -> Self {
+        let mut accepted: Vec<_> = defects
+            .iter()
+            .map(|defect| AcceptedDefect {
+                defect_type: defect.defect_type.clone(),
+                item_id: defect.item_id.as_ref().map(|id| id.to_string()),
+                description: defect.description.clone(),
+            })
+            .collect();
+        accepted.sort_by(|a, b| (&a.item_id, &a.description).cmp(&(&b.item_id, &b.description)));
+
+        Self { defects: accepted }
+    }
+
+    /// Load a baseline from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the baseline to a JSON file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Split defects into new ones and the ones accepted by the baseline
+    pub fn partition(&self, defects: Vec<Defect>) -> (Vec<Defect>, Vec<Defect>) {
+        let accepted: HashSet<_> = self
+            .defects
+            .iter()
+            .map(|defect| {
+                Self::key(
+                    &defect.defect_type,
+                    defect.item_id.clone(),
+                    &defect.description,
+                )
+            })
+            .collect();
+        defects.into_iter().partition(|defect| {
+            let item_id = defect.item_id.as_ref().map(|id| id.to_string());
+            !accepted.contains(&Self::key(
+                &defect.defect_type,
+                item_id,
+                &defect.description,
+            ))
+        })
+    }
+
+    fn key(
+        defect_type: &DefectType,
+        item_id: Option<String>,
+        description: &str,
+    ) -> (String, Option<String>, Option<String>) {
+        let description = item_id.is_none().then(|| description.to_string());
+        (defect_type.to_string(), item_id, description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SpecificationItemId;
+    use tempfile::TempDir;
+
+    fn defect(item_id: Option<&str>, description: &str) -> Defect {
+        Defect {
+            defect_type: DefectType::UncoveredItem,
+            description: description.to_string(),
+            item_id: item_id.map(|id| SpecificationItemId::parse(id).unwrap()),
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_only_new_defects_remain() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("defect-baseline.json");
+        DefectBaseline::from_defects(&[
+            defect(Some("req~legacy~1"), "Missing coverage: impl"),
+            defect(None, "Unknown item"),
+        ])
+        .save(&path)
+        .unwrap();
+
+        let baseline = DefectBaseline::load(&path).unwrap();
+        let (new, accepted) = baseline.partition(vec![
+            defect(Some("req~legacy~1"), "Missing coverage: impl, utest"),
+            defect(Some("req~feature~1"), "Missing coverage: impl"),
+            defect(None, "Unknown item"),
+        ]);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].item_id.as_ref().unwrap().name, "feature");
+        assert_eq!(accepted.len(), 2);
+    }
+}
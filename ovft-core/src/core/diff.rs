@@ -0,0 +1,189 @@
+use crate::core::{Defect, ReportMetadata, SpecificationItemId, TraceResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Changes between two trace results, e.g. of a branch and its merge base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceDiff {
+    /// Defects that did not exist in the previous trace
+    pub new_defects: Vec<Defect>,
+    /// Defects of the previous trace that are gone
+    pub fixed_defects: Vec<Defect>,
+    /// Items that did not exist in the previous trace
+    pub added_items: Vec<SpecificationItemId>,
+    /// Items of the previous trace that are gone
+    pub removed_items: Vec<SpecificationItemId>,
+    /// Coverage of the artifact types whose coverage changed, sorted by artifact type
+    pub coverage_changes: Vec<CoverageChange>,
+    /// Change of the overall coverage percentage in percentage points
+    pub coverage_change: f64,
+    /// Metadata of the current trace
+    #[serde(default)]
+    pub metadata: ReportMetadata,
+}
+
+/// Coverage of an artifact type before and after a change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageChange {
+    /// Artifact type
+    pub artifact_type: String,
+    /// Covered and total items in the previous trace
+    pub previous: (usize, usize),
+    /// Covered and total items in the current trace
+    pub current: (usize, usize),
+    /// Change of the coverage percentage in percentage points
+    pub percentage_change: f64,
+}
+
+impl TraceDiff {
+    /// Compare a trace against a previous one
+    pub fn between(previous: &TraceResult, current: &TraceResult) -> Self {
+        let previous_ids = Self::item_ids(previous);
+        let current_ids = Self::item_ids(current);
+
+        let mut artifact_types: BTreeSet<&str> = BTreeSet::new();
+        artifact_types.extend(previous.coverage_summary.keys().map(String::as_str));
+        artifact_types.extend(current.coverage_summary.keys().map(String::as_str));
+        let coverage_changes = artifact_types
+            .into_iter()
+            .filter_map(|artifact_type| {
+                let coverage = |trace_result: &TraceResult| {
+                    trace_result
+                        .coverage_summary
+                        .get(artifact_type)
+                        .map(|summary| ((summary.covered, summary.total), summary.percentage))
+                        .unwrap_or(((0, 0), 0.0))
+                };
+                let (previous, previous_percentage) = coverage(previous);
+                let (current, percentage) = coverage(current);
+                (previous != current).then(|| CoverageChange {
+                    artifact_type: artifact_type.to_string(),
+                    previous,
+                    current,
+                    percentage_change: percentage - previous_percentage,
+                })
+            })
+            .collect();
+
+        Self {
+            new_defects: Self::defects_missing_in(&current.defects, &previous.defects),
+            fixed_defects: Self::defects_missing_in(&previous.defects, &current.defects),
+            added_items: current_ids
+                .iter()
+                .filter(|(key, _)| !previous_ids.contains_key(*key))
+                .map(|(_, id)| (*id).clone())
+                .collect(),
+            removed_items: previous_ids
+                .iter()
+                .filter(|(key, _)| !current_ids.contains_key(*key))
+                .map(|(_, id)| (*id).clone())
+                .collect(),
+            coverage_changes,
+            coverage_change: current.coverage_percentage() - previous.coverage_percentage(),
+            metadata: current.metadata.clone(),
+        }
+    }
+
+    /// Check whether the traces are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.new_defects.is_empty()
+            && self.fixed_defects.is_empty()
+            && self.added_items.is_empty()
+            && self.removed_items.is_empty()
+            && self.coverage_changes.is_empty()
+    }
+
+    /// Item IDs keyed by their string form, so that they are sorted
+    fn item_ids(trace_result: &TraceResult) -> BTreeMap<String, &SpecificationItemId> {
+        trace_result
+            .items
+            .iter()
+            .map(|item| (item.id().to_string(), item.id()))
+            .collect()
+    }
+
+    /// The defects of `defects` that have no equal in `others`
+    fn defects_missing_in(defects: &[Defect], others: &[Defect]) -> Vec<Defect> {
+        let others: BTreeSet<_> = others.iter().map(Self::defect_key).collect();
+        defects
+            .iter()
+            .filter(|defect| !others.contains(&Self::defect_key(defect)))
+            .cloned()
+            .collect()
+    }
+
+    fn defect_key(defect: &Defect) -> (String, Option<String>, &str) {
+        (
+            defect.defect_type.to_string(),
+            defect.item_id.as_ref().map(|id| id.to_string()),
+            defect.description.as_str(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CoverageStatus, CoverageSummary, DefectType, Linker, SpecificationItem};
+    use std::collections::HashMap;
+
+    fn trace_result(names: &[&str], defects: &[&str], covered: usize) -> TraceResult {
+        let items = names
+            .iter()
+            .map(|name| {
+                SpecificationItem::builder(SpecificationItemId::new(
+                    "req".to_string(),
+                    name.to_string(),
+                    1,
+                ))
+                .build()
+            })
+            .collect();
+        let linked = Linker::new().link_items(items).unwrap();
+        TraceResult {
+            defect_count: defects.len(),
+            defects: defects
+                .iter()
+                .map(|description| Defect {
+                    defect_type: DefectType::UncoveredItem,
+                    description: description.to_string(),
+                    item_id: None,
+                    location: None,
+                })
+                .collect(),
+            coverage_summary: HashMap::from([(
+                "req".to_string(),
+                CoverageSummary {
+                    total: names.len(),
+                    covered,
+                    percentage: covered as f64 / names.len() as f64 * 100.0,
+                    status: CoverageStatus::Partial,
+                },
+            )]),
+            is_success: defects.is_empty(),
+            ..TraceResult::from_items(linked)
+        }
+    }
+
+    #[test]
+    fn test_diff_lists_changed_defects_items_and_coverage() {
+        let previous = trace_result(&["a", "b"], &["a uncovered", "b uncovered"], 0);
+        let current = trace_result(&["b", "c"], &["b uncovered", "c uncovered"], 1);
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.new_defects[0].description, "c uncovered");
+        assert_eq!(diff.fixed_defects[0].description, "a uncovered");
+        assert_eq!(diff.added_items[0].to_string(), "req~c~1");
+        assert_eq!(diff.removed_items[0].to_string(), "req~a~1");
+        assert_eq!(
+            diff.coverage_changes,
+            vec![CoverageChange {
+                artifact_type: "req".to_string(),
+                previous: (0, 2),
+                current: (1, 2),
+                percentage_change: 50.0,
+            }]
+        );
+        assert!(current.diff(&current).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::metrics::{MetricsCollector, FUNCTION_SEARCH_LINES};
+use crate::core::{Defect, DefectType, LinkedSpecificationItem};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
+
+/// Line hit counts read from code coverage reports
+#[derive(Debug, Clone, Default)]
+pub struct LineCoverage {
+    files: HashMap<PathBuf, BTreeMap<u32, u64>>,
+}
+
+impl LineCoverage {
+    /// Record hits for a line, adding to hits from other reports
+    pub fn add_hits(&mut self, file: &Path, line: u32, hits: u64) {
+        *self
+            .files
+            .entry(Self::normalize(file))
+            .or_default()
+            .entry(line)
+            .or_default() += hits;
+    }
+
+    /// Hit count of a line, or `None` if the line is not instrumented
+    pub fn hits(&self, file: &Path, line: u32) -> Option<u64> {
+        self.lines(file)?.get(&line).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Find the line hits of a file
+    ///
+    /// Coverage tools usually report absolute paths while tags are located
+    /// relative to the source directories, so files also match by path suffix.
+    fn lines(&self, file: &Path) -> Option<&BTreeMap<u32, u64>> {
+        let file = Self::normalize(file);
+        self.files.get(&file).or_else(|| {
+            self.files
+                .iter()
+                .find(|(path, _)| path.ends_with(&file) || file.ends_with(path))
+                .map(|(_, lines)| lines)
+        })
+    }
+
+    fn normalize(path: &Path) -> PathBuf {
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect()
+    }
+}
+
+/// Flags implementation tags whose code is never executed by any test
+pub struct ExecutionChecker<'a> {
+    coverage: &'a LineCoverage,
+    /// Artifact type of the implementation tags to check
+    artifact_type: String,
+}
+
+impl<'a> ExecutionChecker<'a> {
+    pub fn new<S: Into<String>>(coverage: &'a LineCoverage, artifact_type: S) -> Self {
+        Self {
+            coverage,
+            artifact_type: artifact_type.into(),
+        }
+    }
+
+    /// Return one warning per implementation tag whose function has only unexecuted lines
+    ///
+    /// Tags in files without coverage data, or without instrumented lines in
+    /// the following function, are not reported.
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let mut file_cache: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        let mut warnings = Vec::new();
+
+        for linked_item in items {
+            let item = &linked_item.item;
+            if item.id.artifact_type != self.artifact_type || item.covers.is_empty() {
+                continue;
+            }
+            let Some(location) = &item.location else {
+                continue;
+            };
+            let Some(file_lines) = self.coverage.lines(&location.path) else {
+                continue;
+            };
+
+            let last_line = match &item.end_location {
+                // Block tags state their region explicitly
+                Some(end) => end.line,
+                None => {
+                    let lines = file_cache
+                        .entry(location.path.clone())
+                        .or_insert_with(|| MetricsCollector::read_lines(&location.path));
+                    let tag_line = location.line as usize;
+                    // Without a recognizable block only the lines right after the tag are checked
+                    MetricsCollector::function_span(lines, tag_line)
+                        .map(|(_, close_line)| close_line + 1)
+                        .unwrap_or(tag_line + FUNCTION_SEARCH_LINES) as u32
+                }
+            };
+
+            let mut instrumented = file_lines.range(location.line..=last_line).peekable();
+            if instrumented.peek().is_none() {
+                continue;
+            }
+            if instrumented.all(|(_, hits)| *hits == 0) {
+                let covered: Vec<_> = item.covers.iter().map(|id| id.to_string()).collect();
+                warnings.push(Defect {
+                    defect_type: DefectType::UnexercisedImplementation,
+                    description: format!(
+                        "Item {} (at {}) claims to implement {} but its code is never executed by any test",
+                        item.id,
+                        location,
+                        covered.join(", ")
+                    ),
+                    item_id: Some(item.id.clone()),
+                    location: Some(location.clone()),
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, Location, SpecificationItem, SpecificationItemId};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_unexecuted_implementation_is_flagged() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("lib.rs");
+        fs::write(
+            &source,
+            "// tag\nfn export() {\n    run();\n}\n\n// tag\nfn import() {\n    run();\n}\n",
+        )
+        .unwrap();
+
+        let mut coverage = LineCoverage::default();
+        for (line, hits) in [(2, 3), (3, 3), (7, 0), (8, 0)] {
+            coverage.add_hits(&source, line, hits);
+        }
+
+        let req = SpecificationItemId::parse("req~transfer~1").unwrap();
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("impl~export~0").unwrap())
+                .covers(req.clone())
+                .location(Location::new(source.clone(), 1))
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("impl~import~0").unwrap())
+                .covers(req)
+                .location(Location::new(source, 6))
+                .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+
+        let warnings = ExecutionChecker::new(&coverage, "impl").check(&items);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].item_id.as_ref().unwrap().to_string(),
+            "impl~import~0"
+        );
+        assert!(warnings[0].description.contains("req~transfer~1"));
+    }
+}
@@ -0,0 +1,301 @@
+use crate::core::{Defect, LinkStatus, LinkedSpecificationItem, SpecificationItemId, TraceResult};
+use crate::Result;
+use std::fmt;
+
+/// Reasoning chain explaining the trace state of a single item
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// The explained item
+    pub item: LinkedSpecificationItem,
+    /// One entry per needed artifact type
+    pub needs: Vec<NeedExplanation>,
+    /// Incoming coverage by artifact types the item does not need
+    pub unwanted: Vec<String>,
+    /// Incoming coverage of another revision of the item, with the reason
+    pub stale_coverage: Vec<String>,
+    /// Outgoing links that are not valid, with the reason
+    pub broken_links: Vec<String>,
+    /// Defects reported for the item
+    pub defects: Vec<Defect>,
+    /// Warnings reported for the item
+    pub warnings: Vec<Defect>,
+}
+
+/// How one needed artifact type is satisfied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeedExplanation {
+    /// Needed artifact type
+    pub artifact_type: String,
+    /// Covering items of that type, with their locations
+    pub satisfied_by: Vec<String>,
+}
+
+impl Explanation {
+    /// Explain the item with the given ID
+    pub fn for_item(trace_result: &TraceResult, id: &SpecificationItemId) -> Result<Self> {
+        let item = trace_result
+            .items
+            .iter()
+            .find(|item| item.id() == id)
+            .ok_or_else(|| crate::Error::RequirementNotFound(id.to_string()))?;
+
+        let needs = item
+            .item
+            .needs
+            .iter()
+            .map(|needed_type| NeedExplanation {
+                artifact_type: needed_type.clone(),
+                satisfied_by: item
+                    .incoming_links
+                    .iter()
+                    .filter(|link| link.status.is_valid_coverage())
+                    .filter_map(|link| link.source_id.as_ref())
+                    .filter(|source_id| item.satisfies_need(needed_type, &source_id.artifact_type))
+                    .map(|source_id| Self::describe(trace_result, source_id))
+                    .collect(),
+            })
+            .collect();
+
+        let unwanted = item
+            .incoming_links
+            .iter()
+            .filter(|link| link.status == LinkStatus::CoveredUnwanted)
+            .filter_map(|link| link.source_id.as_ref())
+            .map(|source_id| Self::describe(trace_result, source_id))
+            .collect();
+
+        let stale_coverage = item
+            .incoming_links
+            .iter()
+            .filter_map(|link| {
+                let source_id = link.source_id.as_ref()?;
+                let covered_revision = trace_result
+                    .items
+                    .iter()
+                    .find(|other| other.id() == source_id)?
+                    .item
+                    .covers
+                    .iter()
+                    .find(|covered| {
+                        covered.artifact_type == id.artifact_type && covered.name == id.name
+                    })?
+                    .revision;
+                let reason = match link.status {
+                    LinkStatus::CoveredOutdated => format!(
+                        "covers outdated revision {}, current revision is {}",
+                        covered_revision, id.revision
+                    ),
+                    LinkStatus::CoveredPredated => format!(
+                        "covers revision {}, but only revision {} exists",
+                        covered_revision, id.revision
+                    ),
+                    _ => return None,
+                };
+                Some(format!(
+                    "{}: {}",
+                    Self::describe(trace_result, source_id),
+                    reason
+                ))
+            })
+            .collect();
+
+        let broken_links = item
+            .outgoing_links
+            .iter()
+            .filter_map(|link| {
+                let revisions = || Self::existing_revisions(trace_result, &link.target_id);
+                let reason = match link.status {
+                    LinkStatus::Orphaned => "no such item exists".to_string(),
+                    LinkStatus::Outdated => format!(
+                        "covers outdated revision {}, current revision is {}",
+                        link.target_id.revision,
+                        revisions()
+                    ),
+                    LinkStatus::Predated => format!(
+                        "covers revision {}, but only revision {} exists",
+                        link.target_id.revision,
+                        revisions()
+                    ),
+                    LinkStatus::Ambiguous => {
+                        format!("matches several revisions ({})", revisions())
+                    }
+                    LinkStatus::Duplicate => "item ID is defined more than once".to_string(),
+                    LinkStatus::BrokenDependency => match revisions() {
+                        revisions if revisions.is_empty() => {
+                            "depends on an item that does not exist".to_string()
+                        }
+                        revisions => format!(
+                            "depends on revision {}, but only revision {} exists",
+                            link.target_id.revision, revisions
+                        ),
+                    },
+                    _ => return None,
+                };
+                Some(format!("{}: {}", link.target_id, reason))
+            })
+            .collect();
+
+        let for_item = |defects: &[Defect]| -> Vec<Defect> {
+            defects
+                .iter()
+                .filter(|defect| defect.item_id.as_ref() == Some(id))
+                .cloned()
+                .collect()
+        };
+
+        Ok(Self {
+            item: item.clone(),
+            needs,
+            unwanted,
+            stale_coverage,
+            broken_links,
+            defects: for_item(&trace_result.defects),
+            warnings: for_item(&trace_result.warnings),
+        })
+    }
+
+    /// Format an item ID with its location if known
+    fn describe(trace_result: &TraceResult, id: &SpecificationItemId) -> String {
+        let location = trace_result
+            .items
+            .iter()
+            .find(|item| item.id() == id)
+            .and_then(|item| item.item.location.as_ref());
+        match location {
+            Some(location) => format!("{} ({})", id, location),
+            None => id.to_string(),
+        }
+    }
+
+    /// List the existing revisions of an item, e.g. "1, 3"
+    fn existing_revisions(trace_result: &TraceResult, target_id: &SpecificationItemId) -> String {
+        trace_result
+            .items
+            .iter()
+            .map(|item| item.id())
+            .filter(|id| id.artifact_type == target_id.artifact_type && id.name == target_id.name)
+            .map(|id| id.revision.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let item = &self.item.item;
+        writeln!(f, "{} - {}", item.id, item.title_or_fallback())?;
+        if let Some(location) = &item.location {
+            writeln!(f, "  Location: {}", location)?;
+        }
+        writeln!(f, "  Status: {}", item.status)?;
+        writeln!(f, "  Coverage: {}", self.item.coverage_status)?;
+
+        if self.needs.is_empty() {
+            writeln!(f, "  Needs: nothing (terminating item)")?;
+        } else {
+            writeln!(f, "  Needs:")?;
+            for need in &self.needs {
+                if need.satisfied_by.is_empty() {
+                    writeln!(
+                        f,
+                        "    {}: MISSING - no {} item covers {}",
+                        need.artifact_type, need.artifact_type, item.id
+                    )?;
+                } else {
+                    writeln!(
+                        f,
+                        "    {}: satisfied by {}",
+                        need.artifact_type,
+                        need.satisfied_by.join(", ")
+                    )?;
+                }
+            }
+        }
+
+        for unwanted in &self.unwanted {
+            writeln!(
+                f,
+                "  Unwanted coverage: {} (type not listed in Needs)",
+                unwanted
+            )?;
+        }
+        for stale in &self.stale_coverage {
+            writeln!(f, "  Stale coverage: {}", stale)?;
+        }
+
+        if !item.covers.is_empty() {
+            writeln!(
+                f,
+                "  Covers: {}",
+                item.covers
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        for broken in &self.broken_links {
+            writeln!(f, "  Broken link: {}", broken)?;
+        }
+
+        for defect in &self.defects {
+            writeln!(
+                f,
+                "  Defect ({}): {}",
+                defect.defect_type, defect.description
+            )?;
+        }
+        for warning in &self.warnings {
+            writeln!(
+                f,
+                "  Warning ({}): {}",
+                warning.defect_type, warning.description
+            )?;
+        }
+
+        if !self.item.is_defect && self.defects.is_empty() {
+            writeln!(f, "  No defects")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, Location, SpecificationItem};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_explain_missing_and_broken() {
+        let req = SpecificationItemId::parse("req~export~1").unwrap();
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("dsn".to_string())
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("dsn~export~1").unwrap())
+                .covers(req.clone())
+                .covers(SpecificationItemId::parse("req~export~2").unwrap())
+                .location(Location::new(PathBuf::from("docs/design.md"), 7))
+                .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+
+        let explanation = Explanation::for_item(&trace_result, &req).unwrap();
+        let text = explanation.to_string();
+        assert!(text.contains("dsn: satisfied by dsn~export~1 (docs/design.md:7)"));
+        assert!(text.contains("impl: MISSING"));
+
+        let dsn = SpecificationItemId::parse("dsn~export~1").unwrap();
+        let explanation = Explanation::for_item(&trace_result, &dsn).unwrap();
+        assert_eq!(
+            explanation.broken_links,
+            vec!["req~export~2: covers revision 2, but only revision 1 exists".to_string()]
+        );
+
+        let unknown = SpecificationItemId::parse("req~unknown~1").unwrap();
+        assert!(Explanation::for_item(&trace_result, &unknown).is_err());
+    }
+}
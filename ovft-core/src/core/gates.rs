@@ -0,0 +1,159 @@
+use crate::config::TagGate;
+use crate::core::{DeepCoverage, LinkedSpecificationItem, SpecificationItemId};
+use serde::{Deserialize, Serialize};
+
+/// Outcome of evaluating a single tag gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateResult {
+    /// Tag the gate applies to
+    pub tag: String,
+    /// Number of items carrying the tag
+    pub total: usize,
+    /// Number of tagged items satisfying the coverage requirement
+    pub covered: usize,
+    /// Coverage percentage of the tagged items
+    pub percentage: f64,
+    /// Required coverage percentage
+    pub min_coverage: f64,
+    /// Whether the gate is satisfied
+    pub passed: bool,
+    /// Human-readable reasons why the gate failed
+    pub violations: Vec<String>,
+}
+
+/// Evaluates tag-scoped coverage gates independently of the global result
+pub struct GateChecker<'a> {
+    gates: &'a [TagGate],
+}
+
+impl<'a> GateChecker<'a> {
+    pub fn new(gates: &'a [TagGate]) -> Self {
+        Self { gates }
+    }
+
+    /// Evaluate all gates against the linked items
+    pub fn evaluate(&self, items: &[LinkedSpecificationItem]) -> Vec<GateResult> {
+        let deep_coverage = DeepCoverage::new(items);
+        self.gates
+            .iter()
+            .map(|gate| Self::evaluate_gate(gate, items, &deep_coverage))
+            .collect()
+    }
+
+    fn evaluate_gate(
+        gate: &TagGate,
+        items: &[LinkedSpecificationItem],
+        deep_coverage: &DeepCoverage,
+    ) -> GateResult {
+        let tagged: Vec<_> = items
+            .iter()
+            .filter(|item| item.item.tags.iter().any(|tag| tag == &gate.tag))
+            .collect();
+
+        let mut uncovered: Vec<&SpecificationItemId> = Vec::new();
+        let mut wrong_status = Vec::new();
+        for item in &tagged {
+            let covered = if gate.deep {
+                deep_coverage.is_deeply_covered(item.id())
+            } else {
+                item.is_covered()
+            };
+            if !covered {
+                uncovered.push(item.id());
+            }
+
+            let status = item.item.status.to_string();
+            if !gate.required_status.is_empty()
+                && !gate
+                    .required_status
+                    .iter()
+                    .any(|required| required.eq_ignore_ascii_case(&status))
+            {
+                wrong_status.push(format!("{} has status {}", item.id(), status));
+            }
+        }
+
+        let total = tagged.len();
+        let covered = total - uncovered.len();
+        let percentage = if total > 0 {
+            (covered as f64 / total as f64) * 100.0
+        } else {
+            100.0
+        };
+
+        let mut violations = Vec::new();
+        if percentage < gate.min_coverage {
+            let kind = if gate.deep {
+                "deep coverage"
+            } else {
+                "coverage"
+            };
+            violations.push(format!(
+                "{} is {:.1}% but at least {:.1}% is required (not covered: {})",
+                kind,
+                percentage,
+                gate.min_coverage,
+                uncovered
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        violations.extend(wrong_status);
+
+        GateResult {
+            tag: gate.tag.clone(),
+            total,
+            covered,
+            percentage,
+            min_coverage: gate.min_coverage,
+            passed: violations.is_empty(),
+            violations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ItemStatus, Linker, SpecificationItem};
+
+    #[test]
+    fn test_tag_gate_evaluation() {
+        let req_a = SpecificationItemId::new("req".to_string(), "brake".to_string(), 1);
+        let req_b = SpecificationItemId::new("req".to_string(), "steer".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req_a.clone())
+                .needs("impl".to_string())
+                .tag("safety".to_string())
+                .build(),
+            SpecificationItem::builder(req_b.clone())
+                .needs("impl".to_string())
+                .tag("safety".to_string())
+                .status(ItemStatus::Draft)
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "brake".to_string(),
+                1,
+            ))
+            .covers(req_a)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+
+        let mut strict = TagGate::new("safety");
+        strict.required_status = vec!["approved".to_string()];
+        let mut relaxed = TagGate::new("safety");
+        relaxed.min_coverage = 50.0;
+        let gates = vec![strict, relaxed];
+
+        let results = GateChecker::new(&gates).evaluate(&linked);
+        assert_eq!(results[0].total, 2);
+        assert_eq!(results[0].covered, 1);
+        assert!(!results[0].passed);
+        assert_eq!(results[0].violations.len(), 2);
+        assert!(results[1].passed);
+    }
+}
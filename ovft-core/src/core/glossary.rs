@@ -0,0 +1,180 @@
+use crate::config::GlossaryConfig;
+use crate::core::{Defect, DefectType, LinkedSpecificationItem, SpecificationItem};
+use crate::Result;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Checks item wording against the configured glossary
+pub struct GlossaryChecker {
+    /// Discouraged terms with the preferred replacement
+    preferred_terms: Vec<(String, Regex, String)>,
+    /// Forbidden words
+    forbidden_words: Vec<(String, Regex)>,
+    /// Required modal verbs per artifact type
+    required_modals: BTreeMap<String, Vec<(String, Regex)>>,
+}
+
+impl GlossaryChecker {
+    /// Create a checker from the glossary configuration
+    pub fn new(glossary: &GlossaryConfig) -> Result<Self> {
+        let preferred_terms = glossary
+            .preferred_terms
+            .iter()
+            .map(|(term, preferred)| Ok((term.clone(), Self::word_regex(term)?, preferred.clone())))
+            .collect::<Result<_>>()?;
+        let forbidden_words = glossary
+            .forbidden_words
+            .iter()
+            .map(|word| Ok((word.clone(), Self::word_regex(word)?)))
+            .collect::<Result<_>>()?;
+        let required_modals = glossary
+            .required_modals
+            .iter()
+            .map(|(artifact_type, modals)| {
+                let regexes = modals
+                    .iter()
+                    .map(|modal| Ok((modal.clone(), Self::word_regex(modal)?)))
+                    .collect::<Result<_>>()?;
+                Ok((artifact_type.clone(), regexes))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            preferred_terms,
+            forbidden_words,
+            required_modals,
+        })
+    }
+
+    /// Return one warning per terminology violation
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let mut warnings = Vec::new();
+
+        for linked_item in items {
+            let item = &linked_item.item;
+            let text = Self::item_text(item);
+
+            for (term, regex, preferred) in &self.preferred_terms {
+                if regex.is_match(&text) {
+                    warnings.push(Self::warning(
+                        item,
+                        format!("uses '{}' instead of preferred term '{}'", term, preferred),
+                    ));
+                }
+            }
+
+            for (word, regex) in &self.forbidden_words {
+                if regex.is_match(&text) {
+                    warnings.push(Self::warning(
+                        item,
+                        format!("uses forbidden word '{}'", word),
+                    ));
+                }
+            }
+
+            if let Some(modals) = self.required_modals.get(&item.id.artifact_type) {
+                // Only items that carry a description can be checked for modal verbs
+                if let Some(description) = &item.description {
+                    if !modals.is_empty()
+                        && !modals.iter().any(|(_, regex)| regex.is_match(description))
+                    {
+                        let names: Vec<_> =
+                            modals.iter().map(|(modal, _)| modal.as_str()).collect();
+                        warnings.push(Self::warning(
+                            item,
+                            format!("lacks a required modal verb ({})", names.join(", ")),
+                        ));
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Build a case-insensitive whole-word regex for a term
+    fn word_regex(term: &str) -> Result<Regex> {
+        Ok(Regex::new(&format!(r"(?i)\b{}\b", regex::escape(term)))?)
+    }
+
+    /// Concatenate all prose fields of an item
+    fn item_text(item: &SpecificationItem) -> String {
+        [
+            &item.title,
+            &item.description,
+            &item.rationale,
+            &item.comment,
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+
+    fn warning(item: &SpecificationItem, issue: String) -> Defect {
+        let location = item
+            .location
+            .as_ref()
+            .map(|location| format!(" (at {})", location))
+            .unwrap_or_default();
+
+        Defect {
+            defect_type: DefectType::Terminology,
+            description: format!("Item {} {}{}", item.id, issue, location),
+            item_id: Some(item.id.clone()),
+            location: item.location.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, SpecificationItemId};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_glossary_violations() {
+        let mut glossary = GlossaryConfig::default();
+        glossary
+            .preferred_terms
+            .insert("log in".to_string(), "sign in".to_string());
+        glossary.forbidden_words.push("etc".to_string());
+        glossary
+            .required_modals
+            .insert("req".to_string(), vec!["shall".to_string()]);
+        let checker = GlossaryChecker::new(&glossary).unwrap();
+
+        let bad = SpecificationItem::builder(SpecificationItemId::new(
+            "req".to_string(),
+            "login".to_string(),
+            1,
+        ))
+        .description("Users can Log In with passwords, tokens etc.".to_string())
+        .location(Location::new(PathBuf::from("spec.md"), 3))
+        .build();
+        let good = SpecificationItem::builder(SpecificationItemId::new(
+            "req".to_string(),
+            "logout".to_string(),
+            1,
+        ))
+        .description("The system shall let users sign out.".to_string())
+        .build();
+
+        let items = vec![
+            LinkedSpecificationItem::new(bad),
+            LinkedSpecificationItem::new(good),
+        ];
+        let warnings = checker.check(&items);
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings
+            .iter()
+            .all(|w| w.description.contains("req~login~1")));
+        assert!(warnings[0].description.contains("preferred term 'sign in'"));
+        assert!(warnings[0].description.ends_with("(at spec.md:3)"));
+        assert!(warnings[1].description.contains("forbidden word 'etc'"));
+        assert!(warnings[2].description.contains("modal verb (shall)"));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::core::TraceResult;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Compact summary of a single trace run stored in the run history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Time of the run in milliseconds since the Unix epoch
+    pub timestamp: u128,
+    /// Total number of items
+    pub total_items: usize,
+    /// Overall coverage percentage
+    pub coverage_percentage: f64,
+    /// Descriptions of all defects found
+    pub defects: BTreeSet<String>,
+}
+
+impl RunSummary {
+    /// Summarize a trace result at the current time
+    pub fn from_trace_result(trace_result: &TraceResult) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis())
+                .unwrap_or_default(),
+            total_items: trace_result.total_items,
+            coverage_percentage: trace_result.coverage_percentage(),
+            defects: trace_result
+                .defects
+                .iter()
+                .map(|defect| defect.description.clone())
+                .collect(),
+        }
+    }
+
+    /// Compare this run against a previous one
+    pub fn delta(&self, previous: &RunSummary) -> RunDelta {
+        RunDelta {
+            coverage_change: self.coverage_percentage - previous.coverage_percentage,
+            item_change: self.total_items as i64 - previous.total_items as i64,
+            new_defects: self
+                .defects
+                .difference(&previous.defects)
+                .cloned()
+                .collect(),
+            fixed_defects: previous
+                .defects
+                .difference(&self.defects)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Difference between two consecutive runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunDelta {
+    /// Change of the coverage percentage in percentage points
+    pub coverage_change: f64,
+    /// Change of the number of items
+    pub item_change: i64,
+    /// Defects that did not exist in the previous run
+    pub new_defects: Vec<String>,
+    /// Defects of the previous run that are gone
+    pub fixed_defects: Vec<String>,
+}
+
+impl RunDelta {
+    /// Check whether anything changed between the runs
+    pub fn is_unchanged(&self) -> bool {
+        self.coverage_change.abs() < 0.05
+            && self.item_change == 0
+            && self.new_defects.is_empty()
+            && self.fixed_defects.is_empty()
+    }
+}
+
+impl fmt::Display for RunDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_unchanged() {
+            return writeln!(f, "No changes since the previous run");
+        }
+
+        writeln!(
+            f,
+            "Since the previous run: coverage {:+.1}%, items {:+}, {} new defect(s), {} fixed defect(s)",
+            self.coverage_change,
+            self.item_change,
+            self.new_defects.len(),
+            self.fixed_defects.len()
+        )?;
+        for defect in &self.new_defects {
+            writeln!(f, "  + {}", defect)?;
+        }
+        for defect in &self.fixed_defects {
+            writeln!(f, "  - {}", defect)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run summaries stored as JSON files in a history directory
+pub struct RunHistory {
+    dir: PathBuf,
+}
+
+impl RunHistory {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Load the most recent run, if any
+    pub fn latest(&self) -> Result<Option<RunSummary>> {
+        let Some(path) = self.run_files()?.pop() else {
+            return Ok(None);
+        };
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Store a run and return its delta against the previously stored run
+    pub fn record(&self, summary: &RunSummary) -> Result<Option<RunDelta>> {
+        let delta = self.latest()?.map(|previous| summary.delta(&previous));
+
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("run-{:016}.json", summary.timestamp));
+        fs::write(path, serde_json::to_string_pretty(summary)?)?;
+
+        Ok(delta)
+    }
+
+    /// List stored run files, oldest first
+    fn run_files(&self) -> Result<Vec<PathBuf>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| Self::is_run_file(path))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn is_run_file(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("run-") && name.ends_with(".json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn summary(timestamp: u128, coverage_percentage: f64, defects: &[&str]) -> RunSummary {
+        RunSummary {
+            timestamp,
+            total_items: 10,
+            coverage_percentage,
+            defects: defects.iter().map(|defect| defect.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_record_returns_delta_to_previous_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = RunHistory::new(temp_dir.path().join("history"));
+
+        assert!(history
+            .record(&summary(1, 80.0, &["a", "b"]))
+            .unwrap()
+            .is_none());
+        let delta = history
+            .record(&summary(2, 90.0, &["b", "c"]))
+            .unwrap()
+            .unwrap();
+
+        assert!((delta.coverage_change - 10.0).abs() < f64::EPSILON);
+        assert_eq!(delta.new_defects, vec!["c".to_string()]);
+        assert_eq!(delta.fixed_defects, vec!["a".to_string()]);
+        assert_eq!(history.latest().unwrap().unwrap().timestamp, 2);
+    }
+}
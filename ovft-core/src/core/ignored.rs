@@ -0,0 +1,81 @@
+use crate::core::{Defect, SpecificationItemId};
+use crate::Result;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+/// Items left out of defect analysis by ID pattern, e.g. `req~legacy-*~*`
+///
+/// The items are still imported, linked and reported, and other items can
+/// cover them, but their defects and warnings are dropped and they do not count
+/// towards the coverage summary or `min_coverage`. This keeps old requirement
+/// sets in the trace while they are being migrated.
+pub struct IgnoredItems {
+    patterns: GlobSet,
+}
+
+impl IgnoredItems {
+    /// Compile the configured ID patterns
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = Glob::new(pattern).map_err(|e| {
+                crate::Error::Config(format!("Invalid ignore_items pattern '{}': {}", pattern, e))
+            })?;
+            builder.add(glob);
+        }
+        let patterns = builder
+            .build()
+            .map_err(|e| crate::Error::Config(format!("Invalid ignore_items patterns: {}", e)))?;
+        Ok(Self { patterns })
+    }
+
+    /// Check if an item is left out of defect analysis
+    pub fn is_ignored(&self, id: &SpecificationItemId) -> bool {
+        self.patterns.is_match(id.to_string())
+    }
+
+    /// Drop the defects of ignored items
+    pub fn retain(&self, defects: &mut Vec<Defect>) {
+        defects.retain(|defect| match &defect.item_id {
+            Some(id) => !self.is_ignored(id),
+            None => true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DefectType;
+
+    #[test]
+    fn test_defects_of_ignored_items_are_dropped() {
+        let ignored = IgnoredItems::new(&["req~legacy-*~*".to_string()]).unwrap();
+        let defect = |item_id: Option<&str>| Defect {
+            defect_type: DefectType::UncoveredItem,
+            description: "needs coverage".to_string(),
+            item_id: item_id.map(|id| SpecificationItemId::parse(id).unwrap()),
+            location: None,
+        };
+        let mut defects = vec![
+            defect(Some("req~legacy-export~3")),
+            defect(Some("req~export~1")),
+            defect(Some("dsn~legacy-export~1")),
+            defect(None),
+        ];
+
+        ignored.retain(&mut defects);
+        let ids: Vec<_> = defects
+            .iter()
+            .map(|defect| defect.item_id.as_ref().map(|id| id.to_string()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                Some("req~export~1".to_string()),
+                Some("dsn~legacy-export~1".to_string()),
+                None
+            ]
+        );
+        assert!(IgnoredItems::new(&["req~[legacy~*".to_string()]).is_err());
+    }
+}
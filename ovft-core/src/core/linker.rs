@@ -1,16 +1,103 @@
+use crate::config::{RevisionMatching, Severity, SeverityPolicy};
 use crate::core::{
-    CoverageStatus, LinkStatus, LinkedSpecificationItem, SpecificationItem, SpecificationItemId,
+    ArtifactTypes, CoverageStatus, ItemStatus, LinkStatus, LinkedSpecificationItem,
+    SpecificationItem, SpecificationItemId,
 };
 use crate::Result;
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 
 /// Linker that creates relationships between specification items
 /// [impl->dsn~linker-module~1]
-pub struct Linker {}
+pub struct Linker {
+    /// Statuses of items that neither need nor provide coverage
+    excluded_statuses: Vec<ItemStatus>,
+    /// How strictly covered revisions must match
+    revision_matching: RevisionMatching,
+    /// Artifact types satisfying generic needs
+    artifact_types: ArtifactTypes,
+    /// Which link statuses make an item a defect
+    severity_policy: SeverityPolicy,
+}
+
+/// Lookup of the items being linked by ID and by name
+///
+/// Of duplicate items only the first one is indexed.
+struct ItemIndex<'a> {
+    by_id: HashMap<&'a SpecificationItemId, &'a SpecificationItem>,
+    /// IDs of all revisions per artifact type and name
+    revisions: HashMap<&'a str, HashMap<&'a str, Vec<&'a SpecificationItemId>>>,
+}
+
+impl<'a> ItemIndex<'a> {
+    fn new(linked_items: &'a [LinkedSpecificationItem]) -> Self {
+        let mut by_id = HashMap::with_capacity(linked_items.len());
+        let mut revisions: HashMap<_, HashMap<_, Vec<_>>> = HashMap::new();
+        for linked_item in linked_items {
+            let id = &linked_item.item.id;
+            if let Entry::Vacant(entry) = by_id.entry(id) {
+                entry.insert(&linked_item.item);
+                revisions
+                    .entry(id.artifact_type.as_str())
+                    .or_default()
+                    .entry(id.name.as_str())
+                    .or_default()
+                    .push(id);
+            }
+        }
+        Self { by_id, revisions }
+    }
+
+    fn get(&self, id: &SpecificationItemId) -> Option<&'a SpecificationItem> {
+        self.by_id.get(id).copied()
+    }
+
+    fn contains(&self, id: &SpecificationItemId) -> bool {
+        self.by_id.contains_key(id)
+    }
+
+    /// IDs of the items with the artifact type and name of `id`, in any revision
+    fn revisions_of(&self, id: &SpecificationItemId) -> &[&'a SpecificationItemId] {
+        self.revisions
+            .get(id.artifact_type.as_str())
+            .and_then(|names| names.get(id.name.as_str()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
 
 impl Linker {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            excluded_statuses: Vec::new(),
+            revision_matching: RevisionMatching::default(),
+            artifact_types: ArtifactTypes::default(),
+            severity_policy: SeverityPolicy::default(),
+        }
+    }
+
+    /// Leave items with the given statuses, e.g. `rejected`, out of coverage analysis
+    pub fn exclude_statuses(mut self, statuses: Vec<ItemStatus>) -> Self {
+        self.excluded_statuses = statuses;
+        self
+    }
+
+    /// Resolve `covers` links to other revisions of an item as configured
+    pub fn revision_matching(mut self, matching: RevisionMatching) -> Self {
+        self.revision_matching = matching;
+        self
+    }
+
+    /// Let needs of generic artifact types be satisfied by their specific types
+    pub fn artifact_types(mut self, artifact_types: ArtifactTypes) -> Self {
+        self.artifact_types = artifact_types;
+        self
+    }
+
+    /// Decide by project policy which link statuses make an item a defect
+    pub fn severity_policy(mut self, policy: SeverityPolicy) -> Self {
+        self.severity_policy = policy;
+        self
     }
 
     /// Link specification items together and analyze coverage
@@ -18,191 +105,321 @@ impl Linker {
         &self,
         items: Vec<SpecificationItem>,
     ) -> Result<Vec<LinkedSpecificationItem>> {
-        // First, build the lookup map and check for duplicates
-        let mut items_by_id = HashMap::new();
-        let mut duplicate_ids = Vec::new();
-
-        for item in &items {
-            if items_by_id.contains_key(&item.id) {
-                duplicate_ids.push(item.id.clone());
-            } else {
-                items_by_id.insert(item.id.clone(), item.clone());
-            }
-        }
+        // First, check for duplicates
+        let mut seen_ids = HashSet::with_capacity(items.len());
+        let duplicate_ids: HashSet<_> = items
+            .iter()
+            .filter(|item| !seen_ids.insert(&item.id))
+            .map(|item| item.id.clone())
+            .collect();
 
         // Create linked items
-        let mut linked_items = Vec::new();
+        let mut linked_items = Vec::with_capacity(items.len());
         for item in items {
-            let mut linked_item = LinkedSpecificationItem::new(item.clone());
+            let is_duplicate = duplicate_ids.contains(&item.id);
+            let mut linked_item = LinkedSpecificationItem::new(item);
+            for needed_type in &linked_item.item.needs {
+                let satisfying_types = self.artifact_types.satisfying_types(needed_type);
+                if !satisfying_types.is_empty() {
+                    linked_item
+                        .satisfying_types
+                        .insert(needed_type.clone(), satisfying_types);
+                }
+            }
 
             // Mark duplicates as defects
-            if duplicate_ids.contains(&item.id) {
+            if is_duplicate {
                 linked_item.is_defect = true;
-                linked_item.add_outgoing_link(item.id.clone(), LinkStatus::Duplicate);
+                let id = linked_item.item.id.clone();
+                linked_item.add_outgoing_link(id, LinkStatus::Duplicate);
             }
 
             linked_items.push(linked_item);
         }
 
         // Process links between items
-        self.process_coverage_links(&mut linked_items, &items_by_id)?;
+        self.process_coverage_links(&mut linked_items)?;
         self.analyze_coverage(&mut linked_items);
 
         Ok(linked_items)
     }
 
     /// Process coverage relationships between items
-    fn process_coverage_links(
-        &self,
-        linked_items: &mut [LinkedSpecificationItem],
-        items_by_id: &HashMap<SpecificationItemId, SpecificationItem>,
-    ) -> Result<()> {
-        // Process outgoing links for each item
-        for item in linked_items.iter_mut() {
-            let covers = item.item.covers.clone();
-            for covered_id in &covers {
-                let link_status = self.determine_link_status(covered_id, items_by_id);
-                item.add_outgoing_link(covered_id.clone(), link_status);
+    ///
+    /// Runs in linear time in the number of items and links: the covering items
+    /// are collected per covered item first, so that each item only looks at
+    /// the items actually referring to it.
+    fn process_coverage_links(&self, linked_items: &mut [LinkedSpecificationItem]) -> Result<()> {
+        let index = ItemIndex::new(linked_items);
+
+        // Outgoing links of each item, and the positions of the items covering
+        // each item, including the ones covering other revisions of it
+        let mut outgoing_links = Vec::with_capacity(linked_items.len());
+        let mut covering_items: HashMap<SpecificationItemId, Vec<usize>> = HashMap::new();
+        for (position, linked_item) in linked_items.iter().enumerate() {
+            let item = &linked_item.item;
+            let mut links = Vec::with_capacity(item.covers.len());
+            for covered_id in &item.covers {
+                let target_id = self.resolve(covered_id, &index).unwrap_or(covered_id);
+                let link_status = self.determine_link_status(&item.id, target_id, &index);
+                links.push((target_id.clone(), link_status));
+
+                if let Some(covered_item_id) = self.incoming_link_target(covered_id, &index) {
+                    let positions = covering_items.entry(covered_item_id.clone()).or_default();
+                    if positions.last() != Some(&position) {
+                        positions.push(position);
+                    }
+                }
             }
-        }
 
-        // Process incoming links
-        let items_clone: Vec<_> = linked_items.iter().map(|li| li.item.clone()).collect();
-        for item in linked_items.iter_mut() {
-            let item_id = item.item.id.clone();
-            for other_item in &items_clone {
-                if other_item.covers.contains(&item_id) {
-                    let link_status = self.determine_incoming_link_status(&item_id, &other_item.id);
-                    item.add_incoming_link(other_item.id.clone(), link_status);
+            // Only unresolved dependencies are recorded; valid ones are not coverage links
+            for dependency_id in &item.depends {
+                if !index.contains(dependency_id) {
+                    links.push((dependency_id.clone(), LinkStatus::BrokenDependency));
                 }
             }
+            outgoing_links.push(links);
+        }
+
+        let incoming_links: Vec<Vec<_>> = linked_items
+            .iter()
+            .map(|linked_item| {
+                let item = &linked_item.item;
+                covering_items
+                    .get(&item.id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|&position| {
+                        let other_item = &linked_items[position].item;
+                        let (covered_id, link_status) =
+                            other_item.covers.iter().find_map(|covered_id| {
+                                self.determine_incoming_link_status(
+                                    item,
+                                    covered_id,
+                                    other_item,
+                                    &index,
+                                )
+                                .map(|link_status| (covered_id, link_status))
+                            })?;
+                        Some((
+                            other_item.id.clone(),
+                            link_status,
+                            other_item.covers_location(covered_id).cloned(),
+                        ))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let links = outgoing_links.into_iter().zip(incoming_links);
+        for (linked_item, (outgoing, incoming)) in linked_items.iter_mut().zip(links) {
+            for (target_id, link_status) in outgoing {
+                linked_item.add_outgoing_link(target_id, link_status);
+            }
+            for (source_id, link_status, location) in incoming {
+                linked_item.add_incoming_link(source_id, link_status, location);
+            }
         }
 
         Ok(())
     }
 
+    /// Find the item a `covers` entry refers to under the configured revision matching
+    fn resolve<'i>(
+        &self,
+        covered_id: &SpecificationItemId,
+        index: &ItemIndex<'i>,
+    ) -> Option<&'i SpecificationItemId> {
+        if let Some((id, _)) = index.by_id.get_key_value(covered_id) {
+            return Some(id);
+        }
+        if self.revision_matching == RevisionMatching::Strict {
+            return None;
+        }
+
+        let [existing_id] = index.revisions_of(covered_id) else {
+            return None;
+        };
+        let accepted = match self.revision_matching {
+            RevisionMatching::Strict => false,
+            RevisionMatching::Newer => existing_id.revision > covered_id.revision,
+            RevisionMatching::Any => true,
+        };
+        accepted.then_some(*existing_id)
+    }
+
+    /// Find the item a `covers` entry creates an incoming link on
+    ///
+    /// That is the item it resolves to or else, mirroring the outgoing `Outdated`
+    /// and `Predated` statuses, the only other revision of the covered item.
+    fn incoming_link_target<'i>(
+        &self,
+        covered_id: &SpecificationItemId,
+        index: &ItemIndex<'i>,
+    ) -> Option<&'i SpecificationItemId> {
+        self.resolve(covered_id, index)
+            .or_else(|| match index.revisions_of(covered_id) {
+                [existing_id] => Some(*existing_id),
+                _ => None,
+            })
+    }
+
+    /// Check whether an item needs coverage by the given artifact type
+    fn is_needed(&self, item: &SpecificationItem, artifact_type: &str) -> bool {
+        item.needs
+            .iter()
+            .any(|needed_type| self.artifact_types.satisfies(needed_type, artifact_type))
+    }
+
     /// Determine the status of an outgoing link
     fn determine_link_status(
         &self,
+        covering_id: &SpecificationItemId,
         covered_id: &SpecificationItemId,
-        items_by_id: &HashMap<SpecificationItemId, SpecificationItem>,
+        index: &ItemIndex,
     ) -> LinkStatus {
-        match items_by_id.get(covered_id) {
+        match index.get(covered_id) {
             Some(covered_item) => {
                 // Check if coverage is requested
-                if covered_item.needs.is_empty() {
+                if !self.is_needed(covered_item, &covering_id.artifact_type) {
                     LinkStatus::Unwanted
                 } else {
                     LinkStatus::Covers
                 }
             }
-            None => {
-                // Check for items with same name but different revision
-                let matching_items: Vec<_> = items_by_id
-                    .keys()
-                    .filter(|id| {
-                        id.artifact_type == covered_id.artifact_type && id.name == covered_id.name
-                    })
-                    .collect();
-
-                if matching_items.is_empty() {
-                    LinkStatus::Orphaned
-                } else if matching_items.len() > 1 {
-                    LinkStatus::Ambiguous
-                } else {
-                    let existing_item = matching_items[0];
-                    if existing_item.revision > covered_id.revision {
-                        LinkStatus::Outdated
-                    } else {
-                        LinkStatus::Predated
-                    }
-                }
-            }
+            // Check for items with same name but different revision
+            None => match index.revisions_of(covered_id) {
+                [] => LinkStatus::Orphaned,
+                [existing_id] if existing_id.revision > covered_id.revision => LinkStatus::Outdated,
+                [_] => LinkStatus::Predated,
+                _ => LinkStatus::Ambiguous,
+            },
         }
     }
 
-    /// Determine the status of an incoming link
+    /// Determine the status of the incoming link that a `covers` entry creates on an item
+    ///
+    /// Returns `None` if the entry does not refer to the item. An entry refers to
+    /// another revision of the item if no item has that revision and the item is
+    /// the only revision with its name, mirroring the outgoing `Outdated` and
+    /// `Predated` statuses.
     fn determine_incoming_link_status(
         &self,
-        _item_id: &SpecificationItemId,
-        _covering_id: &SpecificationItemId,
-    ) -> LinkStatus {
-        // For now, assume all incoming links are valid
-        // In a more sophisticated implementation, we would check revision compatibility
-        LinkStatus::CoveredShallow
+        item: &SpecificationItem,
+        covered_id: &SpecificationItemId,
+        covering_item: &SpecificationItem,
+        index: &ItemIndex,
+    ) -> Option<LinkStatus> {
+        if self.resolve(covered_id, index) == Some(&item.id) {
+            return Some(if self.excluded_statuses.contains(&covering_item.status) {
+                LinkStatus::CoveredExcluded
+            } else if self.is_needed(item, &covering_item.id.artifact_type) {
+                LinkStatus::CoveredShallow
+            } else {
+                LinkStatus::CoveredUnwanted
+            });
+        }
+
+        if covered_id.artifact_type != item.id.artifact_type
+            || covered_id.name != item.id.name
+            || index.contains(covered_id)
+            || index.revisions_of(&item.id).len() > 1
+        {
+            return None;
+        }
+
+        Some(if covered_id.revision < item.id.revision {
+            LinkStatus::CoveredOutdated
+        } else {
+            LinkStatus::CoveredPredated
+        })
     }
 
     /// Analyze coverage status for each item
     fn analyze_coverage(&self, linked_items: &mut [LinkedSpecificationItem]) {
-        // Clone the data we need to avoid borrowing issues
-        let items_data: Vec<_> = linked_items
+        // Items validly covering each item
+        let mut covering_items: HashMap<&SpecificationItemId, Vec<&SpecificationItem>> =
+            HashMap::new();
+        for linked_item in linked_items.iter() {
+            for link in &linked_item.outgoing_links {
+                if matches!(link.status, LinkStatus::Covers) {
+                    covering_items
+                        .entry(&link.target_id)
+                        .or_default()
+                        .push(&linked_item.item);
+                }
+            }
+        }
+
+        let results: Vec<_> = linked_items
             .iter()
-            .map(|li| (li.item.clone(), li.outgoing_links.clone()))
+            .map(|linked_item| self.assess(linked_item, &covering_items))
             .collect();
+        for (linked_item, (coverage_status, is_defect)) in linked_items.iter_mut().zip(results) {
+            linked_item.coverage_status = coverage_status;
+            linked_item.is_defect = is_defect;
+        }
+    }
 
-        for linked_item in linked_items.iter_mut() {
-            // If item has no requirements, it's considered covered (terminating item)
-            if linked_item.item.needs.is_empty() {
-                linked_item.coverage_status = CoverageStatus::Covered;
-                // Still need to check for broken links even if no coverage requirements
-            } else {
-                // Check if all needed artifact types are covered
-                let mut all_covered = true;
-                let mut any_covered = false;
-
-                for needed_type in &linked_item.item.needs.clone() {
-                    let is_covered = self.is_artifact_type_covered_static(
-                        &linked_item.item.id,
-                        needed_type,
-                        &items_data,
-                    );
-                    if is_covered {
-                        any_covered = true;
-                    } else {
-                        all_covered = false;
-                    }
-                }
+    /// Determine the coverage status of an item and whether it is a defect
+    fn assess(
+        &self,
+        linked_item: &LinkedSpecificationItem,
+        covering_items: &HashMap<&SpecificationItemId, Vec<&SpecificationItem>>,
+    ) -> (CoverageStatus, bool) {
+        // Excluded items need no coverage and are never defects
+        if self.excluded_statuses.contains(&linked_item.item.status) {
+            return (CoverageStatus::Covered, false);
+        }
 
-                // Determine overall coverage status
-                linked_item.coverage_status = if all_covered {
-                    CoverageStatus::Covered
-                } else if any_covered {
-                    CoverageStatus::Partial
-                } else {
-                    CoverageStatus::Uncovered
-                };
+        // If item has no requirements, it's considered covered (terminating item)
+        let coverage_status = if linked_item.item.needs.is_empty() {
+            CoverageStatus::Covered
+            // Still need to check for broken links even if no coverage requirements
+        } else {
+            // Check if all needed artifact types are covered
+            let covering_items = covering_items
+                .get(&linked_item.item.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let covered_count = linked_item
+                .item
+                .needs
+                .iter()
+                .filter(|needed_type| self.is_artifact_type_covered(needed_type, covering_items))
+                .count();
+
+            // Determine overall coverage status
+            if covered_count == linked_item.item.needs.len() {
+                CoverageStatus::Covered
+            } else if covered_count > 0 {
+                CoverageStatus::Partial
+            } else {
+                CoverageStatus::Uncovered
             }
+        };
 
-            // Mark as defect if not properly covered or has broken links (check for ALL items)
-            let not_covered = !matches!(linked_item.coverage_status, CoverageStatus::Covered);
-            let has_broken_links = linked_item.outgoing_links.iter().any(|link| {
-                matches!(
-                    link.status,
-                    LinkStatus::Orphaned
-                        | LinkStatus::Ambiguous
-                        | LinkStatus::Outdated
-                        | LinkStatus::Predated
-                        | LinkStatus::Duplicate
-                )
-            });
+        // Mark as defect if not properly covered or has links the policy treats as errors
+        let not_covered = !matches!(coverage_status, CoverageStatus::Covered);
+        let has_broken_links = linked_item
+            .outgoing_links
+            .iter()
+            .chain(&linked_item.incoming_links)
+            .any(|link| self.severity_policy.link_severity(&link.status) == Severity::Error);
 
-            linked_item.is_defect = not_covered || has_broken_links;
-        }
+        (coverage_status, not_covered || has_broken_links)
     }
 
-    /// Check if a specific artifact type is covered for an item (static version to avoid borrowing issues)
-    fn is_artifact_type_covered_static(
+    /// Check if one of the items validly covering an item satisfies a needed artifact type
+    fn is_artifact_type_covered(
         &self,
-        item_id: &SpecificationItemId,
         artifact_type: &str,
-        items_data: &[(SpecificationItem, Vec<crate::core::Link>)],
+        covering_items: &[&SpecificationItem],
     ) -> bool {
-        items_data.iter().any(|(item, outgoing_links)| {
-            item.id.artifact_type == artifact_type
-                && item.covers.contains(item_id)
-                && outgoing_links.iter().any(|link| {
-                    link.target_id == *item_id && matches!(link.status, LinkStatus::Covers)
-                })
+        covering_items.iter().any(|item| {
+            self.artifact_types
+                .satisfies(artifact_type, &item.id.artifact_type)
+                && !self.excluded_statuses.contains(&item.status)
         })
     }
 }
@@ -216,7 +433,8 @@ impl Default for Linker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{SpecificationItem, SpecificationItemId};
+    use crate::core::{Location, SpecificationItem, SpecificationItemId};
+    use std::path::PathBuf;
 
     #[test]
     fn test_simple_linking() {
@@ -232,6 +450,7 @@ mod tests {
 
         let req = SpecificationItem::builder(req_id.clone())
             .covers(feat_id.clone())
+            .location(Location::new(PathBuf::from("src/login.rs"), 7))
             .build();
 
         let items = vec![feat, req];
@@ -246,6 +465,10 @@ mod tests {
             .unwrap();
         assert!(feat_linked.is_covered());
 
+        // Check that the link points to the covering tag
+        let location = feat_linked.incoming_links[0].location.as_ref().unwrap();
+        assert_eq!(location.to_string(), "src/login.rs:7");
+
         // Check that requirement has outgoing link
         let req_linked = linked_items.iter().find(|li| li.item.id == req_id).unwrap();
         assert!(req_linked
@@ -253,4 +476,162 @@ mod tests {
             .iter()
             .any(|link| link.target_id == feat_id));
     }
+
+    #[test]
+    fn test_broken_dependencies_are_defects() {
+        let base_id = SpecificationItemId::new("req".to_string(), "base".to_string(), 2);
+        let missing_id = SpecificationItemId::new("req".to_string(), "missing".to_string(), 1);
+        let old_base_id = SpecificationItemId::new("req".to_string(), "base".to_string(), 1);
+
+        let base = SpecificationItem::builder(base_id.clone()).build();
+        let valid = SpecificationItem::builder(SpecificationItemId::new(
+            "req".to_string(),
+            "valid".to_string(),
+            1,
+        ))
+        .depends(base_id)
+        .build();
+        let broken = SpecificationItem::builder(SpecificationItemId::new(
+            "req".to_string(),
+            "broken".to_string(),
+            1,
+        ))
+        .depends(missing_id.clone())
+        .depends(old_base_id.clone())
+        .build();
+
+        let linked_items = Linker::new().link_items(vec![base, valid, broken]).unwrap();
+        assert!(!linked_items[1].is_defect);
+        assert!(linked_items[1].outgoing_links.is_empty());
+
+        assert!(linked_items[2].is_defect);
+        let broken_targets: Vec<_> = linked_items[2]
+            .outgoing_links
+            .iter()
+            .filter(|link| matches!(link.status, LinkStatus::BrokenDependency))
+            .map(|link| link.target_id.clone())
+            .collect();
+        assert_eq!(broken_targets, vec![missing_id, old_base_id]);
+    }
+
+    #[test]
+    fn test_excluded_statuses() {
+        let obsolete = ItemStatus::Custom("obsolete".to_string());
+        let req_id = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let old_id = SpecificationItemId::new("req".to_string(), "print".to_string(), 1);
+
+        let req = SpecificationItem::builder(req_id.clone())
+            .needs("impl".to_string())
+            .build();
+        let old = SpecificationItem::builder(old_id)
+            .needs("impl".to_string())
+            .status(obsolete.clone())
+            .build();
+        let rejected_impl = SpecificationItem::builder(SpecificationItemId::new(
+            "impl".to_string(),
+            "export".to_string(),
+            1,
+        ))
+        .covers(req_id)
+        .status(ItemStatus::Rejected)
+        .build();
+
+        let linked_items = Linker::new()
+            .exclude_statuses(vec![ItemStatus::Rejected, obsolete])
+            .link_items(vec![req, old, rejected_impl])
+            .unwrap();
+        assert!(linked_items[0].is_defect);
+        assert!(!linked_items[1].is_defect);
+        assert!(!linked_items[2].is_defect);
+    }
+
+    #[test]
+    fn test_incoming_link_statuses() {
+        let req_id = SpecificationItemId::new("req".to_string(), "export".to_string(), 2);
+        let req = SpecificationItem::builder(req_id.clone())
+            .needs("impl".to_string())
+            .build();
+        let covering = |artifact_type: &str, name: &str, revision: u32| {
+            SpecificationItem::builder(SpecificationItemId::new(
+                artifact_type.to_string(),
+                name.to_string(),
+                1,
+            ))
+            .covers(SpecificationItemId::new(
+                "req".to_string(),
+                "export".to_string(),
+                revision,
+            ))
+            .build()
+        };
+
+        let linked_items = Linker::new()
+            .link_items(vec![
+                req,
+                covering("impl", "current", 2),
+                covering("utest", "unrequested", 2),
+                covering("impl", "old", 1),
+                covering("impl", "future", 3),
+            ])
+            .unwrap();
+        let statuses: Vec<_> = linked_items[0]
+            .incoming_links
+            .iter()
+            .map(|link| (link.source_id.as_ref().unwrap().name.as_str(), &link.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("current", &LinkStatus::CoveredShallow),
+                ("unrequested", &LinkStatus::CoveredUnwanted),
+                ("old", &LinkStatus::CoveredOutdated),
+                ("future", &LinkStatus::CoveredPredated),
+            ]
+        );
+        assert_eq!(linked_items[2].outgoing_links[0].status, LinkStatus::Unwanted);
+        assert!(linked_items[0].is_covered());
+    }
+
+    #[test]
+    fn test_revision_matching() {
+        let req_id = SpecificationItemId::new("req".to_string(), "export".to_string(), 2);
+        let link = |matching, covered_revision| {
+            let req = SpecificationItem::builder(req_id.clone())
+                .needs("impl".to_string())
+                .build();
+            let implementation = SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(SpecificationItemId::new(
+                "req".to_string(),
+                "export".to_string(),
+                covered_revision,
+            ))
+            .build();
+            let linked_items = Linker::new()
+                .revision_matching(matching)
+                .link_items(vec![req, implementation])
+                .unwrap();
+            (
+                linked_items[0].is_covered(),
+                linked_items[1].outgoing_links[0].clone(),
+            )
+        };
+
+        let (covered, link_to_old) = link(RevisionMatching::Strict, 1);
+        assert!(!covered);
+        assert_eq!(link_to_old.status, LinkStatus::Outdated);
+
+        let (covered, link_to_old) = link(RevisionMatching::Newer, 1);
+        assert!(covered);
+        assert_eq!(link_to_old.status, LinkStatus::Covers);
+        assert_eq!(link_to_old.target_id, req_id);
+        assert_eq!(
+            link(RevisionMatching::Newer, 3).1.status,
+            LinkStatus::Predated
+        );
+        assert!(link(RevisionMatching::Any, 3).0);
+    }
 }
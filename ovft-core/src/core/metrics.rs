@@ -0,0 +1,164 @@
+use crate::core::{LinkedSpecificationItem, Location, SpecificationItem};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Number of lines after a tag searched for the start of the enclosing function
+pub(crate) const FUNCTION_SEARCH_LINES: usize = 5;
+
+/// Simple implementation metrics for one item
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImplementationMetrics {
+    /// Number of distinct files containing implementation tags
+    pub files: usize,
+    /// Number of implementation tags
+    pub tagged_locations: usize,
+    /// Approximate number of lines in the functions following the tags
+    pub function_lines: usize,
+}
+
+/// Computes implementation metrics from the locations of implementation tags
+pub struct MetricsCollector {
+    /// Artifact type counted as implementation evidence
+    artifact_type: String,
+}
+
+impl MetricsCollector {
+    pub fn new<S: Into<String>>(artifact_type: S) -> Self {
+        Self {
+            artifact_type: artifact_type.into(),
+        }
+    }
+
+    /// Collect metrics for every item covered by at least one implementation tag
+    pub fn collect(
+        &self,
+        items: &[LinkedSpecificationItem],
+    ) -> BTreeMap<String, ImplementationMetrics> {
+        let tag_items: HashMap<_, _> = items
+            .iter()
+            .filter(|item| item.id().artifact_type == self.artifact_type)
+            .filter(|item| item.item.location.is_some())
+            .map(|item| (item.id(), &item.item))
+            .collect();
+        let mut file_cache: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        let mut metrics = BTreeMap::new();
+
+        for item in items {
+            let tags: Vec<&SpecificationItem> = item
+                .incoming_links
+                .iter()
+                .filter_map(|link| tag_items.get(link.source_id.as_ref()?).copied())
+                .collect();
+            if tags.is_empty() {
+                continue;
+            }
+
+            let locations: Vec<&Location> =
+                tags.iter().filter_map(|tag| tag.location.as_ref()).collect();
+            let files: BTreeSet<_> = locations.iter().map(|location| &location.path).collect();
+            let function_lines = tags
+                .iter()
+                .zip(&locations)
+                .map(|(tag, location)| match &tag.end_location {
+                    // Block tags state their region explicitly
+                    Some(end) => end.line.saturating_sub(location.line) as usize + 1,
+                    None => {
+                        let lines = file_cache
+                            .entry(location.path.clone())
+                            .or_insert_with(|| Self::read_lines(&location.path));
+                        Self::function_length(lines, location.line as usize)
+                    }
+                })
+                .sum();
+
+            metrics.insert(
+                item.id().to_string(),
+                ImplementationMetrics {
+                    files: files.len(),
+                    tagged_locations: locations.len(),
+                    function_lines,
+                },
+            );
+        }
+
+        metrics
+    }
+
+    pub(crate) fn read_lines(path: &Path) -> Vec<String> {
+        fs::read_to_string(path)
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Approximate the length of the brace-delimited block starting shortly after a tag
+    ///
+    /// `tag_line` is 1-based. Returns 0 if no block starts within a few lines.
+    fn function_length(lines: &[String], tag_line: usize) -> usize {
+        Self::function_span(lines, tag_line)
+            .map(|(open_line, close_line)| close_line - open_line + 1)
+            .unwrap_or(0)
+    }
+
+    /// Find the 0-based first and last line of the block starting shortly after a tag
+    pub(crate) fn function_span(lines: &[String], tag_line: usize) -> Option<(usize, usize)> {
+        let start = tag_line.saturating_sub(1);
+        let open_line = (start..lines.len().min(start + FUNCTION_SEARCH_LINES + 1))
+            .find(|&index| lines[index].contains('{'))?;
+
+        let mut depth = 0i64;
+        for (index, line) in lines.iter().enumerate().skip(open_line) {
+            depth += line.matches('{').count() as i64;
+            depth -= line.matches('}').count() as i64;
+            if depth <= 0 {
+                return Some((open_line, index));
+            }
+        }
+        Some((open_line, lines.len() - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem, SpecificationItemId};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_metrics() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("lib.rs");
+        fs::write(
+            &source,
+            "// tag\nfn export() {\n    let a = 1;\n    if a > 0 {\n    }\n}\n\n// tag\nfn other() {}\n",
+        )
+        .unwrap();
+
+        let dsn = SpecificationItemId::parse("dsn~export~1").unwrap();
+        let items = vec![
+            SpecificationItem::builder(dsn.clone())
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("impl~export-1~0").unwrap())
+                .covers(dsn.clone())
+                .location(Location::new(source.clone(), 1))
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("impl~export-2~0").unwrap())
+                .covers(dsn.clone())
+                .location(Location::new(source, 8))
+                .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+
+        let metrics = MetricsCollector::new("impl").collect(&items);
+        assert_eq!(
+            metrics.get(&dsn.to_string()),
+            Some(&ImplementationMetrics {
+                files: 1,
+                tagged_locations: 2,
+                function_lines: 6,
+            })
+        );
+    }
+}
@@ -1,7 +1,53 @@
+pub mod artifact_types;
+pub mod baseline;
+pub mod chain;
+pub mod coverage;
+pub mod crossref;
+pub mod dangling;
+pub mod defect_baseline;
+pub mod diff;
+pub mod execution;
+pub mod explain;
+pub mod gates;
+pub mod glossary;
+pub mod history;
+pub mod ignored;
 pub mod linker;
+pub mod metrics;
 pub mod model;
+pub mod namespace;
+pub mod naming;
+pub mod provenance;
+pub mod readiness;
+pub mod rename;
+pub mod revision;
+pub mod suspect;
+pub mod thresholds;
 pub mod tracer;
 
+pub use artifact_types::*;
+pub use baseline::*;
+pub use chain::*;
+pub use coverage::*;
+pub use crossref::*;
+pub use dangling::*;
+pub use defect_baseline::*;
+pub use diff::*;
+pub use execution::*;
+pub use explain::*;
+pub use gates::*;
+pub use glossary::*;
+pub use history::*;
+pub use ignored::*;
 pub use linker::*;
+pub use metrics::*;
 pub use model::*;
+pub use namespace::*;
+pub use naming::*;
+pub use provenance::*;
+pub use readiness::*;
+pub use rename::*;
+pub use revision::*;
+pub use suspect::*;
+pub use thresholds::*;
 pub use tracer::*;
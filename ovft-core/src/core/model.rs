@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::PathBuf;
 
@@ -51,7 +52,7 @@ impl SpecificationItemId {
     pub fn to_html_id(&self) -> String {
         format!("item_{}_{}_{}",
             self.artifact_type.replace('-', "_"),
-            self.name.replace('~', "_").replace(':', "_").replace(' ', "_").replace('-', "_"),
+            self.name.replace(['~', ':', ' ', '-'], "_"),
             self.revision
         )
     }
@@ -64,17 +65,36 @@ impl fmt::Display for SpecificationItemId {
 }
 
 /// Status of a specification item
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ItemStatus {
     Draft,
     Proposed,
+    #[default]
     Approved,
     Rejected,
+    /// A project-specific status declared in the configuration, e.g. `obsolete`
+    Custom(String),
 }
 
-impl Default for ItemStatus {
-    fn default() -> Self {
-        Self::Approved
+impl ItemStatus {
+    /// Parse a status name case-insensitively (e.g. "Approved")
+    pub fn parse(status: &str) -> Option<Self> {
+        Self::parse_with(status, &[])
+    }
+
+    /// Parse a built-in status or one of the given project-specific statuses
+    pub fn parse_with(status: &str, custom: &[String]) -> Option<Self> {
+        let status = status.trim().to_lowercase();
+        match status.as_str() {
+            "draft" => Some(Self::Draft),
+            "proposed" => Some(Self::Proposed),
+            "approved" => Some(Self::Approved),
+            "rejected" => Some(Self::Rejected),
+            _ => custom
+                .iter()
+                .any(|name| name.to_lowercase() == status)
+                .then_some(Self::Custom(status)),
+        }
     }
 }
 
@@ -85,6 +105,7 @@ impl fmt::Display for ItemStatus {
             Self::Proposed => write!(f, "proposed"),
             Self::Approved => write!(f, "approved"),
             Self::Rejected => write!(f, "rejected"),
+            Self::Custom(status) => write!(f, "{}", status),
         }
     }
 }
@@ -131,10 +152,22 @@ pub struct SpecificationItem {
     pub needs: Vec<String>,
     /// Specification items that this item covers
     pub covers: Vec<SpecificationItemId>,
+    /// Lines listing the covered IDs, keyed by ID, if known
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub covers_locations: BTreeMap<String, Location>,
     /// Dependencies on other specification items
     pub depends: Vec<SpecificationItemId>,
     /// Source location where this item is defined
     pub location: Option<Location>,
+    /// End of the region attributed to this item, for block tags spanning several lines
+    #[serde(default)]
+    pub end_location: Option<Location>,
+    /// Module (subsystem or document) this item belongs to
+    #[serde(default)]
+    pub module: Option<String>,
+    /// Custom attributes such as `ASIL` or `Verification-Method`
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl SpecificationItem {
@@ -150,8 +183,12 @@ impl SpecificationItem {
             tags: Vec::new(),
             needs: Vec::new(),
             covers: Vec::new(),
+            covers_locations: BTreeMap::new(),
             depends: Vec::new(),
             location: None,
+            end_location: None,
+            module: None,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -167,6 +204,21 @@ impl SpecificationItem {
             .unwrap_or_else(|| self.id.name.replace(['-', '_'], " "))
     }
 
+    /// Location of the line listing `covered_id`, or of the item itself if unknown
+    pub fn covers_location(&self, covered_id: &SpecificationItemId) -> Option<&Location> {
+        self.covers_locations
+            .get(&covered_id.to_string())
+            .or(self.location.as_ref())
+    }
+
+    /// Where the item is defined, where it ends and where it lists the covered IDs
+    pub fn locations_mut(&mut self) -> impl Iterator<Item = &mut Location> {
+        self.location
+            .iter_mut()
+            .chain(self.end_location.iter_mut())
+            .chain(self.covers_locations.values_mut())
+    }
+
     /// Check if this item is a terminating item (doesn't need coverage)
     pub fn is_terminating(&self) -> bool {
         self.needs.is_empty()
@@ -240,6 +292,14 @@ impl SpecificationItemBuilder {
         self
     }
 
+    pub fn covers_at(mut self, covered_id: SpecificationItemId, location: Location) -> Self {
+        self.item
+            .covers_locations
+            .insert(covered_id.to_string(), location);
+        self.item.covers.push(covered_id);
+        self
+    }
+
     pub fn depends(mut self, dependency: SpecificationItemId) -> Self {
         self.item.depends.push(dependency);
         self
@@ -250,6 +310,21 @@ impl SpecificationItemBuilder {
         self
     }
 
+    pub fn end_location(mut self, location: Location) -> Self {
+        self.item.end_location = Some(location);
+        self
+    }
+
+    pub fn attribute(mut self, key: String, value: String) -> Self {
+        self.item.metadata.insert(key, value);
+        self
+    }
+
+    pub fn module(mut self, module: String) -> Self {
+        self.item.module = Some(module);
+        self
+    }
+
     pub fn build(self) -> SpecificationItem {
         self.item
     }
@@ -278,8 +353,40 @@ pub enum LinkStatus {
     CoveredPredated,
     /// Item is covered with old revision
     CoveredOutdated,
+    /// Item is covered by an item whose status is excluded from tracing
+    CoveredExcluded,
     /// Duplicate item IDs exist
     Duplicate,
+    /// Item depends on a non-existing item or on another revision of it
+    BrokenDependency,
+}
+
+impl LinkStatus {
+    /// All link statuses
+    pub const ALL: [LinkStatus; 13] = [
+        Self::Covers,
+        Self::Predated,
+        Self::Outdated,
+        Self::Ambiguous,
+        Self::Unwanted,
+        Self::Orphaned,
+        Self::CoveredShallow,
+        Self::CoveredUnwanted,
+        Self::CoveredPredated,
+        Self::CoveredOutdated,
+        Self::CoveredExcluded,
+        Self::Duplicate,
+        Self::BrokenDependency,
+    ];
+
+    /// Check whether an incoming link counts as coverage of the item
+    ///
+    /// Links from other revisions, from items with an excluded status and
+    /// coverage of an artifact type that the item does not need are shown, but
+    /// do not cover the item.
+    pub fn is_valid_coverage(&self) -> bool {
+        matches!(self, Self::CoveredShallow)
+    }
 }
 
 impl fmt::Display for LinkStatus {
@@ -295,7 +402,9 @@ impl fmt::Display for LinkStatus {
             Self::CoveredUnwanted => write!(f, "covered unwanted"),
             Self::CoveredPredated => write!(f, "covered predated"),
             Self::CoveredOutdated => write!(f, "covered outdated"),
+            Self::CoveredExcluded => write!(f, "covered excluded"),
             Self::Duplicate => write!(f, "duplicate"),
+            Self::BrokenDependency => write!(f, "broken dependency"),
         }
     }
 }
@@ -334,6 +443,9 @@ pub struct LinkedSpecificationItem {
     pub coverage_status: CoverageStatus,
     /// Whether this item has defects
     pub is_defect: bool,
+    /// Specific artifact types satisfying the needs of the item that are groups
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub satisfying_types: BTreeMap<String, Vec<String>>,
 }
 
 /// A link between specification items
@@ -345,6 +457,10 @@ pub struct Link {
     pub target_id: SpecificationItemId,
     /// Status of the link
     pub status: LinkStatus,
+    /// Where the link is declared: the line listing the covered ID, e.g. a code tag or an
+    /// entry below `Covers:`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 /// Defect found during tracing
@@ -356,6 +472,9 @@ pub struct Defect {
     pub description: String,
     /// ID of the item with the defect (if applicable)
     pub item_id: Option<SpecificationItemId>,
+    /// Where to fix the defect: the definition of the item or the offending tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 /// Types of defects that can be found
@@ -371,6 +490,44 @@ pub enum DefectType {
     WrongRevision,
     /// Circular dependency detected
     CircularDependency,
+    /// Free text mentions or an item depends on an item ID that does not exist
+    BrokenReference,
+    /// Item wording violates the configured glossary
+    Terminology,
+    /// Frozen item changed without a revision bump
+    FrozenItemChanged,
+    /// Implementation tag points at code no test executes
+    UnexercisedImplementation,
+    /// Item covers nothing although its artifact type is needed by other items
+    DanglingItem,
+    /// Item covers or is covered by an artifact type that is not needed
+    UnwantedCoverage,
+    /// Coverage falls short of a configured minimum percentage
+    CoverageThreshold,
+    /// Item name violates the configured naming rules
+    NamingViolation,
+    /// Item covers an ID that does not clearly identify one item
+    AmbiguousReference,
+}
+
+impl DefectType {
+    /// All defect types
+    pub const ALL: [DefectType; 14] = [
+        Self::UncoveredItem,
+        Self::OrphanedCoverage,
+        Self::DuplicateItem,
+        Self::WrongRevision,
+        Self::CircularDependency,
+        Self::BrokenReference,
+        Self::Terminology,
+        Self::FrozenItemChanged,
+        Self::UnexercisedImplementation,
+        Self::DanglingItem,
+        Self::UnwantedCoverage,
+        Self::CoverageThreshold,
+        Self::NamingViolation,
+        Self::AmbiguousReference,
+    ];
 }
 
 impl fmt::Display for DefectType {
@@ -381,6 +538,15 @@ impl fmt::Display for DefectType {
             Self::DuplicateItem => write!(f, "duplicate"),
             Self::WrongRevision => write!(f, "wrong-revision"),
             Self::CircularDependency => write!(f, "circular-dependency"),
+            Self::BrokenReference => write!(f, "broken-reference"),
+            Self::Terminology => write!(f, "terminology"),
+            Self::FrozenItemChanged => write!(f, "frozen-item-changed"),
+            Self::UnexercisedImplementation => write!(f, "unexercised-implementation"),
+            Self::DanglingItem => write!(f, "dangling"),
+            Self::UnwantedCoverage => write!(f, "unwanted"),
+            Self::CoverageThreshold => write!(f, "coverage-threshold"),
+            Self::NamingViolation => write!(f, "naming"),
+            Self::AmbiguousReference => write!(f, "ambiguous"),
         }
     }
 }
@@ -398,6 +564,103 @@ pub struct CoverageSummary {
     pub status: CoverageStatus,
 }
 
+/// Readiness summary for a module
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSummary {
+    /// Name of the module
+    pub module: String,
+    /// Total number of items in the module
+    pub total: usize,
+    /// Number of covered items
+    pub covered: usize,
+    /// Coverage percentage
+    pub percentage: f64,
+    /// Number of open defects on items of this module
+    pub open_defects: usize,
+    /// Number of items per item status
+    pub status_counts: BTreeMap<String, usize>,
+}
+
+/// Coverage summary of the items carrying a tag, e.g. a functional area
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagSummary {
+    /// The tag
+    pub tag: String,
+    /// Total number of items carrying the tag
+    pub total: usize,
+    /// Number of covered items
+    pub covered: usize,
+    /// Coverage percentage
+    pub percentage: f64,
+    /// Number of open defects on items carrying the tag
+    pub open_defects: usize,
+}
+
+/// Coverage summary of the items defined in a document, e.g. a spec file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentSummary {
+    /// Path of the document
+    pub document: String,
+    /// Total number of items in the document
+    pub total: usize,
+    /// Number of covered items
+    pub covered: usize,
+    /// Coverage percentage
+    pub percentage: f64,
+    /// Number of open defects on items of this document
+    pub open_defects: usize,
+    /// Items of the document, in order of appearance
+    pub items: Vec<SpecificationItemId>,
+    /// Covered and total items per needed artifact type, e.g. how much of the
+    /// document is implemented
+    #[serde(default)]
+    pub coverage_by_type: BTreeMap<String, (usize, usize)>,
+}
+
+impl DocumentSummary {
+    /// Format the coverage per needed artifact type like "impl 3/5, utest 1/5"
+    pub fn coverage_by_type_overview(&self) -> String {
+        self.coverage_by_type
+            .iter()
+            .map(|(artifact_type, (covered, total))| {
+                format!("{} {}/{}", artifact_type, covered, total)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// HTML anchor of the document in the report
+    pub fn to_html_id(&self) -> String {
+        let name: String = self
+            .document
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("document_{}", name)
+    }
+}
+
+impl ModuleSummary {
+    /// Check whether the module is fully covered, defect-free and approved
+    pub fn is_ready(&self) -> bool {
+        self.covered == self.total
+            && self.open_defects == 0
+            && self
+                .status_counts
+                .keys()
+                .all(|status| status == &ItemStatus::Approved.to_string())
+    }
+
+    /// Format the status counts like "approved: 3, draft: 1"
+    pub fn status_overview(&self) -> String {
+        self.status_counts
+            .iter()
+            .map(|(status, count)| format!("{}: {}", status, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 impl LinkedSpecificationItem {
     pub fn new(item: SpecificationItem) -> Self {
         Self {
@@ -406,6 +669,7 @@ impl LinkedSpecificationItem {
             incoming_links: Vec::new(),
             coverage_status: CoverageStatus::Uncovered,
             is_defect: false,
+            satisfying_types: BTreeMap::new(),
         }
     }
 
@@ -424,21 +688,55 @@ impl LinkedSpecificationItem {
         matches!(self.coverage_status, CoverageStatus::Covered)
     }
 
-    /// Add an outgoing link
+    /// Check whether coverage by `artifact_type` satisfies the need for `needed_type`
+    pub fn satisfies_need(&self, needed_type: &str, artifact_type: &str) -> bool {
+        needed_type == artifact_type
+            || self
+                .satisfying_types
+                .get(needed_type)
+                .is_some_and(|types| types.iter().any(|member| member == artifact_type))
+    }
+
+    /// Find which needed artifact types have no incoming coverage
+    pub fn missing_coverage_types(&self) -> Vec<String> {
+        self.item
+            .needs
+            .iter()
+            .filter(|needed_type| {
+                !self.incoming_links.iter().any(|link| {
+                    link.status.is_valid_coverage()
+                        && link.source_id.as_ref().is_some_and(|source_id| {
+                            self.satisfies_need(needed_type, &source_id.artifact_type)
+                        })
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Add an outgoing link, declared where this item lists the target
     pub fn add_outgoing_link(&mut self, target_id: SpecificationItemId, status: LinkStatus) {
+        let location = self.item.covers_location(&target_id).cloned();
         self.outgoing_links.push(Link {
             source_id: Some(self.item.id.clone()),
             target_id,
             status,
+            location,
         });
     }
 
-    /// Add an incoming link
-    pub fn add_incoming_link(&mut self, source_id: SpecificationItemId, status: LinkStatus) {
+    /// Add an incoming link, declared where the source item lists this item
+    pub fn add_incoming_link(
+        &mut self,
+        source_id: SpecificationItemId,
+        status: LinkStatus,
+        location: Option<Location>,
+    ) {
         self.incoming_links.push(Link {
             source_id: Some(source_id),
             target_id: self.item.id.clone(),
             status,
+            location,
         });
     }
 }
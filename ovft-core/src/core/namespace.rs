@@ -0,0 +1,79 @@
+use crate::config::Namespace;
+use crate::core::{SpecificationItem, SpecificationItemId};
+
+/// Applies namespace prefixes to the items of namespaced directories
+///
+/// The item's own ID and all of its `covers`/`depends` references are
+/// prefixed. References that already start with a configured namespace
+/// (e.g. `req~billing.login~1`) are kept, so subprojects can still point
+/// at each other explicitly.
+pub struct NamespaceResolver {
+    prefixes: Vec<String>,
+}
+
+impl NamespaceResolver {
+    pub fn new(namespaces: &[Namespace]) -> Self {
+        Self {
+            prefixes: namespaces
+                .iter()
+                .map(|namespace| namespace.prefix.clone())
+                .collect(),
+        }
+    }
+
+    /// Move all given items into the namespace `prefix`
+    pub fn apply(&self, items: &mut [SpecificationItem], prefix: &str) {
+        for item in items {
+            item.id = Self::prefixed(&item.id, prefix);
+            for id in item.covers.iter_mut().chain(item.depends.iter_mut()) {
+                if !self.is_qualified(id) {
+                    *id = Self::prefixed(id, prefix);
+                }
+            }
+        }
+    }
+
+    /// Check whether an ID already names a configured namespace
+    fn is_qualified(&self, id: &SpecificationItemId) -> bool {
+        id.name
+            .split_once('.')
+            .is_some_and(|(namespace, _)| self.prefixes.iter().any(|prefix| prefix == namespace))
+    }
+
+    fn prefixed(id: &SpecificationItemId, prefix: &str) -> SpecificationItemId {
+        SpecificationItemId::new(
+            id.artifact_type.clone(),
+            format!("{}.{}", prefix, id.name),
+            id.revision,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_apply_namespace() {
+        let config = Config::empty()
+            .add_namespace("billing/docs", "billing")
+            .add_namespace("shop/docs", "shop");
+        let resolver = NamespaceResolver::new(&config.namespaces);
+
+        let mut items =
+            vec![
+                SpecificationItem::builder(SpecificationItemId::parse("dsn~login~1").unwrap())
+                    .covers_multiple(vec![
+                        SpecificationItemId::parse("req~login~1").unwrap(),
+                        SpecificationItemId::parse("req~shop.checkout~1").unwrap(),
+                    ])
+                    .build(),
+            ];
+        resolver.apply(&mut items, "billing");
+
+        assert_eq!(items[0].id.to_string(), "dsn~billing.login~1");
+        assert_eq!(items[0].covers[0].to_string(), "req~billing.login~1");
+        assert_eq!(items[0].covers[1].to_string(), "req~shop.checkout~1");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::config::NamingRules;
+use crate::core::{Defect, DefectType, LinkedSpecificationItem, SpecificationItem};
+use crate::Result;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Checks the names in item IDs against the configured naming rules
+///
+/// Namespaced names like `billing.user-login` are checked as a whole, kebab
+/// case allows the dots between the namespace and the name.
+pub struct NamingChecker<'a> {
+    rules: &'a NamingRules,
+    /// The configured pattern, anchored to match the whole name
+    pattern: Option<Regex>,
+    kebab_case: Regex,
+}
+
+impl<'a> NamingChecker<'a> {
+    /// Create a checker from the naming rules
+    pub fn new(rules: &'a NamingRules) -> Result<Self> {
+        let pattern = match &rules.pattern {
+            Some(pattern) => Some(Regex::new(&format!("^(?:{})$", pattern))?),
+            None => None,
+        };
+        Ok(Self {
+            rules,
+            pattern,
+            kebab_case: Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*(\.[a-z0-9]+(-[a-z0-9]+)*)*$")?,
+        })
+    }
+
+    /// Return one warning per rule an item name violates
+    pub fn check(&self, items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let mut warnings = Vec::new();
+        // Duplicates are reported separately, their name only once
+        let mut checked = HashSet::new();
+
+        for item in items.iter().map(|linked_item| &linked_item.item) {
+            if !checked.insert(&item.id) {
+                continue;
+            }
+            let name = &item.id.name;
+
+            if let (Some(pattern), Some(regex)) = (&self.rules.pattern, &self.pattern) {
+                if !regex.is_match(name) {
+                    warnings.push(Self::warning(
+                        item,
+                        format!("does not match the pattern '{}'", pattern),
+                    ));
+                }
+            }
+            if let Some(max_length) = self.rules.max_length {
+                let length = name.chars().count();
+                if length > max_length {
+                    warnings.push(Self::warning(
+                        item,
+                        format!(
+                            "is {} characters long, at most {} allowed",
+                            length, max_length
+                        ),
+                    ));
+                }
+            }
+            if self.rules.kebab_case && !self.kebab_case.is_match(name) {
+                warnings.push(Self::warning(
+                    item,
+                    "is not lowercase words separated by hyphens".to_string(),
+                ));
+            }
+            if let Some(prefix) = self
+                .rules
+                .reserved_prefixes
+                .iter()
+                .find(|prefix| name.starts_with(prefix.as_str()))
+            {
+                warnings.push(Self::warning(
+                    item,
+                    format!("starts with the reserved prefix '{}'", prefix),
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    fn warning(item: &SpecificationItem, issue: String) -> Defect {
+        let location = item
+            .location
+            .as_ref()
+            .map(|location| format!(" (at {})", location))
+            .unwrap_or_default();
+
+        Defect {
+            defect_type: DefectType::NamingViolation,
+            description: format!("Name of item {} {}{}", item.id, issue, location),
+            item_id: Some(item.id.clone()),
+            location: item.location.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SpecificationItemId;
+
+    #[test]
+    fn test_naming_violations() {
+        let rules = NamingRules {
+            pattern: Some("[a-z.-]+".to_string()),
+            max_length: Some(12),
+            kebab_case: true,
+            reserved_prefixes: vec!["tmp".to_string()],
+        };
+        let checker = NamingChecker::new(&rules).unwrap();
+        let item = |name: &str| {
+            LinkedSpecificationItem::new(
+                SpecificationItem::builder(SpecificationItemId::new(
+                    "req".to_string(),
+                    name.to_string(),
+                    1,
+                ))
+                .build(),
+            )
+        };
+
+        let items = vec![
+            item("user-login"),
+            item("billing.login"),
+            item("User_Login2"),
+            item("tmp-export"),
+            item("tmp-export"),
+        ];
+        let warnings = checker.check(&items);
+
+        let descriptions: Vec<_> = warnings.iter().map(|w| w.description.as_str()).collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Name of item req~billing.login~1 is 13 characters long, at most 12 allowed",
+                "Name of item req~User_Login2~1 does not match the pattern '[a-z.-]+'",
+                "Name of item req~User_Login2~1 is not lowercase words separated by hyphens",
+                "Name of item req~tmp-export~1 starts with the reserved prefix 'tmp'",
+            ]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::config::Config;
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Provenance of a trace, stated in the reports for auditors
+///
+/// Trace results that were not produced by the tracer, e.g. deserialized from
+/// an older `trace-result.json`, have empty metadata, which reports omit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportMetadata {
+    /// Version of ovft that produced the trace
+    pub tool_version: String,
+    /// Time of the trace in UTC, formatted as RFC 3339
+    pub generated_at: String,
+    /// Commit of the traced git tree, if the files are in a git repository
+    pub revision: Option<String>,
+    /// SHA-256 digest of the effective configuration
+    pub config_digest: String,
+}
+
+impl ReportMetadata {
+    /// Collect the metadata of a trace of the given revision (`HEAD` for the working tree)
+    pub fn collect(config: &Config, rev: &str) -> Self {
+        let config_digest = match serde_json::to_vec(config) {
+            Ok(json) => format!("{:x}", Sha256::digest(json)),
+            Err(_) => String::new(),
+        };
+        let revision = env::current_dir()
+            .ok()
+            .and_then(|dir| Repository::discover(dir).ok())
+            .and_then(|repo| {
+                let commit = repo.revparse_single(rev).ok()?.peel_to_commit().ok()?;
+                Some(commit.id().to_string())
+            });
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: Self::format_utc(seconds),
+            revision,
+            config_digest,
+        }
+    }
+
+    /// Check whether the metadata is unknown
+    pub fn is_empty(&self) -> bool {
+        self.tool_version.is_empty()
+    }
+
+    /// One-line statement of the metadata, e.g. for report footers and comments
+    pub fn summary(&self) -> String {
+        format!(
+            "Generated by ovft {} at {}, revision {}, configuration sha256:{}",
+            self.tool_version,
+            self.generated_at,
+            self.revision.as_deref().unwrap_or("unknown"),
+            self.config_digest
+        )
+    }
+
+    /// Format seconds since the Unix epoch like `2024-05-17T08:30:00Z`
+    fn format_utc(seconds: u64) -> String {
+        let days = (seconds / 86_400) as i64;
+        let time = seconds % 86_400;
+
+        // Civil date from days since the epoch, after Howard Hinnant's algorithm
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            time / 3_600,
+            time % 3_600 / 60,
+            time % 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let metadata = ReportMetadata::collect(&Config::default(), "HEAD");
+        assert_eq!(metadata.tool_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(metadata.config_digest.len(), 64);
+        assert_eq!(
+            metadata.config_digest,
+            ReportMetadata::collect(&Config::default(), "HEAD").config_digest
+        );
+        assert_ne!(
+            metadata.config_digest,
+            ReportMetadata::collect(&Config::empty(), "HEAD").config_digest
+        );
+        assert!(!metadata.is_empty());
+        assert!(ReportMetadata::default().is_empty());
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(ReportMetadata::format_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            ReportMetadata::format_utc(951_825_600),
+            "2000-02-29T12:00:00Z"
+        );
+        assert_eq!(
+            ReportMetadata::format_utc(1_792_108_800),
+            "2026-10-16T00:00:00Z"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::config::{Config, Waiver};
+use crate::core::{Defect, GateResult, ItemStatus, LinkedSpecificationItem};
+use crate::Result;
+use roxmltree::Document;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Overall release readiness status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerdictStatus {
+    /// Nothing blocks the release
+    Pass,
+    /// Only waived findings remain
+    PassWithWaivers,
+    /// At least one finding blocks the release
+    Fail,
+}
+
+impl VerdictStatus {
+    /// Short identifier used for styling in reports
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::PassWithWaivers => "pass-with-waivers",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+impl fmt::Display for VerdictStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::PassWithWaivers => write!(f, "pass with waivers"),
+            Self::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// Structured release readiness verdict with the reasons that led to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseVerdict {
+    /// Resulting status
+    pub status: VerdictStatus,
+    /// Findings that block the release
+    pub blocking: Vec<String>,
+    /// Findings that would block the release but are covered by a waiver
+    pub waived: Vec<String>,
+}
+
+impl ReleaseVerdict {
+    /// Check whether the release may proceed (with or without waivers)
+    pub fn is_releasable(&self) -> bool {
+        self.status != VerdictStatus::Fail
+    }
+}
+
+impl Default for ReleaseVerdict {
+    fn default() -> Self {
+        Self {
+            status: VerdictStatus::Pass,
+            blocking: Vec::new(),
+            waived: Vec::new(),
+        }
+    }
+}
+
+/// Summary of executed tests read from JUnit XML result files
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestSummary {
+    /// Number of executed test cases
+    pub total: usize,
+    /// Number of failed or errored test cases
+    pub failed: usize,
+}
+
+impl TestSummary {
+    /// Read and accumulate JUnit XML result files
+    pub fn from_junit_files<P: AsRef<Path>>(paths: &[P]) -> Result<Self> {
+        let mut summary = Self::default();
+
+        for path in paths {
+            let content = fs::read_to_string(path)?;
+            let document = Document::parse(&content)?;
+            for testcase in document
+                .descendants()
+                .filter(|node| node.has_tag_name("testcase"))
+            {
+                summary.total += 1;
+                // A test case counts once, even with both a failure and an error
+                if testcase
+                    .children()
+                    .any(|child| child.has_tag_name("failure") || child.has_tag_name("error"))
+                {
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Combines gates, defects, item statuses, waivers and test results into a verdict
+pub struct ReleaseReadiness<'a> {
+    waivers: &'a [Waiver],
+    tests: Option<TestSummary>,
+}
+
+impl<'a> ReleaseReadiness<'a> {
+    /// Create an evaluator from the configuration, reading any configured test results
+    pub fn from_config(config: &'a Config) -> Result<Self> {
+        let tests = if config.test_results.is_empty() {
+            None
+        } else {
+            Some(TestSummary::from_junit_files(&config.test_results)?)
+        };
+
+        Ok(Self::new(&config.waivers, tests))
+    }
+
+    pub fn new(waivers: &'a [Waiver], tests: Option<TestSummary>) -> Self {
+        Self { waivers, tests }
+    }
+
+    /// Evaluate the release readiness of a trace
+    pub fn evaluate(
+        &self,
+        items: &[LinkedSpecificationItem],
+        defects: &[Defect],
+        gate_results: &[GateResult],
+    ) -> ReleaseVerdict {
+        let mut verdict = ReleaseVerdict::default();
+
+        for gate in gate_results.iter().filter(|gate| !gate.passed) {
+            for violation in &gate.violations {
+                verdict
+                    .blocking
+                    .push(format!("tag gate '{}': {}", gate.tag, violation));
+            }
+        }
+
+        for defect in defects {
+            self.classify(&mut verdict, defect.item_id.as_ref(), &defect.description);
+        }
+
+        for item in items {
+            if matches!(item.item.status, ItemStatus::Draft | ItemStatus::Proposed) {
+                let message = format!("item {} is still {}", item.id(), item.item.status);
+                self.classify(&mut verdict, Some(item.id()), &message);
+            }
+        }
+
+        if let Some(tests) = &self.tests {
+            if tests.failed > 0 {
+                verdict.blocking.push(format!(
+                    "{} of {} test case(s) failed",
+                    tests.failed, tests.total
+                ));
+            }
+        }
+
+        verdict.status = if !verdict.blocking.is_empty() {
+            VerdictStatus::Fail
+        } else if !verdict.waived.is_empty() {
+            VerdictStatus::PassWithWaivers
+        } else {
+            VerdictStatus::Pass
+        };
+
+        verdict
+    }
+
+    /// Record a finding as blocking or waived depending on the waiver list
+    fn classify(
+        &self,
+        verdict: &mut ReleaseVerdict,
+        item_id: Option<&crate::core::SpecificationItemId>,
+        message: &str,
+    ) {
+        let waiver = item_id.and_then(|id| {
+            let id = id.to_string();
+            self.waivers.iter().find(|waiver| waiver.item == id)
+        });
+
+        match waiver {
+            Some(waiver) => verdict
+                .waived
+                .push(format!("{} (waived: {})", message, waiver.reason)),
+            None => verdict.blocking.push(message.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DefectType, SpecificationItem, SpecificationItemId};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn defect(id: &SpecificationItemId) -> Defect {
+        Defect {
+            defect_type: DefectType::UncoveredItem,
+            description: format!("Item {} needs coverage by impl", id),
+            item_id: Some(id.clone()),
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_verdict_with_waivers() {
+        let id = SpecificationItemId::new("req".to_string(), "legacy".to_string(), 1);
+        let items = vec![LinkedSpecificationItem::new(SpecificationItem::new(
+            id.clone(),
+        ))];
+        let defects = vec![defect(&id)];
+
+        let verdict = ReleaseReadiness::new(&[], None).evaluate(&items, &defects, &[]);
+        assert_eq!(verdict.status, VerdictStatus::Fail);
+
+        let waivers = vec![Waiver {
+            item: id.to_string(),
+            reason: "accepted for 1.0".to_string(),
+        }];
+        let verdict = ReleaseReadiness::new(&waivers, None).evaluate(&items, &defects, &[]);
+        assert_eq!(verdict.status, VerdictStatus::PassWithWaivers);
+        assert!(verdict.waived[0].contains("accepted for 1.0"));
+
+        let verdict = ReleaseReadiness::new(&[], None).evaluate(&items, &[], &[]);
+        assert_eq!(verdict.status, VerdictStatus::Pass);
+    }
+
+    #[test]
+    fn test_failed_tests_block_release() {
+        let mut junit = NamedTempFile::new().unwrap();
+        writeln!(
+            junit,
+            r#"<testsuite><testcase name="a"/><testcase name="b"><failure message="x"/></testcase></testsuite>"#
+        )
+        .unwrap();
+
+        let tests = TestSummary::from_junit_files(&[junit.path()]).unwrap();
+        assert_eq!(
+            tests,
+            TestSummary {
+                total: 2,
+                failed: 1
+            }
+        );
+
+        let verdict = ReleaseReadiness::new(&[], Some(tests)).evaluate(&[], &[], &[]);
+        assert_eq!(verdict.status, VerdictStatus::Fail);
+        assert_eq!(verdict.blocking, vec!["1 of 2 test case(s) failed"]);
+    }
+
+    #[test]
+    fn test_junit_output_is_not_counted() {
+        let mut junit = NamedTempFile::new().unwrap();
+        writeln!(
+            junit,
+            r#"<testsuite>
+  <testcase name="a"><failure/><error/></testcase>
+  <testcase name="b"><system-out><![CDATA[<testcase> <failure> <error>]]></system-out></testcase>
+</testsuite>"#
+        )
+        .unwrap();
+
+        let tests = TestSummary::from_junit_files(&[junit.path()]).unwrap();
+        assert_eq!(
+            tests,
+            TestSummary {
+                total: 2,
+                failed: 1
+            }
+        );
+    }
+}
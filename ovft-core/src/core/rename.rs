@@ -0,0 +1,321 @@
+use crate::config::Config;
+use crate::core::SpecificationItemId;
+use crate::importers::encoding::read_text_file;
+use crate::importers::{FileWalker, InputFormat, PdfImporter, RemoteSource, XlsxImporter};
+use crate::Result;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rewrites a specification item ID across all spec and source files
+pub struct Renamer<'a> {
+    config: &'a Config,
+}
+
+/// A single line changed by a rename
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineChange {
+    /// Line number in the file (1-based)
+    pub line: u32,
+    /// Line content before the rename
+    pub before: String,
+    /// Line content after the rename
+    pub after: String,
+}
+
+/// All changes a rename applies to one file
+#[derive(Debug, Clone)]
+pub struct FileEdit {
+    /// File to rewrite
+    pub path: PathBuf,
+    /// Changed lines in file order
+    pub changes: Vec<LineChange>,
+    /// Complete file content after the rename
+    new_content: String,
+}
+
+impl FileEdit {
+    /// Write the renamed content back to disk
+    pub fn apply(&self) -> Result<()> {
+        fs::write(&self.path, &self.new_content)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for FileEdit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "--- {}", self.path.display())?;
+        writeln!(f, "+++ {}", self.path.display())?;
+        for change in &self.changes {
+            writeln!(f, "@@ line {} @@", change.line)?;
+            writeln!(f, "-{}", change.before)?;
+            writeln!(f, "+{}", change.after)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Renamer<'a> {
+    /// Create a renamer working on the directories of the given configuration
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    /// Compute the edits needed to rename `old_id` to `new_id` without touching any file
+    pub fn plan(
+        &self,
+        old_id: &SpecificationItemId,
+        new_id: &SpecificationItemId,
+    ) -> Result<Vec<FileEdit>> {
+        if old_id == new_id {
+            return Err(crate::Error::InvalidId(format!(
+                "Cannot rename '{}' to itself",
+                old_id
+            )));
+        }
+
+        let old = old_id.to_string();
+        let new = new_id.to_string();
+        let mut edits = Vec::new();
+
+        for path in self.candidate_files() {
+            let content = match read_text_file(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    log::warn!("Skipping {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            if let Some(edit) = Self::rename_in_content(&path, &content, &old, &new) {
+                edits.push(edit);
+            }
+        }
+
+        Ok(edits)
+    }
+
+    /// Rename `old_id` to `new_id` in all files and return the applied edits
+    pub fn rename(
+        &self,
+        old_id: &SpecificationItemId,
+        new_id: &SpecificationItemId,
+    ) -> Result<Vec<FileEdit>> {
+        let edits = self.plan(old_id, new_id)?;
+        for edit in &edits {
+            edit.apply()?;
+        }
+        Ok(edits)
+    }
+
+    /// Collect all spec and source files the tracer would read
+    ///
+    /// Directories are walked like the tracer walks them, so ignored, oversized
+    /// and binary files are left alone. Individually listed files are included
+    /// as well.
+    fn candidate_files(&self) -> Vec<PathBuf> {
+        let walker = FileWalker::from_config(self.config);
+        let mut files = Vec::new();
+
+        for dir in &self.config.source_dirs {
+            let patterns = self.config.source_dir_patterns(dir);
+            files.extend(
+                walker
+                    .files(&dir.path)
+                    .into_iter()
+                    .filter(|path| patterns.matches_in(&dir.path, path)),
+            );
+        }
+        for dir in self.config.spec_dirs.iter().filter(|dir| !RemoteSource::is_remote(dir)) {
+            files.extend(
+                walker
+                    .files(dir)
+                    .into_iter()
+                    .filter(|path| InputFormat::from_path(path) != InputFormat::Source),
+            );
+        }
+        files.extend(self.config.source_files.iter().cloned());
+        files.extend(
+            self.config
+                .spec_files
+                .iter()
+                .filter(|path| Self::is_text_spec_file(path))
+                .cloned(),
+        );
+
+        files.retain(|path| walker.is_text_file(path));
+        walker.log_skipped_files();
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// Spreadsheets and PDFs are read by the tracer but cannot be rewritten as text
+    fn is_text_spec_file(path: &Path) -> bool {
+        !XlsxImporter::is_xlsx_file(path) && !PdfImporter::is_pdf_file(path)
+    }
+
+    /// Replace every standalone occurrence of `old` in the content
+    fn rename_in_content(path: &Path, content: &str, old: &str, new: &str) -> Option<FileEdit> {
+        let mut changes = Vec::new();
+        let mut new_content = String::with_capacity(content.len());
+
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            let renamed = Self::replace_id(line, old, new);
+            if renamed != line {
+                changes.push(LineChange {
+                    line: index as u32 + 1,
+                    before: line.trim_end_matches(['\r', '\n']).to_string(),
+                    after: renamed.trim_end_matches(['\r', '\n']).to_string(),
+                });
+            }
+            new_content.push_str(&renamed);
+        }
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(FileEdit {
+                path: path.to_path_buf(),
+                changes,
+                new_content,
+            })
+        }
+    }
+
+    /// Replace `old` in a line, ignoring matches that are part of a longer ID
+    /// such as `req~old-name~12` when renaming `req~old-name~1`
+    fn replace_id(line: &str, old: &str, new: &str) -> String {
+        let is_id_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '~');
+        let mut result = String::with_capacity(line.len());
+        let mut rest = line;
+
+        while let Some(pos) = rest.find(old) {
+            let before = rest[..pos].chars().next_back();
+            let after = rest[pos + old.len()..].chars().next();
+            let standalone = !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char);
+
+            result.push_str(&rest[..pos]);
+            result.push_str(if standalone { new } else { old });
+            rest = &rest[pos + old.len()..];
+        }
+
+        result.push_str(rest);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_replace_id_keeps_longer_ids() {
+        let line = "Covers: req~login~1, req~login~12, req~login-extra~1";
+        assert_eq!(
+            Renamer::replace_id(line, "req~login~1", "req~sign-in~1"),
+            "Covers: req~sign-in~1, req~login~12, req~login-extra~1"
+        );
+        assert_eq!(
+            Renamer::replace_id("// [impl->req~login~1]", "req~login~1", "req~sign-in~1"),
+            "// [impl->req~sign-in~1]"
+        );
+    }
+
+    #[test]
+    fn test_rename_across_spec_and_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join("docs");
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            spec_dir.join("spec.md"),
+            "# Login\n`req~login~1`\n\nNeeds: impl\n\n`dsn~auth~1`\n\nCovers: req~login~1\n",
+        )
+        .unwrap();
+        fs::write(src_dir.join("lib.rs"), "// [impl->req~login~1]\nfn login() {}\n").unwrap();
+
+        let config = Config::empty()
+            .add_source_dir(&src_dir)
+            .add_spec_dir(&spec_dir);
+        let renamer = Renamer::new(&config);
+        let old_id = SpecificationItemId::parse("req~login~1").unwrap();
+        let new_id = SpecificationItemId::parse("req~sign-in~1").unwrap();
+
+        let plan = renamer.plan(&old_id, &new_id).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan.iter().map(|e| e.changes.len()).sum::<usize>(), 3);
+        // Planning must not modify files
+        assert!(fs::read_to_string(src_dir.join("lib.rs"))
+            .unwrap()
+            .contains("req~login~1"));
+
+        renamer.rename(&old_id, &new_id).unwrap();
+        let spec = fs::read_to_string(spec_dir.join("spec.md")).unwrap();
+        let source = fs::read_to_string(src_dir.join("lib.rs")).unwrap();
+        assert!(!spec.contains("req~login~1"));
+        assert_eq!(spec.matches("req~sign-in~1").count(), 2);
+        assert_eq!(source, "// [impl->req~sign-in~1]\nfn login() {}\n");
+    }
+
+    #[test]
+    fn test_rename_skips_files_the_tracer_skips() {
+        let temp_dir = TempDir::new().unwrap();
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(src_dir.join(".ovftignore"), "generated.rs\n").unwrap();
+        fs::write(src_dir.join("generated.rs"), "// [impl->req~login~1]\n").unwrap();
+        fs::write(src_dir.join("blob.rs"), b"[impl->req~login~1]\0\0").unwrap();
+        let utf16: Vec<u8> = "\u{FEFF}// [impl->req~login~1]\n"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        fs::write(src_dir.join("utf16.rs"), utf16).unwrap();
+
+        let config = Config::empty().add_source_dir(&src_dir);
+        let renamer = Renamer::new(&config);
+        let old_id = SpecificationItemId::parse("req~login~1").unwrap();
+        let new_id = SpecificationItemId::parse("req~sign-in~1").unwrap();
+
+        let plan = renamer.plan(&old_id, &new_id).unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].path, src_dir.join("utf16.rs"));
+    }
+
+    #[test]
+    fn test_rename_in_all_spec_formats_and_listed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join("docs");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("auth.yaml"), "- id: req~login~1\n  needs: [impl]\n").unwrap();
+        fs::write(spec_dir.join("trace.csv"), "id,covers\ndsn~form~1,req~login~1\n").unwrap();
+        fs::write(spec_dir.join("notes.txt"), "req~login~1\n").unwrap();
+        let spec_file = temp_dir.path().join("extra.spec");
+        let source_file = temp_dir.path().join("main.rs");
+        fs::write(&spec_file, "`dsn~extra~1`\n\nCovers: req~login~1\n").unwrap();
+        fs::write(&source_file, "// [impl->req~login~1]\n").unwrap();
+
+        let config = Config::empty()
+            .add_spec_dir(&spec_dir)
+            .add_spec_dir("https://example.com/spec.md")
+            .add_spec_file(&spec_file)
+            .add_source_file(&source_file);
+        let renamer = Renamer::new(&config);
+        let old_id = SpecificationItemId::parse("req~login~1").unwrap();
+        let new_id = SpecificationItemId::parse("req~sign-in~1").unwrap();
+
+        let plan = renamer.plan(&old_id, &new_id).unwrap();
+        let paths: Vec<_> = plan.iter().map(|edit| edit.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                spec_dir.join("auth.yaml"),
+                spec_dir.join("trace.csv"),
+                spec_file,
+                source_file
+            ]
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use git2::{ObjectType, Repository, TreeEntry, TreeWalkMode, TreeWalkResult};
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tempfile::TempDir;
+
+use crate::config::Config;
+use crate::core::{Location, TraceResult};
+use crate::importers::RemoteSource;
+use crate::Result;
+
+/// Files of a git revision extracted to a temporary directory
+///
+/// Only the configured source and spec directories and files are extracted.
+/// [`RevisionSnapshot::config`] points a configuration at the extracted copies
+/// and [`RevisionSnapshot::restore_paths`] maps reported paths back to the
+/// configured ones, so results look like those of a working tree run.
+pub struct RevisionSnapshot {
+    dir: TempDir,
+    /// Short commit ID the revision resolved to
+    commit: String,
+    /// Configured path and its extracted copy
+    mapped_paths: Vec<(PathBuf, PathBuf)>,
+}
+
+impl RevisionSnapshot {
+    /// Extract the configured inputs of `rev` (a branch, tag or commit) from the
+    /// repository containing the current directory
+    pub fn extract(config: &Config, rev: &str) -> Result<Self> {
+        Self::extract_from(&env::current_dir()?, config, rev)
+    }
+
+    /// Extract from the repository containing `base_dir`, which relative
+    /// configured paths are resolved against
+    fn extract_from(base_dir: &Path, config: &Config, rev: &str) -> Result<Self> {
+        let base_dir = base_dir.canonicalize()?;
+        let repo = Repository::discover(&base_dir).map_err(Self::git_error)?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| crate::Error::Git("Repository has no working tree".to_string()))?
+            .canonicalize()?;
+        let commit = repo
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(Self::git_error)?;
+        let tree = commit.tree().map_err(Self::git_error)?;
+        let dir = TempDir::new()?;
+
+        let configured = config
+            .source_dirs
+            .iter()
+            .map(|source_dir| &source_dir.path)
+            .chain(&config.spec_dirs)
+            .chain(&config.source_files)
+            .chain(&config.spec_files)
+            .filter(|path| !RemoteSource::is_remote(path));
+        let mut mapped_paths = Vec::new();
+        let mut prefixes = Vec::new();
+        for path in configured {
+            let relative = Self::repository_path(&workdir, &base_dir, path)?;
+            mapped_paths.push((path.clone(), dir.path().join(&relative)));
+            prefixes.push(relative);
+        }
+
+        let mut write_error = None;
+        let walked = tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+            let path = Path::new(root).join(entry.name().unwrap_or_default());
+            let selected = prefixes.iter().any(|prefix| path.starts_with(prefix));
+            if entry.kind() == Some(ObjectType::Tree) {
+                // Only descend into selected directories and their ancestors
+                return if selected || prefixes.iter().any(|prefix| prefix.starts_with(&path)) {
+                    TreeWalkResult::Ok
+                } else {
+                    TreeWalkResult::Skip
+                };
+            }
+            if !selected || entry.kind() != Some(ObjectType::Blob) {
+                return TreeWalkResult::Ok;
+            }
+
+            match Self::write_blob(&repo, entry, &dir.path().join(&path)) {
+                Ok(()) => TreeWalkResult::Ok,
+                Err(e) => {
+                    write_error = Some(e);
+                    TreeWalkResult::Abort
+                }
+            }
+        });
+        if let Some(e) = write_error {
+            return Err(e);
+        }
+        walked.map_err(Self::git_error)?;
+
+        Ok(Self {
+            dir,
+            commit: commit.id().to_string()[..12].to_string(),
+            mapped_paths,
+        })
+    }
+
+    /// Short commit ID the revision resolved to
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    /// Copy of the configuration reading the extracted files instead of the working tree
+    pub fn config(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        let map = |paths: &mut Vec<PathBuf>| {
+            for path in paths.iter_mut() {
+                if let Some(mapped) = self.mapped(path) {
+                    *path = mapped;
+                }
+            }
+        };
+        for source_dir in &mut config.source_dirs {
+            if let Some(mapped) = self.mapped(&source_dir.path) {
+                source_dir.path = mapped;
+            }
+        }
+        map(&mut config.spec_dirs);
+        map(&mut config.source_files);
+        map(&mut config.spec_files);
+        for namespace in &mut config.namespaces {
+            if let Some(mapped) = self.mapped(&namespace.dir) {
+                namespace.dir = mapped;
+            }
+        }
+        // Extracted files get new modification times on every run
+        config.cache_file = None;
+        config
+    }
+
+    /// Replace paths of extracted files in locations and messages with the configured paths
+    pub fn restore_paths(&self, trace_result: &mut TraceResult) {
+        for linked_item in &mut trace_result.items {
+            let links = linked_item
+                .outgoing_links
+                .iter_mut()
+                .chain(linked_item.incoming_links.iter_mut())
+                .filter_map(|link| link.location.as_mut());
+            for location in linked_item.item.locations_mut().chain(links) {
+                self.restore_location(location);
+            }
+        }
+
+        let dir = self.dir.path().display().to_string();
+        let replacements: Vec<_> = self
+            .mapped_paths
+            .iter()
+            .map(|(path, mapped)| (mapped.display().to_string(), path.display().to_string()))
+            .collect();
+        for defect in trace_result
+            .defects
+            .iter_mut()
+            .chain(trace_result.warnings.iter_mut())
+        {
+            if let Some(location) = &mut defect.location {
+                self.restore_location(location);
+            }
+            if defect.description.contains(&dir) {
+                for (mapped, path) in &replacements {
+                    defect.description = defect.description.replace(mapped, path);
+                }
+            }
+        }
+    }
+
+    fn restore_location(&self, location: &mut Location) {
+        if let Some(path) = self.configured(&location.path) {
+            location.path = path;
+        }
+    }
+
+    fn mapped(&self, path: &Path) -> Option<PathBuf> {
+        self.mapped_paths
+            .iter()
+            .find(|(configured, _)| configured == path)
+            .map(|(_, mapped)| mapped.clone())
+    }
+
+    fn configured(&self, path: &Path) -> Option<PathBuf> {
+        self.mapped_paths.iter().find_map(|(configured, mapped)| {
+            let rest = path.strip_prefix(mapped).ok()?;
+            Some(if rest.as_os_str().is_empty() {
+                configured.clone()
+            } else {
+                configured.join(rest)
+            })
+        })
+    }
+
+    fn write_blob(repo: &Repository, entry: &TreeEntry, target: &Path) -> Result<()> {
+        let blob = entry
+            .to_object(repo)
+            .and_then(|object| object.peel_to_blob())
+            .map_err(Self::git_error)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(target, blob.content())?;
+        Ok(())
+    }
+
+    /// Path of a configured directory or file relative to the repository root
+    fn repository_path(workdir: &Path, base_dir: &Path, path: &Path) -> Result<PathBuf> {
+        let absolute = base_dir.join(path);
+
+        let mut normalized = PathBuf::new();
+        for component in absolute.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    normalized.pop();
+                }
+                component => normalized.push(component),
+            }
+        }
+
+        normalized
+            .strip_prefix(workdir)
+            .map(Path::to_path_buf)
+            .map_err(|_| {
+                crate::Error::Git(format!(
+                    "{} is outside of the repository {}",
+                    path.display(),
+                    workdir.display()
+                ))
+            })
+    }
+
+    fn git_error(e: git2::Error) -> crate::Error {
+        crate::Error::Git(e.message().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Tracer;
+    use git2::{Signature, Time};
+
+    #[test]
+    fn test_trace_committed_revision() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        let repo = Repository::init(&root).unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(
+            root.join("docs/spec.md"),
+            "`req~released~1`\n\nNeeds: dsn\n\n\
+             `dsn~released~1`\n\nNeeds: impl\n\nCovers: req~released~1\n",
+        )
+        .unwrap();
+        fs::write(root.join("notes.md"), "`req~unrelated~1`\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("docs/spec.md")).unwrap();
+        index.add_path(Path::new("notes.md")).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::new("ovft", "ovft@example.com", &Time::new(0, 0)).unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "v1", &tree, &[])
+            .unwrap();
+
+        // The working tree moved on since the commit
+        fs::write(root.join("docs/spec.md"), "`req~unreleased~1`\n").unwrap();
+
+        let config = Config::empty().add_spec_dir(root.join("docs"));
+        let snapshot = RevisionSnapshot::extract_from(&root, &config, "HEAD").unwrap();
+        assert!(!snapshot.dir.path().join("notes.md").exists());
+
+        let mut trace_result = Tracer::new(snapshot.config(&config)).trace().unwrap();
+        snapshot.restore_paths(&mut trace_result);
+
+        assert_eq!(trace_result.total_items, 2);
+        let spec = root.join("docs/spec.md");
+        let req = &trace_result.items[0];
+        assert_eq!(req.item.id.to_string(), "req~released~1");
+        assert_eq!(req.item.location.as_ref().unwrap().path, spec);
+        let link = req.incoming_links[0].location.as_ref().unwrap();
+        assert_eq!((&link.path, link.line), (&spec, 9));
+        let dsn = &trace_result.items[1].item;
+        assert_eq!(dsn.covers_location(&req.item.id).unwrap().path, spec);
+        let defect = &trace_result.defects[0];
+        assert_eq!(defect.location.as_ref().unwrap().path, spec);
+    }
+}
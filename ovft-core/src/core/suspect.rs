@@ -0,0 +1,125 @@
+use crate::core::{LinkStatus, Location, SpecificationItemId, TraceResult};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Coverage of an older revision of an item that has since been changed
+///
+/// When the revision of a requirement is increased, the code tags and tests
+/// covering it still name the old revision. Their links are suspect: the
+/// covering item has to be reviewed against the changed requirement and its
+/// link updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SuspectLink {
+    /// The covering item that needs review
+    pub source_id: SpecificationItemId,
+    /// Where the covering item is defined, e.g. the code tag
+    pub source_location: Option<Location>,
+    /// The covered item in its current revision
+    pub target_id: SpecificationItemId,
+    /// The revision the covering item still references
+    pub covered_revision: u32,
+}
+
+impl SuspectLink {
+    /// Collect the suspect links of a trace, ordered by the location of the covering item
+    pub fn find(trace_result: &TraceResult) -> Vec<Self> {
+        let items_by_id: HashMap<_, _> = trace_result
+            .items
+            .iter()
+            .map(|item| (item.id(), item))
+            .collect();
+
+        let mut suspects: Vec<_> = trace_result
+            .items
+            .iter()
+            .flat_map(|item| {
+                let target_id = item.id();
+                item.incoming_links
+                    .iter()
+                    .filter(|link| matches!(link.status, LinkStatus::CoveredOutdated))
+                    .filter_map(|link| {
+                        let source = items_by_id.get(link.source_id.as_ref()?)?;
+                        let covered_revision = source
+                            .item
+                            .covers
+                            .iter()
+                            .find(|covered| {
+                                covered.artifact_type == target_id.artifact_type
+                                    && covered.name == target_id.name
+                            })?
+                            .revision;
+                        Some(Self {
+                            source_id: source.id().clone(),
+                            source_location: source.item.location.clone(),
+                            target_id: target_id.clone(),
+                            covered_revision,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        suspects.sort_by_key(|suspect| {
+            (
+                suspect
+                    .source_location
+                    .as_ref()
+                    .map(|location| (location.path.clone(), location.line)),
+                suspect.source_id.to_string(),
+                suspect.target_id.to_string(),
+            )
+        });
+        suspects
+    }
+}
+
+impl fmt::Display for SuspectLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} covers revision {} of {}",
+            self.source_id, self.covered_revision, self.target_id
+        )?;
+        if let Some(location) = &self.source_location {
+            write!(f, " ({})", location)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_links_to_old_revision_are_suspect() {
+        let id = |artifact_type: &str, name: &str, revision: u32| {
+            SpecificationItemId::new(artifact_type.to_string(), name.to_string(), revision)
+        };
+        let items = vec![
+            SpecificationItem::builder(id("req", "login", 2))
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(id("impl", "login", 1))
+                .covers(id("req", "login", 1))
+                .location(Location::new(PathBuf::from("src/login.rs"), 12))
+                .build(),
+            SpecificationItem::builder(id("impl", "logout", 1))
+                .covers(id("req", "login", 2))
+                .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+
+        let suspects = trace_result.suspect_links();
+        assert_eq!(suspects.len(), 1);
+        assert_eq!(suspects[0].source_id, id("impl", "login", 1));
+        assert_eq!(suspects[0].covered_revision, 1);
+        assert_eq!(
+            suspects[0].to_string(),
+            "impl~login~1 covers revision 1 of req~login~2 (src/login.rs:12)"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::config::MinCoverage;
+use crate::core::{CoverageSummary, Defect, DefectType};
+use std::collections::HashMap;
+
+/// Checks the coverage of the trace against the configured minimum percentages
+pub struct CoverageThresholdChecker<'a> {
+    min_coverage: &'a MinCoverage,
+}
+
+impl<'a> CoverageThresholdChecker<'a> {
+    pub fn new(min_coverage: &'a MinCoverage) -> Self {
+        Self { min_coverage }
+    }
+
+    /// Return one defect per threshold the coverage falls short of
+    ///
+    /// Artifact types without items have nothing to cover and pass.
+    pub fn check(&self, coverage_summary: &HashMap<String, CoverageSummary>) -> Vec<Defect> {
+        let mut defects = Vec::new();
+        if let Some(min_coverage) = self.min_coverage.overall {
+            let (covered, total) = coverage_summary
+                .values()
+                .fold((0, 0), |(covered, total), summary| {
+                    (covered + summary.covered, total + summary.total)
+                });
+            if total > 0 {
+                let percentage = covered as f64 / total as f64 * 100.0;
+                if percentage < min_coverage {
+                    defects.push(Self::defect("Overall", percentage, min_coverage));
+                }
+            }
+        }
+
+        for (artifact_type, &min_coverage) in &self.min_coverage.artifact_types {
+            if let Some(summary) = coverage_summary.get(artifact_type) {
+                if summary.percentage < min_coverage {
+                    defects.push(Self::defect(
+                        artifact_type,
+                        summary.percentage,
+                        min_coverage,
+                    ));
+                }
+            }
+        }
+        defects
+    }
+
+    fn defect(label: &str, percentage: f64, min_coverage: f64) -> Defect {
+        Defect {
+            defect_type: DefectType::CoverageThreshold,
+            description: format!(
+                "{} coverage {:.1}% is below the minimum of {:.1}%",
+                label, percentage, min_coverage
+            ),
+            item_id: None,
+            location: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::CoverageStatus;
+
+    #[test]
+    fn test_coverage_below_thresholds() {
+        let summary = |covered: usize, total: usize| CoverageSummary {
+            total,
+            covered,
+            percentage: covered as f64 / total as f64 * 100.0,
+            status: CoverageStatus::Partial,
+        };
+        let coverage_summary = HashMap::from([
+            ("req".to_string(), summary(9, 10)),
+            ("utest".to_string(), summary(17, 20)),
+        ]);
+
+        let min_coverage = MinCoverage::default()
+            .overall(80.0)
+            .artifact_type("req", 90.0)
+            .artifact_type("utest", 90.0)
+            .artifact_type("itest", 90.0);
+        let defects = CoverageThresholdChecker::new(&min_coverage).check(&coverage_summary);
+        assert_eq!(defects.len(), 1);
+        assert_eq!(
+            defects[0].description,
+            "utest coverage 85.0% is below the minimum of 90.0%"
+        );
+
+        let min_coverage = MinCoverage::default().overall(90.0);
+        let defects = CoverageThresholdChecker::new(&min_coverage).check(&coverage_summary);
+        assert_eq!(
+            defects[0].description,
+            "Overall coverage 86.7% is below the minimum of 90.0%"
+        );
+    }
+}
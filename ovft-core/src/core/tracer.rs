@@ -1,55 +1,377 @@
-use crate::config::Config;
-use crate::core::Linker;
-use crate::core::{CoverageStatus, CoverageSummary, Defect, DefectType, LinkedSpecificationItem};
-use crate::importers::{MarkdownImporter, TagImporter};
+use crate::config::{Config, MinCoverage, Severity, SeverityPolicy, SourceDir};
+use crate::core::{
+    ArtifactTypes, Baseline, CoverageThresholdChecker, CrossReferenceChecker, DanglingItemChecker,
+    DefectBaseline, ExecutionChecker, GateChecker, GateResult, GlossaryChecker, IgnoredItems,
+    ImplementationMetrics, Linker, MetricsCollector, NamespaceResolver, NamingChecker,
+    ReleaseReadiness, ReleaseVerdict, ReportMetadata, RevisionSnapshot, RunDelta, RunHistory,
+    RunSummary, SuspectLink, TraceChain, TraceDiff,
+};
+use crate::core::{
+    CoverageStatus, CoverageSummary, Defect, DefectType, DocumentSummary, ItemStatus, LinkStatus,
+    LinkedSpecificationItem, ModuleSummary, SpecificationItem, SpecificationItemId, TagSummary,
+};
+use crate::importers::encoding::decode;
+use crate::importers::{
+    ArtifactImporter, CodeCoverageImporter, CommitImporter, CsvImporter, FileWalker,
+    GitLabImporter, ImportCache, InputFormat, MarkdownImporter, OftJsonImporter, OrgImporter,
+    PdfImporter, RemoteSource, ReqIfImporter, RustAttributeImporter, RustdocJsonImporter,
+    TagFormat, TagImporter, XlsxImporter, YamlImporter,
+};
 use crate::Result;
-use std::collections::HashMap;
-use std::path::Path;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Location file name of items read with [`Tracer::trace_with_reader`]
+pub const STDIN_ORIGIN: &str = "<stdin>";
 
 /// Main tracer that orchestrates the requirement tracing process
 pub struct Tracer {
     config: Config,
     tag_importer: TagImporter,
     markdown_importer: MarkdownImporter,
+    reqif_importer: ReqIfImporter,
+    org_importer: OrgImporter,
+    pdf_importer: PdfImporter,
+    yaml_importer: YamlImporter,
+    csv_importer: CsvImporter,
+    xlsx_importer: XlsxImporter,
+    rust_attribute_importer: RustAttributeImporter,
+    cache: Option<Arc<ImportCache>>,
+    remote_source: RemoteSource,
+    /// Shared by the importers, records the files they skipped
+    walker: FileWalker,
 }
 
 impl Tracer {
     /// Create a new tracer with the given configuration
     pub fn new(config: Config) -> Self {
+        let walker = FileWalker::from_config(&config);
+        let cache = config
+            .cache_file
+            .as_ref()
+            .map(|path| Arc::new(ImportCache::load(path, &config)));
+
+        // Config::from_file rejects invalid formats; here they can only come from the builder
+        let tag_formats = config
+            .tag_formats
+            .iter()
+            .filter_map(|template| match TagFormat::parse(template) {
+                Ok(format) => Some(format),
+                Err(e) => {
+                    log::error!("{}", e);
+                    None
+                }
+            })
+            .collect();
+        let mut tag_importer = TagImporter::new()
+            .with_tag_formats(tag_formats)
+            .with_walker(walker.clone())
+            .with_comment_styles(&config.comment_styles)
+            .comments_only(config.scan_comments_only)
+            .strict(config.strict_tag_scan);
+        let mut markdown_importer = MarkdownImporter::new()
+            .with_walker(walker.clone())
+            .skip_code_blocks(config.skip_markdown_code_blocks)
+            .inherit_section_needs(config.inherit_section_needs)
+            .with_custom_statuses(config.custom_statuses.clone())
+            .with_custom_attributes(config.custom_attributes.clone());
+        if let Some(cache) = &cache {
+            tag_importer = tag_importer.with_cache(cache.clone());
+            markdown_importer = markdown_importer.with_cache(cache.clone());
+        }
+
         Self {
-            tag_importer: TagImporter::new(),
-            markdown_importer: MarkdownImporter::new(),
+            tag_importer,
+            markdown_importer,
+            reqif_importer: ReqIfImporter::new().with_walker(walker.clone()),
+            org_importer: OrgImporter::new().with_walker(walker.clone()),
+            pdf_importer: PdfImporter::new().with_walker(walker.clone()),
+            yaml_importer: YamlImporter::new().with_walker(walker.clone()),
+            csv_importer: CsvImporter::new(config.csv.clone()).with_walker(walker.clone()),
+            xlsx_importer: XlsxImporter::new(config.xlsx_sheets.clone())
+                .with_walker(walker.clone()),
+            rust_attribute_importer: RustAttributeImporter::new().with_walker(walker.clone()),
+            cache,
+            remote_source: RemoteSource::new(&config.remote_cache_dir).offline(config.offline),
+            walker,
             config,
         }
     }
 
+    /// The configuration the tracer runs with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     /// Run the complete tracing process
     pub fn trace(&self) -> Result<TraceResult> {
+        self.trace_with_items(Vec::new())
+    }
+
+    /// Run the tracing process with additional content read from `reader`, e.g. a
+    /// specification piped in with `git show rev:spec.md | ovft --spec-file -`
+    pub fn trace_with_reader<R: Read>(
+        &self,
+        mut reader: R,
+        format: InputFormat,
+    ) -> Result<TraceResult> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let (content, _) = decode(&bytes);
+
+        let origin = Path::new(STDIN_ORIGIN);
+        let items = match format {
+            InputFormat::Markdown => self.markdown_importer.import_from_content(&content, origin),
+            InputFormat::Source => self.tag_importer.import_from_content(&content, origin),
+            InputFormat::ReqIf => self.reqif_importer.import_from_content(&content, origin),
+            InputFormat::Org => self.org_importer.import_from_content(&content, origin),
+            InputFormat::Yaml => self.yaml_importer.import_from_content(&content, origin),
+            InputFormat::Csv => self.csv_importer.import_from_content(&content, origin),
+        }?;
+        self.trace_with_items(items)
+    }
+
+    /// Import a single specification file with the importer matching its extension;
+    /// files of unknown type are read as markdown
+    fn import_spec_file(&self, path: &Path) -> Result<Vec<SpecificationItem>> {
+        if XlsxImporter::is_xlsx_file(path) {
+            return self.xlsx_importer.import_from_file(path);
+        }
+        if PdfImporter::is_pdf_file(path) {
+            return self.pdf_importer.import_from_file(path);
+        }
+        match InputFormat::from_path(path) {
+            InputFormat::Markdown | InputFormat::Source => {
+                self.markdown_importer.import_from_file(path)
+            }
+            InputFormat::ReqIf => self.reqif_importer.import_from_file(path),
+            InputFormat::Org => self.org_importer.import_from_file(path),
+            InputFormat::Yaml => self.yaml_importer.import_from_file(path),
+            InputFormat::Csv => self.csv_importer.import_from_file(path),
+        }
+    }
+
+    /// Import a remote specification document; item locations point to its URL
+    fn import_remote_spec(&self, url: &str) -> Result<Vec<SpecificationItem>> {
+        let local_copy = self.remote_source.fetch(url)?;
+        let mut items = self.import_spec_file(&local_copy)?;
+        for location in items.iter_mut().flat_map(SpecificationItem::locations_mut) {
+            location.path = PathBuf::from(url);
+        }
+        Ok(items)
+    }
+
+    /// Listed files that do not lie inside one of the scanned directories
+    fn unscanned_files<'a, D: AsRef<Path>>(
+        files: &'a [PathBuf],
+        dirs: &'a [D],
+    ) -> impl Iterator<Item = &'a PathBuf> {
+        files
+            .iter()
+            .filter(move |file| !dirs.iter().any(|dir| file.starts_with(dir)))
+    }
+
+    /// Tag importer applying the patterns and artifact type of a source directory
+    fn source_dir_importer(&self, source_dir: &SourceDir) -> TagImporter {
+        let importer = self
+            .tag_importer
+            .clone()
+            .with_patterns(self.config.source_dir_patterns(source_dir));
+        match &source_dir.artifact_type {
+            Some(artifact_type) => importer.with_default_artifact_type(artifact_type),
+            None => importer,
+        }
+    }
+
+    /// Run the tracing process on the source and spec files of a git revision
+    /// (branch, tag or commit) instead of the working tree
+    pub fn trace_at_revision(&self, rev: &str) -> Result<TraceResult> {
+        let snapshot = RevisionSnapshot::extract(&self.config, rev)?;
+        log::info!("Tracing revision {} ({})", rev, snapshot.commit());
+
+        let mut trace_result = Tracer::new(snapshot.config(&self.config)).trace()?;
+        snapshot.restore_paths(&mut trace_result);
+        trace_result.metadata = ReportMetadata::collect(&self.config, rev);
+        Ok(trace_result)
+    }
+
+    fn trace_with_items(&self, extra_items: Vec<SpecificationItem>) -> Result<TraceResult> {
+        match self.config.threads {
+            Some(threads) => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| {
+                    crate::Error::Config(format!("Cannot start {} threads: {}", threads, e))
+                })?
+                .install(|| self.run_trace(extra_items)),
+            None => self.run_trace(extra_items),
+        }
+    }
+
+    fn run_trace(&self, extra_items: Vec<SpecificationItem>) -> Result<TraceResult> {
         // 1. Import specification items from all sources
-        let mut items = Vec::new();
+        let mut items = extra_items;
+        let namespaces = NamespaceResolver::new(&self.config.namespaces);
 
         // Import from source code files
         for source_dir in &self.config.source_dirs {
-            let source_items = self.tag_importer.import_from_directory(source_dir)?;
+            let mut source_items = self
+                .source_dir_importer(source_dir)
+                .import_from_directory(&source_dir.path)?;
+            if self.config.rust_attributes {
+                let attribute_items = self
+                    .rust_attribute_importer
+                    .import_from_directory(&source_dir.path)?;
+                source_items.extend(attribute_items);
+            }
+            if let Some(prefix) = self.config.namespace_for(&source_dir.path) {
+                namespaces.apply(&mut source_items, prefix);
+            }
             items.extend(source_items);
         }
 
         // Import from specification files
-        for spec_dir in &self.config.spec_dirs {
-            let spec_items = self.markdown_importer.import_from_directory(spec_dir)?;
+        let (remote_dirs, spec_dirs): (Vec<_>, Vec<_>) = self
+            .config
+            .spec_dirs
+            .iter()
+            .partition(|dir| RemoteSource::is_remote(dir));
+        for spec_dir in spec_dirs {
+            let mut spec_items = self.markdown_importer.import_from_directory(spec_dir)?;
+            spec_items.extend(self.reqif_importer.import_from_directory(spec_dir)?);
+            spec_items.extend(self.org_importer.import_from_directory(spec_dir)?);
+            spec_items.extend(self.yaml_importer.import_from_directory(spec_dir)?);
+            spec_items.extend(self.csv_importer.import_from_directory(spec_dir)?);
+            spec_items.extend(self.xlsx_importer.import_from_directory(spec_dir)?);
+            spec_items.extend(self.pdf_importer.import_from_directory(spec_dir)?);
+            if let Some(prefix) = self.config.namespace_for(spec_dir) {
+                namespaces.apply(&mut spec_items, prefix);
+            }
             items.extend(spec_items);
         }
 
+        // Import specification documents published at remote URLs
+        for url in remote_dirs {
+            let mut remote_items = self.import_remote_spec(&url.to_string_lossy())?;
+            if let Some(prefix) = self.config.namespace_for(url) {
+                namespaces.apply(&mut remote_items, prefix);
+            }
+            items.extend(remote_items);
+        }
+
+        // Import individually listed files; files inside a scanned directory were already read
+        for path in Self::unscanned_files(&self.config.source_files, &self.config.source_dirs) {
+            items.extend(self.tag_importer.import_from_file(path)?);
+            if self.config.rust_attributes && RustAttributeImporter::is_rust_file(path) {
+                items.extend(self.rust_attribute_importer.import_from_file(path)?);
+            }
+        }
+        for path in Self::unscanned_files(&self.config.spec_files, &self.config.spec_dirs) {
+            items.extend(self.import_spec_file(path)?);
+        }
+
+        // Import previously exported OpenFastTrace artifacts
+        for export in &self.config.oft_exports {
+            items.extend(OftJsonImporter::new().import_from_file(export)?);
+        }
+
+        // Import the items other projects exported for tracing across repositories
+        for artifacts in &self.config.external_artifacts {
+            items.extend(ArtifactImporter::new().import_from_file(artifacts)?);
+        }
+
+        // Import tags from the documentation of public items
+        for rustdoc_json in &self.config.rustdoc_json {
+            items.extend(RustdocJsonImporter::new().import_from_file(rustdoc_json)?);
+        }
+
+        // Import coverage recorded in commit messages
+        if let Some(commit_coverage) = &self.config.commit_coverage {
+            let commit_items = CommitImporter::new(&commit_coverage.artifact_type)
+                .import_from_repository(&commit_coverage.repository)?;
+            items.extend(commit_items);
+        }
+
+        // Import issues and epics tracked in GitLab
+        if let Some(gitlab) = &self.config.gitlab {
+            items.extend(GitLabImporter::new(gitlab.clone()).import()?);
+        }
+
+        self.walker.log_skipped_files();
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.save() {
+                log::warn!("Could not write import cache: {}", e);
+            }
+        }
+
+        // Resolve artifact type aliases before items are filtered and linked
+        let artifact_types = ArtifactTypes::from_config(&self.config);
+        for item in &mut items {
+            artifact_types.canonicalize(item);
+        }
+        let items = self.filter_by_tags(items);
+
         // 2. Link items together
-        let linker = Linker::new();
+        // Config::from_file rejects unknown statuses; here they can only come from the builder
+        let excluded_statuses = self
+            .config
+            .excluded_statuses
+            .iter()
+            .filter_map(|status| {
+                let parsed = ItemStatus::parse_with(status, &self.config.custom_statuses);
+                if parsed.is_none() {
+                    log::error!("Unknown excluded status '{}'", status);
+                }
+                parsed
+            })
+            .collect();
+        let linker = Linker::new()
+            .exclude_statuses(excluded_statuses)
+            .revision_matching(self.config.revision_matching)
+            .artifact_types(artifact_types)
+            .severity_policy(self.config.severity.clone());
         let linked_items = linker.link_items(items)?;
 
         // 3. Analyze coverage and defects
-        let trace_result = self.analyze_trace(&linked_items);
+        let trace_result = self.analyze_trace(&linked_items)?;
 
         Ok(trace_result)
     }
 
+    /// Drop the items not passing the configured tag filter
+    ///
+    /// Links of the remaining items to dropped items are removed as well, so that
+    /// they are not reported as orphaned.
+    fn filter_by_tags(&self, items: Vec<SpecificationItem>) -> Vec<SpecificationItem> {
+        let filter = &self.config.filter_tags;
+        if filter.is_empty() {
+            return items;
+        }
+
+        let (mut kept, dropped): (Vec<_>, Vec<_>) =
+            items.into_iter().partition(|item| filter.matches(&item.tags));
+        let kept_names: HashSet<_> = kept
+            .iter()
+            .map(|item| (item.id.artifact_type.clone(), item.id.name.clone()))
+            .collect();
+        let dropped_names: HashSet<_> = dropped
+            .into_iter()
+            .map(|item| (item.id.artifact_type, item.id.name))
+            .filter(|name| !kept_names.contains(name))
+            .collect();
+        let is_dropped = |id: &SpecificationItemId| {
+            dropped_names.contains(&(id.artifact_type.clone(), id.name.clone()))
+        };
+        for item in &mut kept {
+            item.covers.retain(|id| !is_dropped(id));
+            item.depends.retain(|id| !is_dropped(id));
+        }
+        kept
+    }
+
     /// Generate an HTML report for the trace result
     pub fn generate_html_report(
         &self,
@@ -60,15 +382,99 @@ impl Tracer {
         reporter.generate_report(trace_result, output_path)
     }
 
+    /// Export the items of this project as an artifact file for other projects
+    ///
+    /// Items imported from `external_artifacts` are left out, so that each item
+    /// is only published by the project defining it.
+    pub fn export_artifacts(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        let artifact_types = ArtifactTypes::from_config(&self.config);
+        let mut external_ids = HashSet::new();
+        for artifacts in &self.config.external_artifacts {
+            for mut item in ArtifactImporter::new().import_from_file(artifacts)? {
+                artifact_types.canonicalize(&mut item);
+                external_ids.insert(item.id);
+            }
+        }
+
+        let mut exporter = crate::reporters::ArtifactExporter::new();
+        let project = std::env::current_dir()?
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        if let Some(project) = project {
+            exporter = exporter.with_project(project);
+        }
+        let items = trace_result
+            .items
+            .iter()
+            .map(|item| &item.item)
+            .filter(|item| !external_ids.contains(&item.id));
+        exporter.write(items, output_path)
+    }
+
+    /// Generate all configured compliance matrices and return their paths
+    ///
+    /// Relative matrix outputs are placed in the configured output directory.
+    pub fn generate_compliance_reports(&self, trace_result: &TraceResult) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for matrix in &self.config.compliance_matrices {
+            let path = match &self.config.output_dir {
+                Some(dir) if matrix.output.is_relative() => dir.join(&matrix.output),
+                _ => matrix.output.clone(),
+            };
+            crate::reporters::ComplianceReporter::new(matrix).generate_report(trace_result, &path)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Write the audit checksum manifest for the given report files if enabled
+    ///
+    /// The manifest and the serialized trace result are placed next to the
+    /// first report file. Returns the manifest path when one was written.
+    pub fn write_audit_manifest(
+        &self,
+        trace_result: &TraceResult,
+        report_files: &[PathBuf],
+    ) -> Result<Option<PathBuf>> {
+        if !self.config.audit.enabled {
+            return Ok(None);
+        }
+
+        let dir = report_files
+            .first()
+            .and_then(|path| path.parent())
+            .map(Path::to_path_buf)
+            .or_else(|| self.config.output_dir.clone())
+            .unwrap_or_default();
+        let manifest = crate::reporters::AuditWriter::new(&self.config.audit).write(
+            trace_result,
+            report_files,
+            &dir,
+        )?;
+        Ok(Some(manifest))
+    }
+
+    /// Store a summary of the run in the configured history directory
+    ///
+    /// Returns the delta against the previous run, or `None` when the history
+    /// is disabled or this is the first recorded run.
+    pub fn record_history(&self, trace_result: &TraceResult) -> Result<Option<RunDelta>> {
+        match &self.config.history_dir {
+            Some(dir) => RunHistory::new(dir).record(&RunSummary::from_trace_result(trace_result)),
+            None => Ok(None),
+        }
+    }
+
     /// Analyze the linked items to determine coverage and defects
-    fn analyze_trace(&self, linked_items: &[LinkedSpecificationItem]) -> TraceResult {
+    fn analyze_trace(&self, linked_items: &[LinkedSpecificationItem]) -> Result<TraceResult> {
         let total_items = linked_items.len();
         let mut defects = Vec::new();
         let mut coverage_summary = HashMap::new();
+        let ignored_items = IgnoredItems::new(&self.config.ignore_items)?;
 
-        // Group items by artifact type for coverage analysis
+        // Group items by artifact type for coverage analysis, leaving out ignored items
         let mut artifact_groups: HashMap<String, Vec<&LinkedSpecificationItem>> = HashMap::new();
-        for item in linked_items {
+        for item in linked_items.iter().filter(|item| !ignored_items.is_ignored(item.id())) {
             artifact_groups
                 .entry(item.item.id.artifact_type.clone())
                 .or_default()
@@ -104,126 +510,367 @@ impl Tracer {
             );
         }
 
-        // Collect defective items
-        for item in linked_items {
-            if item.is_defect {
-                let detailed_description = self.generate_detailed_defect_description(&item);
-                defects.push(Defect {
-                    defect_type: crate::core::DefectType::UncoveredItem,
-                    description: detailed_description,
-                    item_id: Some(item.item.id.clone()),
-                });
+        // Collect one defect or warning per issue of each item, as the policy demands
+        let policy = &self.config.severity;
+        let mut warnings = Vec::new();
+        let duplicate_defects = Self::duplicate_defects(linked_items)
+            .into_iter()
+            .map(|defect| (policy.link_severity(&LinkStatus::Duplicate), defect));
+        for (severity, defect) in linked_items
+            .iter()
+            .flat_map(|item| self.item_defects(item))
+            .chain(duplicate_defects)
+        {
+            match severity {
+                Severity::Error => defects.push(defect),
+                Severity::Warning => warnings.push(defect),
+                Severity::Ignore => {}
+            }
+        }
+
+        // Enforce change control on frozen items
+        if let Some(baseline_file) = &self.config.baseline_file {
+            if baseline_file.exists() {
+                defects.extend(Baseline::load(baseline_file)?.check(linked_items));
+            } else {
+                log::warn!("Baseline file does not exist: {}", baseline_file.display());
             }
         }
 
-        let is_success = defects.is_empty();
+        // Enforce the configured minimum coverage
+        defects.extend(
+            CoverageThresholdChecker::new(&self.config.min_coverage).check(&coverage_summary),
+        );
+
+        // Collect warnings that do not fail the trace
+        warnings.extend(CrossReferenceChecker::new().check(linked_items));
+        warnings.extend(DanglingItemChecker::new().check(linked_items));
+        if !self.config.glossary.is_empty() {
+            warnings.extend(GlossaryChecker::new(&self.config.glossary)?.check(linked_items));
+        }
+        if !self.config.naming.is_empty() {
+            warnings.extend(NamingChecker::new(&self.config.naming)?.check(linked_items));
+        }
+        if !self.config.code_coverage_reports.is_empty() {
+            let coverage =
+                CodeCoverageImporter::new().import_from_files(&self.config.code_coverage_reports)?;
+            warnings.extend(ExecutionChecker::new(&coverage, "impl").check(linked_items));
+        }
+
+        // Raise or lower defect types as configured; items are defects if errors remain
+        let (mut defects, mut warnings) = Self::apply_defect_severities(policy, defects, warnings);
+        ignored_items.retain(&mut defects);
+        ignored_items.retain(&mut warnings);
+
+        // Defects accepted in the defect baseline are only reported as warnings
+        let mut accepted = Vec::new();
+        if let Some(defect_baseline) = &self.config.defect_baseline {
+            if defect_baseline.exists() {
+                (defects, accepted) = DefectBaseline::load(defect_baseline)?.partition(defects);
+            } else {
+                log::warn!("Defect baseline does not exist: {}", defect_baseline.display());
+            }
+        }
+        warnings.extend(accepted);
+
+        let defective_ids: HashSet<_> = defects
+            .iter()
+            .filter_map(|defect| defect.item_id.as_ref())
+            .collect();
+        let items: Vec<_> = linked_items
+            .iter()
+            .map(|item| {
+                let mut item = item.clone();
+                item.is_defect = defective_ids.contains(item.id());
+                item
+            })
+            .collect();
+
+        // Evaluate tag-scoped gates separately from the global result
+        let gate_results = GateChecker::new(&self.config.tag_gates).evaluate(&items);
+
+        let implementation_metrics = MetricsCollector::new("impl").collect(&items);
+
+        let is_success = defects.is_empty() && gate_results.iter().all(|gate| gate.passed);
 
-        TraceResult {
-            items: linked_items.to_vec(),
+        let release_verdict = ReleaseReadiness::from_config(&self.config)?.evaluate(
+            &items,
+            &defects,
+            &gate_results,
+        );
+
+        Ok(TraceResult {
+            items,
             total_items,
             defect_count: defects.len(),
             defects,
+            warnings,
             coverage_summary,
+            gate_results,
+            release_verdict,
+            implementation_metrics,
+            metadata: ReportMetadata::collect(&self.config, "HEAD"),
             is_success,
-        }
+        })
     }
 
-    /// Generate a detailed description of what's wrong with a defective item
-    fn generate_detailed_defect_description(&self, item: &LinkedSpecificationItem) -> String {
+    /// Describe each issue of an item as a defect of the matching type, with its severity
+    ///
+    /// Valid links are never reported, whatever severity the policy gives them.
+    fn item_defects(&self, item: &LinkedSpecificationItem) -> Vec<(Severity, Defect)> {
+        let policy = &self.config.severity;
         let mut issues = Vec::new();
 
         // Check for broken outgoing links
         for link in &item.outgoing_links {
-            match link.status {
-                crate::core::LinkStatus::Orphaned => {
-                    issues.push(format!("covers non-existing item {}", link.target_id));
-                }
-                crate::core::LinkStatus::Duplicate => {
-                    issues.push(format!("has duplicate ID {}", item.item.id));
-                }
-                crate::core::LinkStatus::Outdated => {
-                    issues.push(format!("covers outdated revision of {}", link.target_id));
-                }
-                crate::core::LinkStatus::Predated => {
-                    issues.push(format!("covers newer revision of {}", link.target_id));
-                }
-                crate::core::LinkStatus::Ambiguous => {
-                    issues.push(format!("has ambiguous reference to {}", link.target_id));
-                }
-                _ => {}
-            }
+            let issue = match link.status {
+                LinkStatus::Orphaned => (
+                    DefectType::OrphanedCoverage,
+                    format!("covers non-existing item {}", link.target_id),
+                ),
+                LinkStatus::Outdated => (
+                    DefectType::WrongRevision,
+                    format!("covers outdated revision of {}", link.target_id),
+                ),
+                LinkStatus::Predated => (
+                    DefectType::WrongRevision,
+                    format!("covers newer revision of {}", link.target_id),
+                ),
+                LinkStatus::Ambiguous => (
+                    DefectType::AmbiguousReference,
+                    format!("has ambiguous reference to {}", link.target_id),
+                ),
+                LinkStatus::Unwanted => (
+                    DefectType::UnwantedCoverage,
+                    format!("covers {}, which does not need it", link.target_id),
+                ),
+                LinkStatus::BrokenDependency => (
+                    DefectType::BrokenReference,
+                    format!("depends on unknown item {}", link.target_id),
+                ),
+                _ => continue,
+            };
+            issues.push((policy.link_severity(&link.status), issue));
         }
 
+        // Check for incoming links the policy does not ignore
+        for link in &item.incoming_links {
+            let Some(source_id) = &link.source_id else {
+                continue;
+            };
+            let issue = match link.status {
+                LinkStatus::CoveredUnwanted => (
+                    DefectType::UnwantedCoverage,
+                    format!("is covered by {}, which it does not need", source_id),
+                ),
+                LinkStatus::CoveredOutdated => (
+                    DefectType::WrongRevision,
+                    format!("is covered by {} with an outdated revision", source_id),
+                ),
+                LinkStatus::CoveredPredated => (
+                    DefectType::WrongRevision,
+                    format!("is covered by {} with a newer revision", source_id),
+                ),
+                _ => continue,
+            };
+            issues.push((policy.link_severity(&link.status), issue));
+        }
+        issues.retain(|(severity, _)| *severity != Severity::Ignore);
+
         // Check for missing coverage
         if !matches!(item.coverage_status, CoverageStatus::Covered) {
-            let missing_coverage = self.find_missing_coverage_types(item);
+            let missing_coverage = item.missing_coverage_types();
             if !missing_coverage.is_empty() {
-                let coverage_list = missing_coverage.join(", ");
-                issues.push(format!("needs coverage by {}", coverage_list));
+                issues.push((
+                    Severity::Error,
+                    (
+                        DefectType::UncoveredItem,
+                        format!("needs coverage by {}", missing_coverage.join(", ")),
+                    ),
+                ));
             }
         }
 
-        if issues.is_empty() {
-            format!("Item {} has unspecified defects", item.item.id)
-        } else if issues.len() == 1 {
-            format!("Item {} {}", item.item.id, issues[0])
-        } else {
-            format!("Item {} has multiple issues: {}", item.item.id, issues.join("; "))
+        // Duplicates are reported once per ID by `duplicate_defects`
+        let is_duplicate = item
+            .outgoing_links
+            .iter()
+            .any(|link| link.status == LinkStatus::Duplicate);
+        let has_errors = issues
+            .iter()
+            .any(|(severity, _)| *severity == Severity::Error);
+        if item.is_defect && !has_errors && !is_duplicate {
+            issues.push((
+                Severity::Error,
+                (DefectType::UncoveredItem, "has unspecified defects".to_string()),
+            ));
         }
+        issues
+            .into_iter()
+            .map(|(severity, (defect_type, issue))| {
+                let defect = Defect {
+                    defect_type,
+                    description: format!("Item {} {}", item.item.id, issue),
+                    item_id: Some(item.item.id.clone()),
+                    location: item.item.location.clone(),
+                };
+                (severity, defect)
+            })
+            .collect()
     }
 
-    /// Find which artifact types are missing coverage for an item
-    fn find_missing_coverage_types(&self, item: &LinkedSpecificationItem) -> Vec<String> {
-        let mut missing = Vec::new();
-        
-        for needed_type in &item.item.needs {
-            // Check if this artifact type has any incoming coverage
-            let has_coverage = item.incoming_links.iter().any(|link| {
-                if let Some(source_id) = &link.source_id {
-                    source_id.artifact_type == *needed_type
-                } else {
-                    false
-                }
-            });
-            
-            if !has_coverage {
-                missing.push(needed_type.clone());
+    /// Move defects and warnings whose type the policy gives another severity
+    fn apply_defect_severities(
+        policy: &SeverityPolicy,
+        defects: Vec<Defect>,
+        warnings: Vec<Defect>,
+    ) -> (Vec<Defect>, Vec<Defect>) {
+        let found = defects
+            .into_iter()
+            .map(|defect| (Severity::Error, defect))
+            .chain(warnings.into_iter().map(|defect| (Severity::Warning, defect)));
+        let (mut defects, mut warnings) = (Vec::new(), Vec::new());
+        for (severity, defect) in found {
+            match policy.defect_severity(&defect.defect_type).unwrap_or(severity) {
+                Severity::Error => defects.push(defect),
+                Severity::Warning => warnings.push(defect),
+                Severity::Ignore => {}
             }
         }
-        
-        missing
+        (defects, warnings)
+    }
+
+    /// Report each duplicate ID once, with the locations of all its definitions
+    fn duplicate_defects(linked_items: &[LinkedSpecificationItem]) -> Vec<Defect> {
+        let mut locations: BTreeMap<String, (&LinkedSpecificationItem, Vec<String>)> =
+            BTreeMap::new();
+        for item in linked_items.iter().filter(|item| {
+            item.outgoing_links
+                .iter()
+                .any(|link| link.status == LinkStatus::Duplicate)
+        }) {
+            let location = match &item.item.location {
+                Some(location) => location.to_string(),
+                None => "unknown location".to_string(),
+            };
+            locations
+                .entry(item.id().to_string())
+                .or_insert_with(|| (item, Vec::new()))
+                .1
+                .push(location);
+        }
+
+        locations
+            .into_values()
+            .map(|(item, locations)| Defect {
+                defect_type: DefectType::DuplicateItem,
+                description: format!(
+                    "Item {} is defined {} times: {}",
+                    item.id(),
+                    locations.len(),
+                    locations.join(", ")
+                ),
+                item_id: Some(item.id().clone()),
+                location: item.item.location.clone(),
+            })
+            .collect()
     }
 }
 
 /// Result of a tracing operation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TraceResult {
     /// All linked specification items
     pub items: Vec<LinkedSpecificationItem>,
     /// Total number of items processed
     pub total_items: usize,
-    /// Number of items with defects
+    /// Number of defects, one per issue; an item can have several
     pub defect_count: usize,
     /// Defects found during tracing
     pub defects: Vec<Defect>,
+    /// Findings that are reported but do not fail the trace
+    pub warnings: Vec<Defect>,
     /// Coverage summary by artifact type
     pub coverage_summary: HashMap<String, CoverageSummary>,
-    /// Whether the trace was successful (no defects)
+    /// Results of the configured tag gates
+    pub gate_results: Vec<GateResult>,
+    /// Combined release readiness verdict
+    pub release_verdict: ReleaseVerdict,
+    /// Implementation metrics by item ID for items covered by implementation tags
+    pub implementation_metrics: BTreeMap<String, ImplementationMetrics>,
+    /// Tool version, time, revision and configuration digest of the trace
+    pub metadata: ReportMetadata,
+    /// Whether the trace was successful (no defects and all gates passed)
     pub is_success: bool,
 }
 
+#[cfg(test)]
+impl TraceResult {
+    /// Successful trace result of the given items, without defects, gates or metrics
+    pub(crate) fn from_items(items: Vec<LinkedSpecificationItem>) -> Self {
+        Self {
+            total_items: items.len(),
+            items,
+            defect_count: 0,
+            defects: Vec::new(),
+            warnings: Vec::new(),
+            coverage_summary: HashMap::new(),
+            gate_results: Vec::new(),
+            release_verdict: Default::default(),
+            implementation_metrics: BTreeMap::new(),
+            metadata: Default::default(),
+            is_success: true,
+        }
+    }
+}
+
 impl TraceResult {
     /// Check if the trace has no defects
     pub fn has_no_defects(&self) -> bool {
         self.is_success
     }
 
+    /// Check whether the coverage reaches the given minimum percentages
+    ///
+    /// A trace run with `min_coverage` configured already fails if it does not.
+    pub fn meets_thresholds(&self, min_coverage: &MinCoverage) -> bool {
+        CoverageThresholdChecker::new(min_coverage)
+            .check(&self.coverage_summary)
+            .is_empty()
+    }
+
+    /// Get the tag gates that are not satisfied
+    pub fn failed_gates(&self) -> Vec<&GateResult> {
+        self.gate_results.iter().filter(|gate| !gate.passed).collect()
+    }
+
+    /// Extract the coverage subtree of an item, e.g. for impact analysis of a feature
+    pub fn chain(&self, root_id: &SpecificationItemId) -> Result<TraceChain> {
+        TraceChain::for_item(self, root_id)
+    }
+
+    /// Get the links covering an outdated revision of an item, whose covering items need review
+    pub fn suspect_links(&self) -> Vec<SuspectLink> {
+        SuspectLink::find(self)
+    }
+
+    /// Compare this trace against a previous one, e.g. of the merge base of a branch
+    pub fn diff(&self, previous: &TraceResult) -> TraceDiff {
+        TraceDiff::between(previous, self)
+    }
+
+    /// Get the implementation metrics of an item, if it is covered by implementation tags
+    pub fn metrics_for(&self, id: &str) -> Option<&ImplementationMetrics> {
+        self.implementation_metrics.get(id)
+    }
+
     /// Get coverage percentage
     pub fn coverage_percentage(&self) -> f64 {
         if self.total_items == 0 {
             100.0
         } else {
-            let covered_items = self.total_items - self.defect_count;
+            // One item can have several defects, so count the items without any
+            let covered_items = self.items.iter().filter(|item| !item.is_defect).count();
             (covered_items as f64 / self.total_items as f64) * 100.0
         }
     }
@@ -241,6 +888,137 @@ impl TraceResult {
         result
     }
 
+    /// Get readiness summaries for each module, sorted by module name
+    ///
+    /// Items without a module (e.g. code tags) are not part of any summary.
+    pub fn module_summaries(&self) -> Vec<ModuleSummary> {
+        let mut modules: BTreeMap<&str, ModuleSummary> = BTreeMap::new();
+
+        for item in &self.items {
+            let Some(module) = item.item.module.as_deref() else {
+                continue;
+            };
+            let summary = modules.entry(module).or_insert_with(|| ModuleSummary {
+                module: module.to_string(),
+                total: 0,
+                covered: 0,
+                percentage: 100.0,
+                open_defects: 0,
+                status_counts: BTreeMap::new(),
+            });
+
+            summary.total += 1;
+            if item.is_covered() {
+                summary.covered += 1;
+            }
+            summary.open_defects += self
+                .defects
+                .iter()
+                .filter(|defect| defect.item_id.as_ref() == Some(item.id()))
+                .count();
+            *summary
+                .status_counts
+                .entry(item.item.status.to_string())
+                .or_insert(0) += 1;
+        }
+
+        modules
+            .into_values()
+            .map(|mut summary| {
+                summary.percentage = (summary.covered as f64 / summary.total as f64) * 100.0;
+                summary
+            })
+            .collect()
+    }
+
+    /// Get coverage summaries for each tag, sorted by tag
+    ///
+    /// Items with several tags count towards each of them.
+    pub fn tag_summaries(&self) -> Vec<TagSummary> {
+        let mut tags: BTreeMap<&str, TagSummary> = BTreeMap::new();
+
+        for item in &self.items {
+            let open_defects = self
+                .defects
+                .iter()
+                .filter(|defect| defect.item_id.as_ref() == Some(item.id()))
+                .count();
+            for tag in &item.item.tags {
+                let summary = tags.entry(tag).or_insert_with(|| TagSummary {
+                    tag: tag.clone(),
+                    total: 0,
+                    covered: 0,
+                    percentage: 100.0,
+                    open_defects: 0,
+                });
+                summary.total += 1;
+                if item.is_covered() {
+                    summary.covered += 1;
+                }
+                summary.open_defects += open_defects;
+            }
+        }
+
+        tags.into_values()
+            .map(|mut summary| {
+                summary.percentage = (summary.covered as f64 / summary.total as f64) * 100.0;
+                summary
+            })
+            .collect()
+    }
+
+    /// Get coverage summaries for each document the items were read from, sorted by path
+    ///
+    /// Items without a location (e.g. from issue trackers) are not part of any summary.
+    pub fn document_summaries(&self) -> Vec<DocumentSummary> {
+        let mut documents: BTreeMap<String, Vec<&LinkedSpecificationItem>> = BTreeMap::new();
+        for item in &self.items {
+            if let Some(location) = &item.item.location {
+                documents
+                    .entry(location.path.display().to_string())
+                    .or_default()
+                    .push(item);
+            }
+        }
+
+        documents
+            .into_iter()
+            .map(|(document, mut items)| {
+                items.sort_by_key(|item| item.item.location.as_ref().map(|location| location.line));
+                let total = items.len();
+                let covered = items.iter().filter(|item| item.is_covered()).count();
+                let mut coverage_by_type: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+                for item in &items {
+                    let missing = item.missing_coverage_types();
+                    for needed_type in &item.item.needs {
+                        let counts = coverage_by_type.entry(needed_type.clone()).or_default();
+                        counts.1 += 1;
+                        if !missing.contains(needed_type) {
+                            counts.0 += 1;
+                        }
+                    }
+                }
+                DocumentSummary {
+                    document,
+                    total,
+                    covered,
+                    percentage: (covered as f64 / total as f64) * 100.0,
+                    open_defects: self
+                        .defects
+                        .iter()
+                        .filter(|defect| {
+                            items
+                                .iter()
+                                .any(|item| defect.item_id.as_ref() == Some(item.id()))
+                        })
+                        .count(),
+                    items: items.iter().map(|item| item.id().clone()).collect(),
+                    coverage_by_type,
+                }
+            })
+            .collect()
+    }
+
     /// Get defect statistics grouped by defect type
     /// [impl->req~defect-type-statistics~1]
     pub fn defect_statistics(&self) -> HashMap<DefectType, usize> {
@@ -262,6 +1040,15 @@ impl TraceResult {
         let mut duplicate_count = 0;
         let mut wrong_revision_count = 0;
         let mut circular_count = 0;
+        let mut broken_reference_count = 0;
+        let mut terminology_count = 0;
+        let mut frozen_changed_count = 0;
+        let mut unexercised_count = 0;
+        let mut dangling_count = 0;
+        let mut unwanted_count = 0;
+        let mut threshold_count = 0;
+        let mut naming_count = 0;
+        let mut ambiguous_count = 0;
         
         for defect in &self.defects {
             match defect.defect_type {
@@ -288,6 +1075,15 @@ impl TraceResult {
                 DefectType::DuplicateItem => duplicate_count += 1,
                 DefectType::WrongRevision => wrong_revision_count += 1,
                 DefectType::CircularDependency => circular_count += 1,
+                DefectType::BrokenReference => broken_reference_count += 1,
+                DefectType::Terminology => terminology_count += 1,
+                DefectType::FrozenItemChanged => frozen_changed_count += 1,
+                DefectType::UnexercisedImplementation => unexercised_count += 1,
+                DefectType::DanglingItem => dangling_count += 1,
+                DefectType::UnwantedCoverage => unwanted_count += 1,
+                DefectType::CoverageThreshold => threshold_count += 1,
+                DefectType::NamingViolation => naming_count += 1,
+                DefectType::AmbiguousReference => ambiguous_count += 1,
             }
         }
         
@@ -311,7 +1107,363 @@ impl TraceResult {
         if circular_count > 0 {
             messages.push(format!("{} circular dependenc(ies) detected", circular_count));
         }
+        if broken_reference_count > 0 {
+            messages.push(format!(
+                "{} broken reference(s) to unknown items",
+                broken_reference_count
+            ));
+        }
+        if terminology_count > 0 {
+            messages.push(format!("{} terminology violation(s)", terminology_count));
+        }
+        if frozen_changed_count > 0 {
+            messages.push(format!(
+                "{} frozen item(s) changed without revision bump",
+                frozen_changed_count
+            ));
+        }
+        if unexercised_count > 0 {
+            messages.push(format!(
+                "{} implementation(s) never executed by tests",
+                unexercised_count
+            ));
+        }
+        if dangling_count > 0 {
+            messages.push(format!(
+                "{} item(s) cover nothing although needed",
+                dangling_count
+            ));
+        }
+        if unwanted_count > 0 {
+            messages.push(format!("{} unwanted coverage link(s)", unwanted_count));
+        }
+        if threshold_count > 0 {
+            messages.push(format!("{} coverage threshold(s) not met", threshold_count));
+        }
+        if naming_count > 0 {
+            messages.push(format!("{} naming rule violation(s)", naming_count));
+        }
+        if ambiguous_count > 0 {
+            messages.push(format!("{} ambiguous reference(s)", ambiguous_count));
+        }
 
         messages
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TagFilter;
+
+    #[test]
+    fn test_trace_with_reader() {
+        let spec = "# Login\n`req~login~1`\n\nNeeds: impl\n";
+        let tracer = Tracer::new(Config::empty());
+
+        let trace_result = tracer
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        assert_eq!(trace_result.total_items, 1);
+        let item = &trace_result.items[0].item;
+        assert_eq!(item.id.to_string(), "req~login~1");
+        assert_eq!(
+            item.location.as_ref().unwrap().path,
+            PathBuf::from(STDIN_ORIGIN)
+        );
+        assert!(trace_result.defect_count > 0);
+    }
+
+    #[test]
+    fn test_listed_files_are_traced_once() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let docs = temp_dir.path().join("docs");
+        std::fs::create_dir_all(&docs).unwrap();
+        let scanned = docs.join("scanned.md");
+        let listed = temp_dir.path().join("listed.md");
+        let source = temp_dir.path().join("lib.rs");
+        std::fs::write(&scanned, "`req~scanned~1`\n\nNeeds: impl\n").unwrap();
+        std::fs::write(&listed, "`req~listed~1`\n\nNeeds: impl\n").unwrap();
+        std::fs::write(&source, "// [impl->req~listed~1]\n").unwrap();
+
+        let config = Config::empty()
+            .add_spec_dir(&docs)
+            .add_spec_file(&scanned)
+            .add_spec_file(&listed)
+            .add_source_file(&source);
+        let trace_result = Tracer::new(config).trace().unwrap();
+
+        assert_eq!(trace_result.total_items, 3);
+        assert_eq!(trace_result.defect_count, 1);
+    }
+
+    #[test]
+    fn test_source_dir_rules() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let docs = temp_dir.path().join("docs");
+        let src = temp_dir.path().join("src");
+        let tests = temp_dir.path().join("tests");
+        for dir in [&docs, &src, &tests] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        std::fs::write(docs.join("spec.md"), "`req~login~1`\n\nNeeds: impl, itest\n").unwrap();
+        std::fs::write(src.join("login.rs"), "// @covers req~login~1\n").unwrap();
+        std::fs::write(tests.join("login.rs"), "// @covers req~login~1\n").unwrap();
+        std::fs::write(tests.join("fixture.py"), "# @covers req~login~1\n").unwrap();
+
+        let config = Config::empty()
+            .add_spec_dir(&docs)
+            .add_source_dir(&src)
+            .add_source_dir_with_rules(
+                SourceDir::new(&tests)
+                    .pattern("*.rs")
+                    .artifact_type("itest"),
+            )
+            .add_tag_format("@covers {id}");
+        let trace_result = Tracer::new(config).trace().unwrap();
+
+        let mut artifact_types: Vec<_> = trace_result
+            .items
+            .iter()
+            .map(|item| item.id().artifact_type.as_str())
+            .collect();
+        artifact_types.sort();
+        assert_eq!(artifact_types, vec!["impl", "itest", "req"]);
+        assert_eq!(trace_result.defect_count, 0);
+    }
+
+    #[test]
+    fn test_document_summaries() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let spec = temp_dir.path().join("spec.md");
+        std::fs::write(
+            &spec,
+            "`req~login~1`\n\nNeeds: impl\n\n`req~logout~1`\n\nNeeds: impl\n",
+        )
+        .unwrap();
+        let source = temp_dir.path().join("lib.rs");
+        std::fs::write(&source, "// [impl->req~logout~1]\n").unwrap();
+
+        let config = Config::empty().add_spec_file(&spec).add_source_file(&source);
+        let trace_result = Tracer::new(config).trace().unwrap();
+
+        let documents = trace_result.document_summaries();
+        assert_eq!(documents.len(), 2);
+        let spec_summary = documents
+            .iter()
+            .find(|document| document.document.ends_with("spec.md"))
+            .unwrap();
+        assert_eq!((spec_summary.covered, spec_summary.total), (1, 2));
+        assert_eq!(spec_summary.open_defects, 1);
+        assert_eq!(spec_summary.items[0].to_string(), "req~login~1");
+        assert_eq!(spec_summary.coverage_by_type_overview(), "impl 1/2");
+    }
+
+    #[test]
+    fn test_defect_types_per_issue() {
+        let spec = "`req~login~2`\n\nNeeds: impl\n\n\
+                    `dsn~login~1`\n\nCovers: req~login~1, req~logout~1\n\n\
+                    `dsn~login~1`\n";
+        let trace_result = Tracer::new(Config::empty())
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        let mut defects: Vec<_> = trace_result
+            .defects
+            .iter()
+            .map(|defect| (defect.defect_type.to_string(), defect.description.as_str()))
+            .collect();
+        defects.sort();
+        assert_eq!(
+            defects,
+            vec![
+                (
+                    "duplicate".to_string(),
+                    "Item dsn~login~1 is defined 2 times: <stdin>:5, <stdin>:9"
+                ),
+                (
+                    "orphaned".to_string(),
+                    "Item dsn~login~1 covers non-existing item req~logout~1"
+                ),
+                ("uncovered".to_string(), "Item req~login~2 needs coverage by impl"),
+                (
+                    "wrong-revision".to_string(),
+                    "Item dsn~login~1 covers outdated revision of req~login~1"
+                ),
+            ]
+        );
+        assert_eq!(trace_result.defect_statistics()[&DefectType::DuplicateItem], 1);
+    }
+
+    #[test]
+    fn test_coverage_by_excluded_item() {
+        let spec = "`req~export~1`\n\nNeeds: impl\n\n\
+                    `impl~export~1`\n\nStatus: rejected\n\nCovers: req~export~1\n";
+        let trace_result = Tracer::new(Config::empty().exclude_status("rejected"))
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        let descriptions: Vec<_> = trace_result
+            .defects
+            .iter()
+            .map(|defect| defect.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Item req~export~1 needs coverage by impl"]);
+        assert_eq!(
+            trace_result.items[0].incoming_links[0].status,
+            LinkStatus::CoveredExcluded
+        );
+    }
+
+    #[test]
+    fn test_accepted_defects_do_not_mark_items() {
+        let spec = "`req~login~1`\n\nNeeds: impl\n";
+        let trace_result = Tracer::new(Config::empty())
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let baseline = temp_dir.path().join("baseline.json");
+        DefectBaseline::from_defects(&trace_result.defects)
+            .save(&baseline)
+            .unwrap();
+
+        let trace_result = Tracer::new(Config::empty().defect_baseline(&baseline))
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+        assert!(trace_result.is_success);
+        assert_eq!(trace_result.warnings.len(), 1);
+        assert!(!trace_result.items[0].is_defect);
+        assert_eq!(trace_result.coverage_percentage(), 100.0);
+    }
+
+    #[test]
+    fn test_ignored_items_do_not_lower_coverage() {
+        let spec = "`req~export~1`\n\nNeeds: dsn\n\n\
+                    `req~legacy-import~1`\n\nNeeds: dsn\n\n\
+                    `dsn~export~1`\n\nCovers: req~export~1\n";
+        let config = Config::empty()
+            .ignore_item("req~legacy-*~*")
+            .min_coverage(MinCoverage::default().artifact_type("req", 100.0));
+        let trace_result = Tracer::new(config)
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        assert!(trace_result.is_success);
+        assert!(trace_result.defects.is_empty());
+        assert_eq!(trace_result.coverage_summary["req"].total, 1);
+        assert_eq!(trace_result.total_items, 3);
+    }
+
+    #[test]
+    fn test_more_defects_than_items() {
+        let spec = "`req~login~1`\n\nNeeds: dsn\n\n\
+                    `dsn~export~1`\n\nCovers: req~a~1, req~b~1, req~c~1\n";
+        let trace_result = Tracer::new(Config::empty())
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        assert_eq!(trace_result.total_items, 2);
+        assert_eq!(trace_result.defect_count, 4);
+        assert_eq!(trace_result.coverage_percentage(), 0.0);
+        let summary = crate::core::RunSummary::from_trace_result(&trace_result);
+        assert_eq!(summary.defects.len(), 4);
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let spec = "`req~login~1`\n\nNeeds: impl\nTags: safety\n\n\
+                    `req~theme~1`\n\nNeeds: impl\nTags: ui\n\n\
+                    `req~audit~1`\n\nNeeds: impl\nTags: safety, legacy\n\n\
+                    `impl~login~1`\n\nCovers: req~login~1, req~theme~1\n";
+        let filter = TagFilter {
+            include: vec!["safety".to_string(), TagFilter::UNTAGGED.to_string()],
+            exclude: vec!["legacy".to_string()],
+        };
+        let trace_result = Tracer::new(Config::empty().filter_tags(filter))
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        let ids: Vec<_> = trace_result.items.iter().map(|item| item.id().to_string()).collect();
+        assert_eq!(ids, vec!["req~login~1", "impl~login~1"]);
+        assert_eq!(trace_result.coverage_summary["req"].total, 1);
+        assert!(trace_result.is_success);
+    }
+
+    #[test]
+    fn test_severity_policy() {
+        let spec = "`req~login~2`\n\nNeeds: impl\n\n\
+                    `impl~login~2`\n\nCovers: req~login~2\n\n\
+                    `impl~old~1`\n\nCovers: req~login~1\n\n\
+                    `dsn~login~1`\n\nCovers: req~login~2\n";
+        let trace = |policy: SeverityPolicy| {
+            Tracer::new(Config::empty().severity(policy))
+                .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+                .unwrap()
+        };
+        assert!(!trace(SeverityPolicy::default()).is_success);
+
+        let policy = SeverityPolicy::default()
+            .link_status(LinkStatus::Outdated, Severity::Warning)
+            .link_status(LinkStatus::Unwanted, Severity::Error);
+        let trace_result = trace(policy.clone());
+        let defective: Vec<_> = trace_result
+            .items
+            .iter()
+            .filter(|item| item.is_defect)
+            .map(|item| item.id().to_string())
+            .collect();
+        assert_eq!(defective, vec!["dsn~login~1"]);
+        assert_eq!(trace_result.defects[0].defect_type, DefectType::UnwantedCoverage);
+        assert!(trace_result
+            .warnings
+            .iter()
+            .any(|warning| warning.item_id.as_ref().unwrap().name == "old"));
+
+        let trace_result =
+            trace(policy.defect_type(DefectType::UnwantedCoverage, Severity::Ignore));
+        assert!(trace_result.is_success);
+        assert!(trace_result.items.iter().all(|item| !item.is_defect));
+    }
+
+    #[test]
+    fn test_artifact_type_groups_and_aliases() {
+        let spec = "`req~login~1`\n\nNeeds: code, test\n\n\
+                    `impl~login~1`\n\nCovers: req~login~1\n\n\
+                    `itest~login~1`\n\nCovers: req~login~1\n";
+        let config = Config::empty()
+            .add_artifact_type_group("test", &["utest", "itest"])
+            .add_artifact_type_alias("code", "impl");
+        let trace_result = Tracer::new(config)
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        let req = &trace_result.items[0];
+        assert_eq!(req.item.needs, vec!["impl".to_string(), "test".to_string()]);
+        assert!(req.is_covered());
+        assert!(req.missing_coverage_types().is_empty());
+        assert!(trace_result.is_success);
+    }
+
+    #[test]
+    fn test_tag_summaries() {
+        let spec = "`req~login~1`\n\nNeeds: impl\nTags: security, ui\n\n\
+                    `req~audit~1`\n\nTags: security\n";
+        let trace_result = Tracer::new(Config::empty())
+            .trace_with_reader(spec.as_bytes(), InputFormat::Markdown)
+            .unwrap();
+
+        let tags: Vec<_> = trace_result
+            .tag_summaries()
+            .into_iter()
+            .map(|tag| (tag.tag, tag.covered, tag.total, tag.open_defects))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                ("security".to_string(), 1, 2, 1),
+                ("ui".to_string(), 0, 1, 1),
+            ]
+        );
+    }
+}
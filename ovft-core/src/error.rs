@@ -19,9 +19,27 @@ pub enum Error {
     #[error("Template error: {0}")]
     Template(#[from] askama::Error),
 
+    #[error("Custom template error: {0}")]
+    CustomTemplate(String),
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    #[error("XML parsing error: {0}")]
+    Xml(#[from] roxmltree::Error),
+
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("Spreadsheet error: {0}")]
+    Spreadsheet(#[from] calamine::Error),
+
+    #[error("Spreadsheet export error: {0}")]
+    SpreadsheetExport(#[from] rust_xlsxwriter::XlsxError),
+
     #[error("TOML parsing error: {0}")]
     Toml(#[from] toml::de::Error),
 
@@ -36,6 +54,15 @@ pub enum Error {
 
     #[error("Requirement not found: {0}")]
     RequirementNotFound(String),
+
+    #[error("Audit error: {0}")]
+    Audit(String),
+
+    #[error("HTTP error: {0}")]
+    Http(String),
+
+    #[error("Git error: {0}")]
+    Git(String),
 }
 
 /// Result type alias for the library
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::SpecificationItem;
+use crate::Result;
+
+/// Version of the artifact file format, raised on incompatible changes
+pub const ARTIFACT_FORMAT_VERSION: u32 = 1;
+
+/// Items of one project, exported for other projects to trace against
+///
+/// Written by [`crate::reporters::ArtifactExporter`] as compact JSON, so that
+/// e.g. the repository with the system requirements can publish them and the
+/// repositories with the code can cover them in their own traces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactFile {
+    /// Format version the file was written with
+    pub format_version: u32,
+    /// Name of the exporting project, prefixed to the item locations on import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// The specification items with their links, but without link statuses
+    pub items: Vec<SpecificationItem>,
+}
+
+/// Importer for artifact files exported by other projects
+pub struct ArtifactImporter;
+
+impl ArtifactImporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Import the items of an artifact file
+    ///
+    /// Locations are prefixed with the name of the exporting project, so that
+    /// reports show which repository an item comes from.
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = fs::read_to_string(file_path)?;
+        let file: ArtifactFile = serde_json::from_str(&content)?;
+        if file.format_version > ARTIFACT_FORMAT_VERSION {
+            return Err(crate::Error::Config(format!(
+                "Artifact file {} has format version {}, this version of ovft reads up to {}",
+                file_path.display(),
+                file.format_version,
+                ARTIFACT_FORMAT_VERSION
+            )));
+        }
+
+        let mut items = file.items;
+        if let Some(project) = &file.project {
+            for location in items
+                .iter_mut()
+                .flat_map(|item| [item.location.as_mut(), item.end_location.as_mut()])
+                .flatten()
+            {
+                location.path = PathBuf::from(project).join(&location.path);
+            }
+        }
+        Ok(items)
+    }
+}
+
+impl Default for ArtifactImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
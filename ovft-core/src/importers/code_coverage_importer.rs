@@ -0,0 +1,146 @@
+use roxmltree::Document;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::LineCoverage;
+use crate::{Error, Result};
+
+/// Importer for line coverage reports in lcov or Cobertura XML format
+pub struct CodeCoverageImporter;
+
+impl CodeCoverageImporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read several coverage reports into one set of line hits
+    pub fn import_from_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<LineCoverage> {
+        let mut coverage = LineCoverage::default();
+        for path in paths {
+            self.import_from_file(path.as_ref(), &mut coverage)?;
+        }
+        Ok(coverage)
+    }
+
+    /// Add the line hits of a single report, detecting the format from the extension
+    pub fn import_from_file(&self, path: &Path, coverage: &mut LineCoverage) -> Result<()> {
+        let content = fs::read_to_string(path)?;
+        let is_xml = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("xml"));
+
+        if is_xml {
+            Self::parse_cobertura(&content, coverage)
+        } else {
+            Self::parse_lcov(&content, path, coverage)
+        }
+    }
+
+    /// Parse the `SF:` and `DA:` records of an lcov tracefile
+    fn parse_lcov(content: &str, path: &Path, coverage: &mut LineCoverage) -> Result<()> {
+        let mut current_file: Option<PathBuf> = None;
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let location = || format!("{}:{}", path.display(), index + 1);
+            if let Some(file) = line.strip_prefix("SF:") {
+                current_file = Some(PathBuf::from(file));
+            } else if let Some(record) = line.strip_prefix("DA:") {
+                let Some(file) = &current_file else {
+                    return Err(Error::Parse {
+                        message: "DA record outside of a source file".to_string(),
+                        location: location(),
+                    });
+                };
+                let mut fields = record.split(',');
+                let (Some(Ok(number)), Some(Ok(hits))) = (
+                    fields.next().map(str::parse::<u32>),
+                    fields.next().map(str::parse::<u64>),
+                ) else {
+                    return Err(Error::Parse {
+                        message: format!("invalid DA record '{}'", record),
+                        location: location(),
+                    });
+                };
+                coverage.add_hits(file, number, hits);
+            } else if line == "end_of_record" {
+                current_file = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the `<class>` line entries of a Cobertura report
+    fn parse_cobertura(content: &str, coverage: &mut LineCoverage) -> Result<()> {
+        let document = Document::parse(content)?;
+        // Class file names are relative to the first source root, if any
+        let source_root = document
+            .descendants()
+            .find(|node| node.has_tag_name("source"))
+            .and_then(|node| node.text())
+            .map(|text| PathBuf::from(text.trim()));
+
+        for class in document
+            .descendants()
+            .filter(|node| node.has_tag_name("class"))
+        {
+            let Some(filename) = class.attribute("filename") else {
+                continue;
+            };
+            let file = match &source_root {
+                Some(root) => root.join(filename),
+                None => PathBuf::from(filename),
+            };
+
+            for line in class.descendants().filter(|node| node.has_tag_name("line")) {
+                let number = line.attribute("number").and_then(|n| n.parse().ok());
+                let hits = line.attribute("hits").and_then(|h| h.parse().ok());
+                if let (Some(number), Some(hits)) = (number, hits) {
+                    coverage.add_hits(&file, number, hits);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for CodeCoverageImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lcov_and_cobertura() {
+        let mut coverage = LineCoverage::default();
+        CodeCoverageImporter::parse_lcov(
+            "TN:\nSF:/work/project/src/lib.rs\nDA:3,0\nDA:4,2\nend_of_record\n",
+            Path::new("lcov.info"),
+            &mut coverage,
+        )
+        .unwrap();
+        CodeCoverageImporter::parse_cobertura(
+            r#"<coverage><sources><source>/work/project</source></sources>
+            <packages><package><classes>
+            <class filename="src/main.rs"><lines>
+            <line number="7" hits="1"/><line number="8" hits="0"/>
+            </lines></class>
+            </classes></package></packages></coverage>"#,
+            &mut coverage,
+        )
+        .unwrap();
+
+        assert_eq!(coverage.hits(Path::new("src/lib.rs"), 3), Some(0));
+        assert_eq!(coverage.hits(Path::new("src/lib.rs"), 4), Some(2));
+        assert_eq!(coverage.hits(Path::new("src/main.rs"), 7), Some(1));
+        assert_eq!(coverage.hits(Path::new("src/main.rs"), 5), None);
+        assert_eq!(coverage.hits(Path::new("src/other.rs"), 3), None);
+    }
+}
@@ -0,0 +1,221 @@
+use regex::Regex;
+use std::path::Path;
+
+/// Number of lines after a tag searched for the definition it documents
+const LOOKAHEAD_LINES: usize = 10;
+
+/// Keywords the C function pattern would otherwise take for a function name
+const C_KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "return", "else", "sizeof", "case", "do", "catch",
+];
+
+/// Languages whose definitions are recognized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Language {
+    Rust,
+    C,
+    Python,
+}
+
+impl Language {
+    /// Language of a file, judged by its extension
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "rs" => Some(Self::Rust),
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Self::C),
+            "py" => Some(Self::Python),
+            _ => None,
+        }
+    }
+}
+
+/// Recognizes definitions of functions, types and modules in source code
+#[derive(Clone)]
+pub(crate) struct SymbolFinder {
+    rust_definition: Regex,
+    rust_impl: Regex,
+    python_definition: Regex,
+    c_type_definition: Regex,
+    c_function_definition: Regex,
+}
+
+impl SymbolFinder {
+    /// Create a finder for all supported languages
+    pub(crate) fn new() -> Self {
+        Self {
+            rust_definition: Regex::new(
+                r#"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:(?:async|const|unsafe|default)\s+)*(?:extern\s+"[^"]*"\s+)?(?:fn|struct|enum|union|trait|mod|type)\s+([A-Za-z_]\w*)"#,
+            )
+            .unwrap(),
+            // The implementing type of `impl Trait for Type` or `impl Type`
+            rust_impl: Regex::new(
+                r"^\s*(?:unsafe\s+)?impl\b(?:<[^{]*?>)?\s+(?:[\w:]+(?:<[^{]*?>)?\s+for\s+)?(?:\w+::)*([A-Za-z_]\w*)",
+            )
+            .unwrap(),
+            python_definition: Regex::new(r"^\s*(?:async\s+)?(?:def|class)\s+([A-Za-z_]\w*)")
+                .unwrap(),
+            c_type_definition: Regex::new(
+                r"^\s*(?:typedef\s+)?(?:struct|class|enum|union|namespace)\s+([A-Za-z_]\w*)[^;]*$",
+            )
+            .unwrap(),
+            // A return type followed by a name and an opening parameter list, not a declaration
+            c_function_definition: Regex::new(
+                r"^\s*[A-Za-z_][\w:<>,\s\*&]*?[\s\*&]\**([A-Za-z_]\w*)\s*\([^;]*$",
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Index the definitions of the given source content
+    pub(crate) fn index<'a>(&self, content: &'a str, language: Language) -> SymbolIndex<'a> {
+        let lines: Vec<_> = content.lines().collect();
+        let definitions = lines
+            .iter()
+            .map(|line| self.definition(line, language))
+            .collect();
+        SymbolIndex { lines, definitions }
+    }
+
+    /// Name of the item defined on a line, if any
+    fn definition(&self, line: &str, language: Language) -> Option<String> {
+        let captures = match language {
+            Language::Rust => self
+                .rust_definition
+                .captures(line)
+                .or_else(|| self.rust_impl.captures(line)),
+            Language::Python => self.python_definition.captures(line),
+            Language::C => self.c_type_definition.captures(line).or_else(|| {
+                self.c_function_definition
+                    .captures(line)
+                    .filter(|captures| !C_KEYWORDS.contains(&&captures[1]))
+            }),
+        }?;
+        Some(captures[1].to_string())
+    }
+}
+
+/// Finds the code symbol a line of source belongs to
+///
+/// This is a line-based heuristic, not a parser: a definition encloses all
+/// following lines that are indented deeper than the definition itself.
+pub(crate) struct SymbolIndex<'a> {
+    lines: Vec<&'a str>,
+    /// Name of the definition on each line
+    definitions: Vec<Option<String>>,
+}
+
+impl SymbolIndex<'_> {
+    /// Dot-qualified name of the symbol a line (1-based) belongs to
+    ///
+    /// A tag in a comment right above a definition belongs to that definition;
+    /// any other line belongs to the innermost definition enclosing it.
+    pub(crate) fn symbol_at(&self, line_number: u32) -> Option<String> {
+        let index = (line_number as usize).checked_sub(1)?;
+        if index >= self.lines.len() {
+            return None;
+        }
+
+        let documented = (index..self.lines.len().min(index + LOOKAHEAD_LINES + 1))
+            .take_while(|&i| {
+                i == index || self.definitions[i].is_some() || is_preamble(self.lines[i])
+            })
+            .find(|&i| self.definitions[i].is_some());
+        match documented {
+            Some(definition) => self.qualified_name(definition),
+            None => {
+                let indent = indentation(self.lines[index]);
+                let enclosing = (0..index).rev().find(|&i| {
+                    self.definitions[i].is_some() && indentation(self.lines[i]) < indent
+                })?;
+                self.qualified_name(enclosing)
+            }
+        }
+    }
+
+    /// Join the names of a definition and all definitions enclosing it
+    fn qualified_name(&self, index: usize) -> Option<String> {
+        let mut names = vec![self.definitions[index].clone()?];
+        let mut indent = indentation(self.lines[index]);
+        for i in (0..index).rev() {
+            if let Some(name) = &self.definitions[i] {
+                let outer_indent = indentation(self.lines[i]);
+                if outer_indent < indent {
+                    names.push(name.clone());
+                    indent = outer_indent;
+                }
+            }
+        }
+        names.reverse();
+        Some(names.join("."))
+    }
+}
+
+/// Whether a line may sit between a tag and the definition it documents
+fn is_preamble(line: &str) -> bool {
+    let line = line.trim_start();
+    line.is_empty()
+        || ["//", "/*", "*", "#", "@", "\"\"\""]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_symbols() {
+        let content = "\
+// [impl->dsn~parse~1]
+pub fn parse() {}
+
+impl Display for Tracer {
+    /// Formats the tracer
+    // [impl->dsn~fmt~1]
+    #[inline]
+    fn fmt(&self) {
+        // [impl->dsn~body~1]
+        body();
+    }
+}
+";
+        let index = SymbolFinder::new().index(content, Language::Rust);
+        assert_eq!(index.symbol_at(1).as_deref(), Some("parse"));
+        assert_eq!(index.symbol_at(6).as_deref(), Some("Tracer.fmt"));
+        assert_eq!(index.symbol_at(9).as_deref(), Some("Tracer.fmt"));
+    }
+
+    #[test]
+    fn test_c_and_python_symbols() {
+        let c = "\
+struct session {
+    int id;
+};
+
+/* [impl->dsn~login~1] */
+static int login(struct session *s)
+{
+    if (s) {
+        return 1; // [impl->dsn~check~1]
+    }
+}
+";
+        let index = SymbolFinder::new().index(c, Language::C);
+        assert_eq!(index.symbol_at(5).as_deref(), Some("login"));
+        assert_eq!(index.symbol_at(9).as_deref(), Some("login"));
+
+        let python = "\
+class Session:
+    # [impl->dsn~login~1]
+    def login(self):
+        pass
+";
+        let index = SymbolFinder::new().index(python, Language::Python);
+        assert_eq!(index.symbol_at(2).as_deref(), Some("Session.login"));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::CommentStyle;
+
+/// Blank out everything but comments, keeping line breaks so line numbers stay valid
+///
+/// Block comment markers are checked before line comment markers so that
+/// styles like Lua's `--[[ ... ]]` win over the `--` line comment. String
+/// literals are skipped as in [`strict_text`], so a glob like `"src/**/*.rs"`
+/// does not open a comment.
+pub(crate) fn comment_text(content: &str, style: &CommentStyle) -> String {
+    scan(content, style, false, true)
+}
+
+/// Blank out string literals, and also code if `comments_only` is set
+///
+/// Used by strict scanning so that tags inside example strings, such as the
+/// fixtures of parser tests, are not taken for real tags. Comment markers
+/// inside strings do not start a comment. Double-quoted strings may span
+/// lines; single quotes only start char literals, which keeps Rust lifetimes
+/// and apostrophes in code from being read as strings.
+pub(crate) fn strict_text(content: &str, style: &CommentStyle, comments_only: bool) -> String {
+    scan(content, style, !comments_only, true)
+}
+
+fn scan(content: &str, style: &CommentStyle, keep_code: bool, skip_strings: bool) -> String {
+    let mut text = String::with_capacity(content.len());
+    let mut rest = content;
+    // End marker of the comment currently being read; "\n" for line comments
+    let mut comment_end: Option<&str> = None;
+
+    while let Some(c) = rest.chars().next() {
+        match comment_end {
+            Some(end) if rest.starts_with(end) => {
+                text.push_str(&blank(end));
+                rest = &rest[end.len()..];
+                comment_end = None;
+            }
+            Some(_) => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+            None => {
+                if let Some(len) = skip_strings.then(|| string_literal(rest)).flatten() {
+                    text.push_str(&blank(&rest[..len]));
+                    rest = &rest[len..];
+                    continue;
+                }
+
+                let marker = style
+                    .block
+                    .iter()
+                    .find(|(start, _)| rest.starts_with(start.as_str()))
+                    .map(|(start, end)| (start.as_str(), end.as_str()))
+                    .or_else(|| {
+                        style
+                            .line
+                            .iter()
+                            .find(|start| rest.starts_with(start.as_str()))
+                            .map(|start| (start.as_str(), "\n"))
+                    });
+                match marker {
+                    Some((start, end)) => {
+                        text.push_str(&blank(start));
+                        rest = &rest[start.len()..];
+                        comment_end = Some(end);
+                    }
+                    None => {
+                        text.push(if keep_code || c == '\n' { c } else { ' ' });
+                        rest = &rest[c.len_utf8()..];
+                    }
+                }
+            }
+        }
+    }
+
+    text
+}
+
+/// Length of the string or char literal at the start of the text, if any
+fn string_literal(text: &str) -> Option<usize> {
+    if text.starts_with('\'') {
+        return char_literal(text);
+    }
+    if !text.starts_with('"') {
+        return None;
+    }
+    let mut chars = text.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return Some(index + 1),
+            _ => {}
+        }
+    }
+    // An unterminated string runs to the end of the content
+    Some(text.len())
+}
+
+/// Length of the char literal like `'x'`, `'\n'` or `'\u{1F600}'` at the start of the text
+///
+/// Anything else starting with a single quote, such as a Rust lifetime or an
+/// apostrophe, is not a literal.
+fn char_literal(text: &str) -> Option<usize> {
+    let rest = &text[1..];
+    let body = if let Some(escape) = rest.strip_prefix("\\u{") {
+        3 + escape.find('}')? + 1
+    } else if rest.starts_with("\\x") {
+        4
+    } else if let Some(escaped) = rest.strip_prefix('\\') {
+        1 + escaped.chars().next()?.len_utf8()
+    } else {
+        let c = rest.chars().next().filter(|c| !matches!(c, '\'' | '\n'))?;
+        c.len_utf8()
+    };
+    (rest.get(body..)?.starts_with('\'')).then_some(body + 2)
+}
+
+/// Replace text by spaces, keeping its line breaks
+fn blank(text: &str) -> String {
+    text.chars()
+        .map(|c| if c == '\n' { '\n' } else { ' ' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_comments_remain() {
+        let style = CommentStyle::new(&["//"], &[("/*", "*/")]);
+        let content =
+            "let a = \"[impl->req~a~1]\"; // [impl->req~b~1]\n/* [impl->req~c~1]\n[end] */ x";
+        let text = comment_text(content, &style);
+
+        assert_eq!(text.lines().count(), content.lines().count());
+        assert!(!text.contains("req~a~1"));
+        assert!(text.lines().next().unwrap().contains("[impl->req~b~1]"));
+        assert!(text.contains("[impl->req~c~1]\n[end]"));
+        assert!(!text.contains('x'));
+    }
+
+    #[test]
+    fn test_comment_markers_in_strings() {
+        let style = CommentStyle::new(&["//"], &[("/*", "*/")]);
+        let content =
+            "let glob = \"src/**/*.rs\"; // [impl->req~a~1]\nlet b = '/'; // [impl->req~b~1]";
+        let text = comment_text(content, &style);
+
+        assert!(!text.contains("src"));
+        assert!(text.contains("[impl->req~a~1]"));
+        assert!(text.contains("[impl->req~b~1]"));
+
+        let content = "pub struct Parser<'a> { // doesn't own the input [impl->dsn~parser~1]\n\
+                       let c = '\\u{2F}'; let d = '\\''; // [impl->dsn~chars~1]";
+        let text = comment_text(content, &style);
+        assert!(text.contains("[impl->dsn~parser~1]"));
+        assert!(text.contains("[impl->dsn~chars~1]"));
+        assert!(!text.contains("u{2F}"));
+    }
+
+    #[test]
+    fn test_strict_text_skips_string_literals() {
+        let style = CommentStyle::new(&["//"], &[("/*", "*/")]);
+        let content = "\
+let s = \"// [impl->req~a~1]\";
+let multi = \"\\
+// [impl->req~b~1] \\\" still a string
+\";
+fn f<'a>(x: &'a str) -> char { '\"' } // [impl->req~c~1]
+";
+        let text = strict_text(content, &style, true);
+        assert_eq!(text.lines().count(), content.lines().count());
+        assert!(!text.contains("req~a~1"));
+        assert!(!text.contains("req~b~1"));
+        assert!(text.contains("[impl->req~c~1]"));
+
+        let text = strict_text(content, &CommentStyle::default(), false);
+        assert!(!text.contains("req~a~1"));
+        assert!(text.starts_with("let s = "));
+    }
+}
@@ -0,0 +1,133 @@
+use regex::Regex;
+use std::path::Path;
+use std::process::Command;
+
+use crate::core::{SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Separates the fields of one commit in the `git log` output
+const FIELD_SEPARATOR: char = '\u{1f}';
+/// Separates commits in the `git log` output
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// Importer turning coverage tags in git commit messages into evidence items
+///
+/// A commit message line like `Covers: req~hotfix~1, req~audit-log~2` yields one
+/// item per commit (e.g. `impl~commit-1a2b3c4d5e6f~0`) covering the listed items.
+pub struct CommitImporter {
+    /// Artifact type of the created evidence items
+    artifact_type: String,
+    /// Regex for matching the covers line of a commit message
+    covers_regex: Regex,
+    /// Regex for matching item IDs in the covers line
+    item_ref_regex: Regex,
+}
+
+impl CommitImporter {
+    /// Create a commit importer producing items of the given artifact type
+    pub fn new<S: Into<String>>(artifact_type: S) -> Self {
+        Self {
+            artifact_type: artifact_type.into(),
+            covers_regex: Regex::new(r"(?im)^\s*Covers:\s*(.+)$").unwrap(),
+            item_ref_regex: Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap(),
+        }
+    }
+
+    /// Import evidence items from the history of the git repository at `repo_dir`
+    pub fn import_from_repository(&self, repo_dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo_dir)
+            .arg("log")
+            .arg(format!(
+                "--format=%H{}%s{}%B{}",
+                FIELD_SEPARATOR, FIELD_SEPARATOR, RECORD_SEPARATOR
+            ))
+            .output()?;
+
+        if !output.status.success() {
+            return Err(crate::Error::Git(format!(
+                "git log failed in {}: {}",
+                repo_dir.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(self.parse_log(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Parse `git log` output in the importer's record format
+    fn parse_log(&self, log: &str) -> Vec<SpecificationItem> {
+        log.split(RECORD_SEPARATOR)
+            .filter_map(|record| {
+                let mut fields = record.trim_start().splitn(3, FIELD_SEPARATOR);
+                let hash = fields.next()?.trim();
+                let subject = fields.next()?.trim();
+                let body = fields.next()?;
+                self.parse_commit(hash, subject, body)
+            })
+            .collect()
+    }
+
+    /// Create the evidence item for one commit, if its message covers anything
+    fn parse_commit(&self, hash: &str, subject: &str, body: &str) -> Option<SpecificationItem> {
+        let covers: Vec<_> = self
+            .covers_regex
+            .captures_iter(body)
+            .flat_map(|captures| {
+                self.item_ref_regex
+                    .captures_iter(captures.get(1).unwrap().as_str())
+                    .filter_map(|id| {
+                        Some(SpecificationItemId::new(
+                            id[1].to_string(),
+                            id[2].to_string(),
+                            id[3].parse().ok()?,
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if covers.is_empty() || hash.is_empty() {
+            return None;
+        }
+
+        let short_hash = &hash[..hash.len().min(12)];
+        let id = SpecificationItemId::new(
+            self.artifact_type.clone(),
+            format!("commit-{}", short_hash),
+            0, // Default revision for auto-generated items
+        );
+
+        Some(
+            SpecificationItem::builder(id)
+                .title(subject.to_string())
+                .comment(format!("Commit {}", hash))
+                .covers_multiple(covers)
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log() {
+        let importer = CommitImporter::new("impl");
+        let log = format!(
+            "0123456789abcdef0123{f}Fix export crash{f}Fix export crash\n\nCovers: req~hotfix~1, req~export~2\n{r}\n\
+             fedcba9876543210fedc{f}Refactor{f}Refactor\n{r}\n",
+            f = FIELD_SEPARATOR,
+            r = RECORD_SEPARATOR
+        );
+
+        let items = importer.parse_log(&log);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id.to_string(), "impl~commit-0123456789ab~0");
+        assert_eq!(items[0].title.as_deref(), Some("Fix export crash"));
+        assert_eq!(items[0].covers.len(), 2);
+        assert_eq!(items[0].covers[1].to_string(), "req~export~2");
+    }
+}
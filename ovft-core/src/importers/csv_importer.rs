@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use super::table_mapper::TableMapper;
+use crate::config::CsvConfig;
+use crate::core::{Location, SpecificationItem};
+use crate::Result;
+
+/// Importer for requirement tables exported as CSV (e.g. from Excel or Jira)
+///
+/// The first record is the header row; the configured column mapping decides
+/// which column holds which item field.
+pub struct CsvImporter {
+    config: CsvConfig,
+    walker: FileWalker,
+}
+
+impl CsvImporter {
+    /// Create a CSV importer with the given delimiter and column mapping
+    pub fn new(config: CsvConfig) -> Self {
+        Self {
+            config,
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import specification items from all CSV files in a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_csv_file(path) && self.walker.is_text_file(path) {
+                items.extend(self.import_from_file(path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single CSV file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = read_text_file(file_path)?;
+        self.parse_csv(&content, file_path)
+    }
+
+    /// Import specification items from CSV content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.parse_csv(content, origin)
+    }
+
+    /// Check if a file is a CSV file
+    pub fn is_csv_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+    }
+
+    fn parse_csv(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.config.delimiter as u8)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+
+        let headers: Vec<String> = reader.headers()?.iter().map(str::to_string).collect();
+        let Some(mapper) = TableMapper::new(&self.config.columns, &headers) else {
+            log::warn!(
+                "Skipping {}: no '{}' column",
+                file_path.display(),
+                self.config.columns.id
+            );
+            return Ok(Vec::new());
+        };
+
+        let mut items = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line() as u32).unwrap_or(1);
+            let row: Vec<String> = record.iter().map(str::to_string).collect();
+            if let Some(item) = mapper.item(&row, Location::new(file_path.to_path_buf(), line))? {
+                items.push(item);
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ItemStatus;
+
+    #[test]
+    fn test_parse_csv_with_column_mapping() {
+        let mut config = CsvConfig {
+            delimiter: ';',
+            ..CsvConfig::default()
+        };
+        config.columns.id = "Key".to_string();
+        config.columns.title = "Summary".to_string();
+        config.columns.covers = "Links".to_string();
+
+        let content = "Key;Summary;Links;Needs;Status\n\
+                       req~export~1;Export;feat~reporting~1, feat~api~1;dsn, impl;Draft\n\
+                       ;Empty row;;;\n\
+                       req~import~1;\"Import; with delimiter\";;;\n";
+        let items = CsvImporter::new(config)
+            .parse_csv(content, Path::new("jira.csv"))
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id.to_string(), "req~export~1");
+        assert_eq!(items[0].title.as_deref(), Some("Export"));
+        assert_eq!(items[0].covers.len(), 2);
+        assert_eq!(items[0].needs, vec!["dsn", "impl"]);
+        assert_eq!(items[0].status, ItemStatus::Draft);
+        assert_eq!(items[0].location.as_ref().unwrap().line, 2);
+        assert_eq!(items[1].title.as_deref(), Some("Import; with delimiter"));
+    }
+}
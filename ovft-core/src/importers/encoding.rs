@@ -0,0 +1,93 @@
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE};
+use std::fs;
+use std::path::Path;
+
+use crate::Result;
+
+/// Read a text file in any common encoding
+///
+/// Files exported from Windows tools are often UTF-16 or a legacy code page
+/// such as Windows-1252. A byte order mark decides the encoding if present;
+/// otherwise UTF-8 is assumed if valid, UTF-16 if the content looks like it,
+/// and the detected legacy encoding else.
+pub(crate) fn read_text_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+    let (content, encoding) = decode(&bytes);
+    if encoding != encoding_rs::UTF_8 {
+        log::debug!("Read {} as {}", path.display(), encoding.name());
+    }
+    Ok(content)
+}
+
+/// Decode bytes to text, returning the encoding used
+pub(crate) fn decode(bytes: &[u8]) -> (String, &'static Encoding) {
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        let (content, _) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+        return (content.into_owned(), encoding);
+    }
+
+    if let Ok(content) = std::str::from_utf8(bytes) {
+        return (content.to_string(), encoding_rs::UTF_8);
+    }
+
+    let encoding = utf16_without_bom(bytes).unwrap_or_else(|| {
+        let mut detector = EncodingDetector::new();
+        detector.feed(bytes, true);
+        detector.guess(None, true)
+    });
+    let (content, _) = encoding.decode_without_bom_handling(bytes);
+    (content.into_owned(), encoding)
+}
+
+/// Recognize binary content by zero bytes, which text only contains as UTF-16
+pub(crate) fn is_binary(bytes: &[u8]) -> bool {
+    bytes.contains(&0) && Encoding::for_bom(bytes).is_none() && utf16_without_bom(bytes).is_none()
+}
+
+/// Recognize UTF-16 text without a byte order mark by its zero bytes
+///
+/// Mostly-ASCII text has a zero in every other byte, at odd positions for
+/// little endian and at even positions for big endian.
+fn utf16_without_bom(bytes: &[u8]) -> Option<&'static Encoding> {
+    if bytes.len() < 4 || !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let pairs = bytes.len() / 2;
+    let even_zeros = bytes.iter().step_by(2).filter(|&&b| b == 0).count();
+    let odd_zeros = bytes.iter().skip(1).step_by(2).filter(|&&b| b == 0).count();
+
+    if odd_zeros * 2 > pairs && even_zeros * 10 < pairs {
+        Some(UTF_16LE)
+    } else if even_zeros * 2 > pairs && odd_zeros * 10 < pairs {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_common_encodings() {
+        let text = "`req~grüße~1`\nCovers: feat~größe~1\n";
+        let utf16le: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let utf16be: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        let (latin1, _, _) = encoding_rs::WINDOWS_1252.encode(text);
+
+        let mut with_bom = vec![0xFF, 0xFE];
+        with_bom.extend(&utf16le);
+        let mut utf8_bom = vec![0xEF, 0xBB, 0xBF];
+        utf8_bom.extend(text.as_bytes());
+
+        assert_eq!(decode(text.as_bytes()).0, text);
+        assert_eq!(decode(&utf8_bom).0, text);
+        assert_eq!(decode(&with_bom), (text.to_string(), UTF_16LE));
+        assert_eq!(decode(&utf16le), (text.to_string(), UTF_16LE));
+        assert_eq!(decode(&utf16be), (text.to_string(), UTF_16BE));
+        assert_eq!(decode(&latin1).0, text);
+    }
+}
@@ -0,0 +1,282 @@
+use ignore::WalkBuilder;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::encoding::is_binary;
+use crate::config::Config;
+
+/// Name of the ovft specific ignore file, using `.gitignore` syntax
+pub const OVFT_IGNORE_FILE: &str = ".ovftignore";
+
+/// Number of bytes at the start of a file checked for binary content
+const BINARY_CHECK_LENGTH: u64 = 8192;
+
+/// Lists the files below a directory for the importers
+///
+/// By default `.gitignore`, `.ignore` and `.ovftignore` files are honored,
+/// also outside of git repositories, so vendored or generated code is not
+/// scanned. The `.git` directory itself is always skipped.
+///
+/// Symbolic links are skipped unless following them is enabled. Followed
+/// links that loop back to a parent directory are reported and not entered,
+/// and files reachable through several links are listed only once.
+///
+/// Files larger than the maximum size are not listed, and the text importers
+/// skip files with binary content. Clones of a walker share the record of
+/// skipped files, so one summary covers all importers.
+#[derive(Debug, Clone)]
+pub struct FileWalker {
+    respect_ignore_files: bool,
+    follow_symlinks: bool,
+    max_file_size: Option<u64>,
+    skipped: Arc<Mutex<BTreeMap<PathBuf, SkipReason>>>,
+}
+
+/// Why a file was not imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SkipReason {
+    /// Larger than the maximum file size, with its size in bytes
+    TooLarge(u64),
+    Binary,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge(size) => write!(f, "{} bytes exceed max_file_size", size),
+            Self::Binary => write!(f, "binary content"),
+        }
+    }
+}
+
+impl FileWalker {
+    /// Create a walker honoring ignore files
+    pub fn new() -> Self {
+        Self {
+            respect_ignore_files: true,
+            follow_symlinks: false,
+            max_file_size: None,
+            skipped: Arc::default(),
+        }
+    }
+
+    /// Create a walker with the traversal settings of a configuration
+    pub fn from_config(config: &Config) -> Self {
+        Self::new()
+            .respect_ignore_files(config.respect_ignore_files)
+            .follow_symlinks(config.follow_symlinks)
+            .max_file_size(config.max_file_size)
+    }
+
+    /// Set whether ignore files are honored
+    pub fn respect_ignore_files(mut self, respect: bool) -> Self {
+        self.respect_ignore_files = respect;
+        self
+    }
+
+    /// Set whether symbolic links are followed
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Set the size in bytes above which files are skipped (`None` for no limit)
+    pub fn max_file_size(mut self, max_file_size: Option<u64>) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Check if a file has text content, recording it as skipped otherwise
+    ///
+    /// Only the start of the file is read. Zero bytes mark binary content,
+    /// unless they belong to UTF-16 text.
+    pub fn is_text_file(&self, path: &Path) -> bool {
+        let mut head = Vec::new();
+        let read = fs::File::open(path)
+            .and_then(|file| file.take(BINARY_CHECK_LENGTH).read_to_end(&mut head));
+        // Unreadable files are left to the importer to report
+        if read.is_ok() && is_binary(&head) {
+            self.skip(path, SkipReason::Binary);
+            return false;
+        }
+        true
+    }
+
+    /// Log a summary of the files skipped since the last call
+    pub fn log_skipped_files(&self) {
+        let skipped = std::mem::take(&mut *self.skipped.lock().unwrap());
+        if skipped.is_empty() {
+            return;
+        }
+        for (path, reason) in &skipped {
+            log::info!("Skipped {}: {}", path.display(), reason);
+        }
+        let binary = skipped
+            .values()
+            .filter(|reason| **reason == SkipReason::Binary)
+            .count();
+        log::warn!(
+            "Skipped {} file(s): {} larger than max_file_size, {} with binary content",
+            skipped.len(),
+            skipped.len() - binary,
+            binary
+        );
+    }
+
+    fn skip(&self, path: &Path, reason: SkipReason) {
+        self.skipped
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), reason);
+    }
+
+    /// List all files below a directory in a stable order
+    pub fn files(&self, dir: &Path) -> Vec<PathBuf> {
+        let mut builder = WalkBuilder::new(dir);
+        builder
+            .standard_filters(self.respect_ignore_files)
+            .hidden(false)
+            .git_global(false)
+            .require_git(false)
+            .follow_links(self.follow_symlinks)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(|entry| entry.file_name() != ".git");
+        if self.respect_ignore_files {
+            builder.add_custom_ignore_filename(OVFT_IGNORE_FILE);
+        }
+
+        let mut seen = HashSet::new();
+        builder
+            .build()
+            .filter_map(|entry| match entry {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    log::warn!("Skipping entry while walking {}: {}", dir.display(), e);
+                    None
+                }
+            })
+            .filter(|entry| {
+                entry
+                    .file_type()
+                    .is_some_and(|file_type| file_type.is_file())
+            })
+            .filter(|entry| match (self.max_file_size, entry.metadata()) {
+                (Some(max_file_size), Ok(metadata)) if metadata.len() > max_file_size => {
+                    self.skip(entry.path(), SkipReason::TooLarge(metadata.len()));
+                    false
+                }
+                _ => true,
+            })
+            .map(|entry| entry.into_path())
+            .filter(|path| {
+                // Without following links every file is reached only once
+                !self.follow_symlinks
+                    || seen.insert(fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+            })
+            .collect()
+    }
+}
+
+impl Default for FileWalker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ignore_files_are_honored() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("vendor")).unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join(".gitignore"), "vendor/\n").unwrap();
+        fs::write(root.join(OVFT_IGNORE_FILE), "generated.rs\n").unwrap();
+        fs::write(root.join("vendor/lib.rs"), "").unwrap();
+        fs::write(root.join("src/generated.rs"), "").unwrap();
+        fs::write(root.join("src/main.rs"), "").unwrap();
+
+        let files = FileWalker::new().files(root);
+        let relative: Vec<_> = files
+            .iter()
+            .map(|path| path.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            relative,
+            vec![
+                PathBuf::from(".gitignore"),
+                PathBuf::from(OVFT_IGNORE_FILE),
+                PathBuf::from("src/main.rs")
+            ]
+        );
+
+        let all_files = FileWalker::new().respect_ignore_files(false).files(root);
+        assert_eq!(all_files.len(), 5);
+    }
+
+    #[test]
+    fn test_large_and_binary_files_are_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("generated.rs"),
+            "// [impl->req~x~1]\n".repeat(100),
+        )
+        .unwrap();
+        fs::write(root.join("image.rs"), [0x89, b'P', b'N', b'G', 0, 0, 0, 13]).unwrap();
+        let utf16: Vec<u8> = "// [impl->req~x~1]\n"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        fs::write(root.join("utf16.rs"), utf16).unwrap();
+
+        let walker = FileWalker::new().max_file_size(Some(1000));
+        let files: Vec<_> = walker
+            .files(root)
+            .into_iter()
+            .filter(|path| walker.is_text_file(path))
+            .collect();
+        assert_eq!(files, vec![root.join("utf16.rs")]);
+
+        let skipped = walker.skipped.lock().unwrap().clone();
+        assert_eq!(
+            skipped,
+            BTreeMap::from([
+                (root.join("generated.rs"), SkipReason::TooLarge(1900)),
+                (root.join("image.rs"), SkipReason::Binary),
+            ])
+        );
+        walker.log_skipped_files();
+        assert!(walker.skipped.lock().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_and_loops() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("docs/spec.md"), "").unwrap();
+        // A link to a sibling directory and a link back to the root
+        std::os::unix::fs::symlink(root.join("docs"), root.join("docs-link")).unwrap();
+        std::os::unix::fs::symlink(root, root.join("docs/loop")).unwrap();
+
+        assert_eq!(
+            FileWalker::new().files(root),
+            vec![root.join("docs/spec.md")]
+        );
+        assert_eq!(
+            FileWalker::new().follow_symlinks(true).files(root),
+            vec![root.join("docs/spec.md")]
+        );
+    }
+}
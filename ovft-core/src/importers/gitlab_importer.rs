@@ -0,0 +1,264 @@
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::env;
+use std::path::PathBuf;
+
+use crate::config::GitLabConfig;
+use crate::core::{Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Number of issues or epics requested per page
+const PAGE_SIZE: usize = 100;
+
+/// Importer for GitLab project issues and group epics
+///
+/// Issues become items like `req~issue-42~1` and epics become items like
+/// `feat~epic-7~1`. An issue assigned to an epic covers it, so epics need
+/// coverage by the issue artifact type. `Needs:` and `Covers:` lines in a
+/// description add further links.
+pub struct GitLabImporter {
+    config: GitLabConfig,
+    needs_regex: Regex,
+    covers_regex: Regex,
+    item_ref_regex: Regex,
+}
+
+/// Issue as returned by the GitLab REST API
+#[derive(Debug, Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    web_url: String,
+    epic: Option<GitLabEpicRef>,
+    epic_iid: Option<u64>,
+}
+
+/// Epic an issue is assigned to
+#[derive(Debug, Deserialize)]
+struct GitLabEpicRef {
+    iid: u64,
+}
+
+/// Epic as returned by the GitLab REST API
+#[derive(Debug, Deserialize)]
+struct GitLabEpic {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    web_url: String,
+}
+
+impl GitLabImporter {
+    /// Create an importer for the configured GitLab project and group
+    pub fn new(config: GitLabConfig) -> Self {
+        Self {
+            config,
+            needs_regex: Regex::new(r"(?im)^\s*Needs:\s*(.+)$").unwrap(),
+            covers_regex: Regex::new(r"(?im)^\s*Covers:\s*(.+)$").unwrap(),
+            item_ref_regex: Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap(),
+        }
+    }
+
+    /// Fetch the configured issues and epics and convert them to items
+    pub fn import(&self) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if let Some(group) = &self.config.group {
+            let epics: Vec<GitLabEpic> =
+                self.fetch_all(&format!("groups/{}/epics", Self::encode_path(group)))?;
+            items.extend(epics.into_iter().map(|epic| self.epic_item(epic)));
+        }
+
+        if let Some(project) = &self.config.project {
+            let issues: Vec<GitLabIssue> =
+                self.fetch_all(&format!("projects/{}/issues", Self::encode_path(project)))?;
+            items.extend(issues.into_iter().map(|issue| self.issue_item(issue)));
+        }
+
+        Ok(items)
+    }
+
+    /// Request all pages of a list endpoint
+    fn fetch_all<T: DeserializeOwned>(&self, endpoint: &str) -> Result<Vec<T>> {
+        let url = format!(
+            "{}/api/v4/{}",
+            self.config.url.trim_end_matches('/'),
+            endpoint
+        );
+        let token = env::var(&self.config.token_env).ok();
+        let labels = self.config.labels.join(",");
+        let mut results = Vec::new();
+
+        for page in 1.. {
+            let mut request = ureq::get(&url)
+                .query("state", "all")
+                .query("per_page", &PAGE_SIZE.to_string())
+                .query("page", &page.to_string());
+            if !labels.is_empty() {
+                request = request.query("labels", &labels);
+            }
+            if let Some(token) = &token {
+                request = request.set("PRIVATE-TOKEN", token);
+            }
+
+            let response = request
+                .call()
+                .map_err(|e| crate::Error::Http(format!("GET {} failed: {}", url, e)))?;
+            let page_results: Vec<T> = response
+                .into_json()
+                .map_err(|e| crate::Error::Http(format!("Invalid response from {}: {}", url, e)))?;
+
+            let is_last_page = page_results.len() < PAGE_SIZE;
+            results.extend(page_results);
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn issue_item(&self, issue: GitLabIssue) -> SpecificationItem {
+        let id = SpecificationItemId::new(
+            self.config.issue_artifact_type.clone(),
+            format!("issue-{}", issue.iid),
+            1,
+        );
+        let mut item = self.item(
+            id,
+            issue.title,
+            issue.description,
+            issue.labels,
+            issue.web_url,
+        );
+
+        if let Some(epic_iid) = issue.epic.map(|epic| epic.iid).or(issue.epic_iid) {
+            item.covers.push(SpecificationItemId::new(
+                self.config.epic_artifact_type.clone(),
+                format!("epic-{}", epic_iid),
+                1,
+            ));
+        }
+
+        item
+    }
+
+    fn epic_item(&self, epic: GitLabEpic) -> SpecificationItem {
+        let id = SpecificationItemId::new(
+            self.config.epic_artifact_type.clone(),
+            format!("epic-{}", epic.iid),
+            1,
+        );
+        let mut item = self.item(id, epic.title, epic.description, epic.labels, epic.web_url);
+
+        if self.config.project.is_some() && !item.needs.contains(&self.config.issue_artifact_type) {
+            item.needs.push(self.config.issue_artifact_type.clone());
+        }
+
+        item
+    }
+
+    fn item(
+        &self,
+        id: SpecificationItemId,
+        title: String,
+        description: Option<String>,
+        labels: Vec<String>,
+        web_url: String,
+    ) -> SpecificationItem {
+        let description = description.unwrap_or_default();
+
+        let needs = self
+            .needs_regex
+            .captures_iter(&description)
+            .flat_map(|captures| {
+                captures[1]
+                    .split(',')
+                    .map(|needed| needed.trim().to_string())
+                    .filter(|needed| !needed.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let covers = self
+            .covers_regex
+            .captures_iter(&description)
+            .flat_map(|captures| {
+                self.item_ref_regex
+                    .captures_iter(captures.get(1).unwrap().as_str())
+                    .filter_map(|id| {
+                        Some(SpecificationItemId::new(
+                            id[1].to_string(),
+                            id[2].to_string(),
+                            id[3].parse().ok()?,
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let mut builder = SpecificationItem::builder(id)
+            .title(title)
+            .tags(labels)
+            .needs_multiple(needs)
+            .covers_multiple(covers)
+            .location(Location::new(PathBuf::from(web_url), 1));
+        if !description.trim().is_empty() {
+            builder = builder.description(description.trim().to_string());
+        }
+        builder.build()
+    }
+
+    /// Encode a project or group path for use in an API URL
+    fn encode_path(path: &str) -> String {
+        path.replace('/', "%2F")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_epic_items() {
+        let config = GitLabConfig {
+            group: Some("acme".to_string()),
+            ..GitLabConfig::for_project("acme/shop")
+        };
+        let importer = GitLabImporter::new(config);
+
+        let epic: GitLabEpic = serde_json::from_str(
+            r#"{"iid": 7, "title": "Checkout", "description": null, "labels": ["q3"],
+                "web_url": "https://gitlab.com/groups/acme/-/epics/7"}"#,
+        )
+        .unwrap();
+        let issue: GitLabIssue = serde_json::from_str(
+            r#"{"iid": 42, "title": "Pay by card", "labels": [],
+                "description": "Card payments.\n\nNeeds: dsn, impl\nCovers: feat~payments~2",
+                "web_url": "https://gitlab.com/acme/shop/-/issues/42",
+                "epic": {"id": 1007, "iid": 7, "title": "Checkout"}}"#,
+        )
+        .unwrap();
+
+        let epic = importer.epic_item(epic);
+        assert_eq!(epic.id.to_string(), "feat~epic-7~1");
+        assert_eq!(epic.needs, vec!["req"]);
+        assert_eq!(epic.tags, vec!["q3"]);
+
+        let issue = importer.issue_item(issue);
+        assert_eq!(issue.id.to_string(), "req~issue-42~1");
+        assert_eq!(issue.title.as_deref(), Some("Pay by card"));
+        assert_eq!(issue.needs, vec!["dsn", "impl"]);
+        let covers: Vec<_> = issue.covers.iter().map(|id| id.to_string()).collect();
+        assert_eq!(covers, vec!["feat~payments~2", "feat~epic-7~1"]);
+        assert_eq!(
+            issue.location.unwrap().path,
+            PathBuf::from("https://gitlab.com/acme/shop/-/issues/42")
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use crate::config::Config;
+use crate::core::SpecificationItem;
+use crate::Result;
+
+/// On-disk cache of the items parsed from each file
+///
+/// Entries are keyed by importer and file path. An entry is reused while the
+/// file's modification time and size are unchanged, or, if only the
+/// modification time changed, while its content hash still matches. The whole
+/// cache is discarded when the configuration or the ovft version changes.
+pub struct ImportCache {
+    path: PathBuf,
+    fingerprint: String,
+    previous: HashMap<String, CacheEntry>,
+    /// Entries of the files imported in this run
+    current: Mutex<HashMap<String, CacheEntry>>,
+}
+
+/// Cache file layout
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    fingerprint: String,
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// Cached items of one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Modification time in nanoseconds since the Unix epoch
+    modified: u128,
+    size: u64,
+    /// SHA-256 of the file content
+    content_hash: String,
+    items: Vec<SpecificationItem>,
+}
+
+impl ImportCache {
+    /// Load the cache file, starting empty if it is missing, unreadable or stale
+    pub fn load<P: Into<PathBuf>>(path: P, config: &Config) -> Self {
+        let path = path.into();
+        let fingerprint = Self::fingerprint(config);
+
+        let previous = match fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+        {
+            Some(cache) if cache.fingerprint == fingerprint => cache.entries,
+            Some(_) => {
+                log::info!("Configuration changed, discarding import cache");
+                HashMap::new()
+            }
+            None => HashMap::new(),
+        };
+
+        Self {
+            path,
+            fingerprint,
+            previous,
+            current: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the cached items of a file, or import it and cache the result
+    pub fn get_or_import<F>(
+        &self,
+        importer: &str,
+        file: &Path,
+        import: F,
+    ) -> Result<Vec<SpecificationItem>>
+    where
+        F: FnOnce(&Path) -> Result<Vec<SpecificationItem>>,
+    {
+        let key = format!("{}:{}", importer, file.display());
+        let metadata = fs::metadata(file)?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let size = metadata.len();
+
+        let cached = self.previous.get(&key);
+        let entry = match cached {
+            Some(entry) if entry.modified == modified && entry.size == size => entry.clone(),
+            _ => {
+                let content_hash = format!("{:x}", Sha256::digest(fs::read(file)?));
+                match cached {
+                    Some(entry) if entry.content_hash == content_hash => CacheEntry {
+                        modified,
+                        size,
+                        ..entry.clone()
+                    },
+                    _ => CacheEntry {
+                        modified,
+                        size,
+                        content_hash,
+                        items: import(file)?,
+                    },
+                }
+            }
+        };
+
+        let items = entry.items.clone();
+        self.current.lock().unwrap().insert(key, entry);
+        Ok(items)
+    }
+
+    /// Write the entries of all files imported in this run
+    ///
+    /// Files that were not imported (e.g. deleted ones) are dropped from the cache.
+    pub fn save(&self) -> Result<()> {
+        let cache = CacheFile {
+            fingerprint: self.fingerprint.clone(),
+            entries: self.current.lock().unwrap().clone(),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string(&cache)?)?;
+        Ok(())
+    }
+
+    fn fingerprint(config: &Config) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(serde_json::to_string(config).unwrap_or_default());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SpecificationItemId;
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cache_reuses_unchanged_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        let source = temp_dir.path().join("lib.rs");
+        fs::write(&source, "// [impl->dsn~export~1]\n").unwrap();
+
+        let imports = Cell::new(0);
+        let import = |_: &Path| {
+            imports.set(imports.get() + 1);
+            Ok(vec![SpecificationItem::new(
+                SpecificationItemId::parse("impl~export~0").unwrap(),
+            )])
+        };
+        let config = Config::empty();
+
+        let cache = ImportCache::load(&cache_path, &config);
+        cache.get_or_import("tag", &source, import).unwrap();
+        cache.save().unwrap();
+        assert_eq!(imports.get(), 1);
+
+        // Unchanged file is served from the cache
+        let cache = ImportCache::load(&cache_path, &config);
+        let items = cache.get_or_import("tag", &source, import).unwrap();
+        assert_eq!(items[0].id.to_string(), "impl~export~0");
+        assert_eq!(imports.get(), 1);
+
+        // Changed content is parsed again
+        fs::write(&source, "// [impl->dsn~export~2]\n// more\n").unwrap();
+        cache.get_or_import("tag", &source, import).unwrap();
+        assert_eq!(imports.get(), 2);
+        cache.save().unwrap();
+
+        // A different configuration invalidates everything
+        let cache = ImportCache::load(&cache_path, &config.clone().threads(2));
+        cache.get_or_import("tag", &source, import).unwrap();
+        assert_eq!(imports.get(), 3);
+    }
+}
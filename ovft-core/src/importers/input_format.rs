@@ -0,0 +1,93 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Format of specification content that does not come from a scanned directory,
+/// such as content piped in on stdin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Markdown specification document
+    Markdown,
+    /// Source code containing coverage tags
+    Source,
+    /// ReqIF XML export
+    ReqIf,
+    /// Org-mode document
+    Org,
+    /// YAML item list
+    Yaml,
+    /// CSV requirement table
+    Csv,
+}
+
+impl InputFormat {
+    /// Guess the format from a file extension; unknown extensions are treated as source code
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        match extension.as_str() {
+            "md" | "markdown" => Self::Markdown,
+            "reqif" => Self::ReqIf,
+            "org" => Self::Org,
+            "yaml" | "yml" => Self::Yaml,
+            "csv" => Self::Csv,
+            _ => Self::Source,
+        }
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "md" | "markdown" => Ok(Self::Markdown),
+            "source" | "src" | "tag" => Ok(Self::Source),
+            "reqif" => Ok(Self::ReqIf),
+            "org" => Ok(Self::Org),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "csv" => Ok(Self::Csv),
+            _ => Err(crate::Error::Config(format!(
+                "Unknown input format '{}' (expected markdown, source, reqif, org, yaml or csv)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Markdown => "markdown",
+            Self::Source => "source",
+            Self::ReqIf => "reqif",
+            Self::Org => "org",
+            Self::Yaml => "yaml",
+            Self::Csv => "csv",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_guess_format() {
+        assert_eq!("md".parse::<InputFormat>().unwrap(), InputFormat::Markdown);
+        assert_eq!("YAML".parse::<InputFormat>().unwrap(), InputFormat::Yaml);
+        assert!("docx".parse::<InputFormat>().is_err());
+        assert_eq!(
+            InputFormat::from_path(Path::new("docs/spec.reqif")),
+            InputFormat::ReqIf
+        );
+        assert_eq!(
+            InputFormat::from_path(Path::new("src/lib.rs")),
+            InputFormat::Source
+        );
+    }
+}
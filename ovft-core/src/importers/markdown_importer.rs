@@ -1,106 +1,287 @@
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use super::import_cache::ImportCache;
 use crate::config::Config;
-use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::core::{
+    ItemStatus, Location, SpecificationItem, SpecificationItemBuilder, SpecificationItemId,
+};
 use crate::Result;
-use regex::Regex;
-use std::fs;
+use rayon::prelude::*;
+use regex::{Regex, RegexSet, SetMatches};
+use serde::Deserialize;
+use std::borrow::Cow;
 use std::path::Path;
-use walkdir::WalkDir;
+use std::sync::Arc;
 
 /// Importer for parsing requirement specifications from markdown files
 /// [impl->dsn~markdown-importer-module~1]
 pub struct MarkdownImporter {
     /// Regex for matching specification item IDs like `req~user-login~1`
     id_regex: Regex,
+    /// Regex for matching IDs without artifact type like `user-login~1`, which take
+    /// the artifact type of the document front matter
+    short_id_regex: Regex,
     /// Regex for matching needs lines like "Needs: impl, utest"
     needs_regex: Regex,
-    /// Regex for matching covers lines like "Covers:" followed by bullet points
-    covers_regex: Regex,
     /// Regex for matching inline covers like "Covers: req~user~1, dsn~auth~1"
     covers_inline_regex: Regex,
-    /// Regex for matching depends lines like "Depends:" followed by bullet points
-    depends_regex: Regex,
+    /// Regex for matching inline depends like "Depends: req~a~1, dsn~b~2"
+    depends_inline_regex: Regex,
     /// Regex for matching tags lines like "Tags: security, authentication"
     tags_regex: Regex,
     /// Regex for matching status lines like "Status: approved"
     status_regex: Regex,
-    /// Regex for matching rationale sections
-    rationale_regex: Regex,
-    /// Regex for matching comment sections
-    comment_regex: Regex,
+    /// Project-specific statuses accepted in addition to the built-in ones
+    custom_statuses: Vec<String>,
+    /// Keys of `Key: value` lines read as custom attributes instead of text
+    custom_attributes: Vec<String>,
+    /// Regex for matching module lines like "Module: authentication"
+    module_regex: Regex,
     /// Regex for matching specification item references in lists
     item_ref_regex: Regex,
+    /// Regex for matching custom attribute lines like "ASIL: B"
+    attribute_regex: Regex,
+    /// Regex for matching forwarding lines like "arch-->dsn, impl : req~x~1"
+    forwarding_regex: Regex,
     /// Regex for matching title fields like "**Title:** Some title"
     title_field_regex: Regex,
     /// Regex for matching description fields like "**Description:** Some description"
     description_field_regex: Regex,
     /// Regex for matching rationale fields like "**Rationale:** Some rationale"
     rationale_field_regex: Regex,
+    /// All line patterns, including the ones for "Covers:", "Depends:", "Rationale:" and
+    /// "Comment:" section starts and `<!-- end -->` markers, matched in a single pass
+    line_patterns: RegexSet,
+    /// Treat the content of fenced code blocks as plain text instead of items and fields
+    skip_code_blocks: bool,
+    /// Apply `Needs:` lines outside of items to all items under the same heading
+    inherit_section_needs: bool,
+    /// Lists the files to scan
+    walker: FileWalker,
+    /// Previously parsed items of unchanged files
+    cache: Option<Arc<ImportCache>>,
 }
 
 impl MarkdownImporter {
     /// Create a new markdown importer
     pub fn new() -> Self {
-        Self {
-            id_regex: Regex::new(r"`([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)`").unwrap(),
-            needs_regex: Regex::new(r"(?i)^\*?\*?Needs:\*?\*?\s*(.+)$").unwrap(),
-            covers_regex: Regex::new(r"(?i)^\*?\*?Covers:\*?\*?\s*$").unwrap(),
-            covers_inline_regex: Regex::new(r"(?i)^\*?\*?Covers:\*?\*?\s*(.+)$").unwrap(),
-            depends_regex: Regex::new(r"(?i)^\*?\*?Depends:\*?\*?\s*$").unwrap(),
-            tags_regex: Regex::new(r"(?i)^\*?\*?Tags:\*?\*?\s*(.+)$").unwrap(),
-            status_regex: Regex::new(
-                r"(?i)^\*?\*?Status:\*?\*?\s*(draft|proposed|approved|rejected)\s*$",
+        let id_regex = Regex::new(r"`([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)`").unwrap();
+        let short_id_regex = Regex::new(r"`([a-zA-Z0-9._-]+)~(\d+)`").unwrap();
+        let needs_regex = Regex::new(r"(?i)^\*?\*?Needs:\*?\*?\s*(.+)$").unwrap();
+        let covers_regex = Regex::new(r"(?i)^\*?\*?Covers:\*?\*?\s*$").unwrap();
+        let covers_inline_regex = Regex::new(r"(?i)^\*?\*?Covers:\*?\*?\s*(.+)$").unwrap();
+        let depends_regex = Regex::new(r"(?i)^\*?\*?Depends:\*?\*?\s*$").unwrap();
+        let depends_inline_regex = Regex::new(r"(?i)^\*?\*?Depends:\*?\*?\s*(.+)$").unwrap();
+        let tags_regex = Regex::new(r"(?i)^\*?\*?Tags:\*?\*?\s*(.+)$").unwrap();
+        let status_regex =
+            Regex::new(r"(?i)^\*?\*?Status:\*?\*?\s*([a-z][a-z0-9_-]*)\s*$").unwrap();
+        let module_regex = Regex::new(r"(?i)^\*?\*?Module:\*?\*?\s*(.+)$").unwrap();
+        let rationale_regex = Regex::new(r"(?i)^\*?\*?Rationale:\*?\*?\s*$").unwrap();
+        let comment_regex = Regex::new(r"(?i)^\*?\*?Comment:\*?\*?\s*$").unwrap();
+        let item_ref_regex = Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap();
+        let attribute_regex =
+            Regex::new(r"^\*?\*?([A-Za-z][A-Za-z0-9_-]*):\*?\*?\s+(\S.*)$").unwrap();
+        let forwarding_regex = Regex::new(
+                r"^\s*([a-zA-Z]+)\s*-->\s*([a-zA-Z]+(?:\s*,\s*[a-zA-Z]+)*)\s*:\s*([a-zA-Z]+~[a-zA-Z0-9._-]+~\d+)\s*$",
             )
-            .unwrap(),
-            rationale_regex: Regex::new(r"(?i)^\*?\*?Rationale:\*?\*?\s*$").unwrap(),
-            comment_regex: Regex::new(r"(?i)^\*?\*?Comment:\*?\*?\s*$").unwrap(),
-            item_ref_regex: Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap(),
-            title_field_regex: Regex::new(r"(?i)^\*?\*?Title:\*?\*?\s*(.+)$").unwrap(),
-            description_field_regex: Regex::new(r"(?i)^\*?\*?Description:\*?\*?\s*(.+)$").unwrap(),
-            rationale_field_regex: Regex::new(r"(?i)^\*?\*?Rationale:\*?\*?\s*(.+)$").unwrap(),
+            .unwrap();
+        let end_marker_regex = Regex::new(r"(?i)^\s*<!--\s*end\s*-->\s*$").unwrap();
+        let title_field_regex = Regex::new(r"(?i)^\*?\*?Title:\*?\*?\s*(.+)$").unwrap();
+        let description_field_regex = Regex::new(r"(?i)^\*?\*?Description:\*?\*?\s*(.+)$").unwrap();
+        let rationale_field_regex = Regex::new(r"(?i)^\*?\*?Rationale:\*?\*?\s*(.+)$").unwrap();
+        // Ordered as the ID, FORWARDING, ... constants index them
+        let line_patterns = RegexSet::new(
+            [
+                &id_regex,
+                &forwarding_regex,
+                &end_marker_regex,
+                &needs_regex,
+                &covers_inline_regex,
+                &depends_inline_regex,
+                &title_field_regex,
+                &description_field_regex,
+                &rationale_field_regex,
+                &covers_regex,
+                &depends_regex,
+                &tags_regex,
+                &status_regex,
+                &module_regex,
+                &rationale_regex,
+                &comment_regex,
+                &attribute_regex,
+            ]
+            .map(Regex::as_str),
+        )
+        .unwrap();
+
+        Self {
+            id_regex,
+            short_id_regex,
+            needs_regex,
+            covers_inline_regex,
+            depends_inline_regex,
+            tags_regex,
+            status_regex,
+            custom_statuses: Vec::new(),
+            custom_attributes: Vec::new(),
+            module_regex,
+            item_ref_regex,
+            attribute_regex,
+            forwarding_regex,
+            title_field_regex,
+            description_field_regex,
+            rationale_field_regex,
+            line_patterns,
+            skip_code_blocks: true,
+            inherit_section_needs: false,
+            walker: FileWalker::new(),
+            cache: None,
         }
     }
 
+    /// Ignore IDs and fields inside fenced code blocks (the default), or parse them like any text
+    pub fn skip_code_blocks(mut self, skip: bool) -> Self {
+        self.skip_code_blocks = skip;
+        self
+    }
+
+    /// Let a `Needs:` line below a heading, outside of any item, apply to all items
+    /// nested under that heading that declare no `Needs:` of their own
+    ///
+    /// Headings then also end the item above them, unless they are nested deeper
+    /// than the item's own heading.
+    pub fn inherit_section_needs(mut self, inherit: bool) -> Self {
+        self.inherit_section_needs = inherit;
+        self
+    }
+
+    /// Accept the given project-specific statuses, e.g. `implemented` or `obsolete`
+    pub fn with_custom_statuses(mut self, statuses: Vec<String>) -> Self {
+        self.custom_statuses = statuses;
+        self
+    }
+
+    /// Read `Key: value` lines with the given keys, e.g. `ASIL`, as custom attributes
+    ///
+    /// Lines with other keys stay part of the text, so prose like `Note: ...` is kept.
+    pub fn with_custom_attributes(mut self, keys: Vec<String>) -> Self {
+        self.custom_attributes = keys;
+        self
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Reuse items of unchanged files from the given cache
+    pub fn with_cache(mut self, cache: Arc<ImportCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Import specification items from a directory
     pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
-        let mut items = Vec::new();
-
         if !dir.exists() {
             log::warn!("Directory does not exist: {}", dir.display());
-            return Ok(items);
+            return Ok(Vec::new());
         }
 
-        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
-
-            if path.is_file() && self.is_markdown_file(path) {
-                let file_items = self.import_from_file(path)?;
-                items.extend(file_items);
-            }
-        }
+        // Parse files in parallel; collecting keeps the walker's file order
+        let files: Vec<_> = self
+            .walker
+            .files(dir)
+            .into_iter()
+            .filter(|path| self.is_markdown_file(path) && self.walker.is_text_file(path))
+            .collect();
+        let file_items = files
+            .par_iter()
+            .map(|path| match &self.cache {
+                Some(cache) => {
+                    cache.get_or_import("markdown", path, |path| self.import_from_file(path))
+                }
+                None => self.import_from_file(path),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(items)
+        Ok(file_items.into_iter().flatten().collect())
     }
 
     /// Import specification items from a single markdown file
     pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
-        let content = fs::read_to_string(file_path)?;
+        let content = read_text_file(file_path)?;
         self.parse_markdown(&content, file_path)
     }
 
+    /// Import specification items from markdown content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.parse_markdown(content, origin)
+    }
+
     /// Parse markdown content for specification items
     fn parse_markdown(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
         let mut items = Vec::new();
+        let (document_defaults, content) = self.split_front_matter(content, file_path)?;
         let lines: Vec<&str> = content.lines().collect();
+        let in_code_block = self.code_block_lines(&lines);
         let mut line_number = 0;
+        // Needs declared for sections, with the level of their heading, innermost last
+        let mut section_needs: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut section_level = 0;
 
         while line_number < lines.len() {
             let line = lines[line_number];
+            let defaults = match section_needs.last() {
+                Some((_, needs)) => Cow::Owned(DocumentDefaults {
+                    needs: needs.clone(),
+                    ..document_defaults.clone()
+                }),
+                None => Cow::Borrowed(&document_defaults),
+            };
+
+            // Examples of the spec syntax in code blocks are not items
+            if in_code_block[line_number] {
+                line_number += 1;
+                continue;
+            }
+            let matches = self.line_patterns.matches(line);
 
+            // Forwarded coverage needs
+            if matches.matched(FORWARDING) {
+                let captures = self.forwarding_regex.captures(line).unwrap();
+                items.push(self.parse_forwarding(
+                    &captures,
+                    file_path,
+                    line_number + 1,
+                    &defaults,
+                )?);
+            }
+            // Requirement tables define one item per row
+            else if self.item_table_columns(&lines, line_number).is_some() {
+                items.extend(self.parse_item_table(
+                    &lines,
+                    &mut line_number,
+                    file_path,
+                    &defaults,
+                )?);
+            }
             // Look for specification item IDs in regular text (backticks)
-            if let Some(captures) = self.id_regex.captures(line) {
-                if let Some(item) =
-                    self.parse_specification_item(&lines, &mut line_number, file_path, &captures)?
-                {
+            else if matches.matched(ID) {
+                let captures = self.id_regex.captures(line).unwrap();
+                if let Some(item) = self.parse_specification_item(
+                    &lines,
+                    &in_code_block,
+                    &mut line_number,
+                    file_path,
+                    &captures,
+                    &defaults,
+                )? {
                     items.push(item);
                 }
             }
@@ -110,14 +291,27 @@ impl MarkdownImporter {
                 if let Some(captures) = self.item_ref_regex.captures(&heading_text) {
                     if let Some(item) = self.parse_specification_item(
                         &lines,
+                        &in_code_block,
                         &mut line_number,
                         file_path,
                         &captures,
+                        &defaults,
                     )? {
                         items.push(item);
                     }
+                } else if self.inherit_section_needs {
+                    // A new section ends all sections at the same or a deeper level
+                    section_level = self.heading_level(line);
+                    section_needs.retain(|(level, _)| *level < section_level);
                 }
             }
+            // Needs of the current section
+            else if self.inherit_section_needs && matches.matched(NEEDS) {
+                let captures = self.needs_regex.captures(line).unwrap();
+                let needs = self.parse_list(captures.get(1).unwrap().as_str());
+                section_needs.retain(|(level, _)| *level < section_level);
+                section_needs.push((section_level, needs));
+            }
 
             line_number += 1;
         }
@@ -129,9 +323,11 @@ impl MarkdownImporter {
     fn parse_specification_item(
         &self,
         lines: &[&str],
+        in_code_block: &[bool],
         line_number: &mut usize,
         file_path: &Path,
         id_captures: &regex::Captures,
+        defaults: &DocumentDefaults,
     ) -> Result<Option<SpecificationItem>> {
         let artifact_type = id_captures.get(1).unwrap().as_str();
         let name = id_captures.get(2).unwrap().as_str();
@@ -145,8 +341,15 @@ impl MarkdownImporter {
 
         let id = SpecificationItemId::new(artifact_type.to_string(), name.to_string(), revision);
 
-        let location = Location::new(file_path.to_path_buf(), (*line_number + 1) as u32);
-        let mut builder = SpecificationItem::builder(id).location(location);
+        let mut builder = self.item_builder(id, file_path, *line_number + 1, defaults);
+        let mut has_needs = false;
+
+        // Level of the heading naming the item, which nests the item's own subheadings
+        let item_level = [Some(*line_number), line_number.checked_sub(1)]
+            .into_iter()
+            .flatten()
+            .find(|&index| self.is_heading(lines[index]))
+            .map(|index| self.heading_level(lines[index]));
 
         // Look for title (if the ID is preceded by a heading, or extract from heading if ID is in heading)
         if *line_number > 0 {
@@ -191,8 +394,31 @@ impl MarkdownImporter {
         while *line_number < lines.len() {
             let line = lines[*line_number];
 
-            // Check if we've reached another specification item
-            if self.id_regex.is_match(line) {
+            if in_code_block[*line_number] {
+                // Code examples are part of the text, never item fields
+                self.append_to_section(
+                    &mut description,
+                    &mut rationale,
+                    &mut comment,
+                    current_section,
+                    line,
+                );
+                *line_number += 1;
+                continue;
+            }
+
+            let matches = self.line_patterns.matches(line);
+
+            // An explicit end marker closes the item; the text after it belongs to the document
+            if matches.matched(END_MARKER) {
+                break;
+            }
+
+            // Check if we've reached another item, a requirement table or a forwarding line
+            if matches.matched(ID)
+                || matches.matched(FORWARDING)
+                || self.item_table_columns(lines, *line_number).is_some()
+            {
                 *line_number -= 1; // Back up so the outer loop can process this
                 break;
             }
@@ -203,61 +429,108 @@ impl MarkdownImporter {
                     *line_number -= 1; // Back up so the outer loop can process this
                     break;
                 }
+                // With section Needs, headings that are not part of the item start a new section
+                if self.inherit_section_needs
+                    && item_level.is_none_or(|level| self.heading_level(line) <= level)
+                {
+                    *line_number -= 1;
+                    break;
+                }
             }
 
             // Check for section keywords
-            if let Some(captures) = self.needs_regex.captures(line) {
-                let needs_str = captures.get(1).unwrap().as_str();
-                let needs = self.parse_list(needs_str);
+            if matches.matched(NEEDS) {
+                let captures = self.needs_regex.captures(line).unwrap();
+                let needs_str = captures.get(1).unwrap().as_str().to_string()
+                    + &self.continuation(lines, in_code_block, line_number);
+                let needs = self.parse_list(&needs_str);
                 builder = builder.needs_multiple(needs);
-            } else if let Some(captures) = self.covers_inline_regex.captures(line) {
+                has_needs = true;
+            } else if matches.matched(COVERS_INLINE) {
                 // Handle inline covers like "Covers: req~user~1, dsn~auth~1"
-                let covers_str = captures.get(1).unwrap().as_str();
-                let covers_list = self.parse_covers_list(covers_str);
+                let location = Self::line_location(file_path, *line_number);
+                let captures = self.covers_inline_regex.captures(line).unwrap();
+                let covers_str = captures.get(1).unwrap().as_str().to_string()
+                    + &self.continuation(lines, in_code_block, line_number);
+                let covers_list = self.parse_covers_list(&covers_str);
                 for cover_id in covers_list {
-                    builder = builder.covers(cover_id);
+                    builder = builder.covers_at(cover_id, location.clone());
                 }
-            } else if let Some(captures) = self.title_field_regex.captures(line) {
+            } else if matches.matched(DEPENDS_INLINE) {
+                // Handle inline depends like "Depends: req~a~1, dsn~b~2"
+                let captures = self.depends_inline_regex.captures(line).unwrap();
+                let depends_str = captures.get(1).unwrap().as_str().to_string()
+                    + &self.continuation(lines, in_code_block, line_number);
+                depends_list.extend(self.parse_covers_list(&depends_str));
+            } else if matches.matched(TITLE_FIELD) {
                 // Handle structured title fields like "**Title:** Some title"
+                let captures = self.title_field_regex.captures(line).unwrap();
                 let title_str = captures.get(1).unwrap().as_str().trim();
                 if !title_str.is_empty() {
                     builder = builder.title(title_str.to_string());
                 }
-            } else if let Some(captures) = self.description_field_regex.captures(line) {
+            } else if matches.matched(DESCRIPTION_FIELD) {
                 // Handle structured description fields like "**Description:** Some description"
+                let captures = self.description_field_regex.captures(line).unwrap();
                 let desc_str = captures.get(1).unwrap().as_str().trim();
                 if !desc_str.is_empty() {
                     // Replace any existing description with the structured one
                     description = desc_str.to_string();
                 }
-            } else if let Some(captures) = self.rationale_field_regex.captures(line) {
+            } else if matches.matched(RATIONALE_FIELD) {
                 // Handle structured rationale fields like "**Rationale:** Some rationale"
+                let captures = self.rationale_field_regex.captures(line).unwrap();
                 let rationale_str = captures.get(1).unwrap().as_str().trim();
                 if !rationale_str.is_empty() {
                     rationale = rationale_str.to_string();
                 }
-            } else if self.covers_regex.is_match(line) {
+            } else if matches.matched(COVERS) {
                 current_section = Section::Covers;
-            } else if self.depends_regex.is_match(line) {
+            } else if matches.matched(DEPENDS) {
                 current_section = Section::Depends;
-            } else if let Some(captures) = self.tags_regex.captures(line) {
+            } else if matches.matched(TAGS) {
+                let captures = self.tags_regex.captures(line).unwrap();
                 let tags_str = captures.get(1).unwrap().as_str();
                 let tags = self.parse_list(tags_str);
                 builder = builder.tags(tags);
-            } else if let Some(captures) = self.status_regex.captures(line) {
+            } else if matches.matched(STATUS) {
+                let captures = self.status_regex.captures(line).unwrap();
                 let status_str = captures.get(1).unwrap().as_str();
-                let status = match status_str.to_lowercase().as_str() {
-                    "draft" => ItemStatus::Draft,
-                    "proposed" => ItemStatus::Proposed,
-                    "approved" => ItemStatus::Approved,
-                    "rejected" => ItemStatus::Rejected,
-                    _ => ItemStatus::Approved,
-                };
-                builder = builder.status(status);
-            } else if self.rationale_regex.is_match(line) {
+                match ItemStatus::parse_with(status_str, &self.custom_statuses) {
+                    Some(status) => builder = builder.status(status),
+                    None => log::warn!(
+                        "Unknown status '{}' at {}:{}",
+                        status_str,
+                        file_path.display(),
+                        *line_number + 1
+                    ),
+                }
+            } else if matches.matched(MODULE) {
+                let captures = self.module_regex.captures(line).unwrap();
+                let module = captures.get(1).unwrap().as_str().trim();
+                builder = builder.module(module.to_string());
+            } else if matches.matched(RATIONALE) {
                 current_section = Section::Rationale;
-            } else if self.comment_regex.is_match(line) {
+            } else if matches.matched(COMMENT) {
                 current_section = Section::Comment;
+            } else if let Some((key, value)) = matches
+                .matched(ATTRIBUTE)
+                .then(|| self.custom_attribute(line))
+                .flatten()
+            {
+                builder = builder.attribute(key, value);
+            } else if matches!(current_section, Section::Covers | Section::Depends)
+                && self.is_continuation(line)
+            {
+                // Indented lines of IDs below "Covers:" or "Depends:", e.g. after reflowing
+                let ids = self.parse_covers_list(line);
+                match current_section {
+                    Section::Covers => {
+                        let location = Self::line_location(file_path, *line_number);
+                        covers_list.extend(ids.into_iter().map(|id| (id, location.clone())))
+                    }
+                    _ => depends_list.extend(ids),
+                }
             } else if line.trim().starts_with('-')
                 || line.trim().starts_with('*')
                 || line.trim().starts_with('+')
@@ -266,7 +539,8 @@ impl MarkdownImporter {
                 match current_section {
                     Section::Covers => {
                         if let Some(item_id) = self.extract_item_reference(line) {
-                            covers_list.push(item_id);
+                            let location = Self::line_location(file_path, *line_number);
+                            covers_list.push((item_id, location));
                         }
                     }
                     Section::Depends => {
@@ -284,7 +558,18 @@ impl MarkdownImporter {
                         );
                     }
                 }
-            } else if !line.trim().is_empty() && !self.is_structured_field(line) {
+            } else if line.trim().is_empty() {
+                // Blank lines separate paragraphs and lists, which markdown rendering needs
+                if !matches!(current_section, Section::Covers | Section::Depends) {
+                    self.append_to_section(
+                        &mut description,
+                        &mut rationale,
+                        &mut comment,
+                        current_section,
+                        line,
+                    );
+                }
+            } else if !Self::is_structured_field(&matches) {
                 // Regular content line (but skip structured fields)
                 self.append_to_section(
                     &mut description,
@@ -299,6 +584,9 @@ impl MarkdownImporter {
         }
 
         // Build the final specification item
+        if !has_needs {
+            builder = builder.needs_multiple(defaults.needs.clone());
+        }
         if !description.trim().is_empty() {
             builder = builder.description(description.trim().to_string());
         }
@@ -308,8 +596,8 @@ impl MarkdownImporter {
         if !comment.trim().is_empty() {
             builder = builder.comment(comment.trim().to_string());
         }
-        if !covers_list.is_empty() {
-            builder = builder.covers_multiple(covers_list);
+        for (covered_id, location) in covers_list {
+            builder = builder.covers_at(covered_id, location);
         }
         if !depends_list.is_empty() {
             for dep in depends_list {
@@ -320,6 +608,177 @@ impl MarkdownImporter {
         Ok(Some(builder.build()))
     }
 
+    /// Location of the line with the given 0-based index
+    fn line_location(file_path: &Path, line_number: usize) -> Location {
+        Location::new(file_path.to_path_buf(), line_number as u32 + 1)
+    }
+
+    /// Start building an item defined at the given line (1-based) of a document
+    fn item_builder(
+        &self,
+        id: SpecificationItemId,
+        file_path: &Path,
+        line: usize,
+        defaults: &DocumentDefaults,
+    ) -> SpecificationItemBuilder {
+        let location = Location::new(file_path.to_path_buf(), line as u32);
+        let mut builder = SpecificationItem::builder(id).location(location);
+
+        // Items belong to the module named after their document unless a Module: field overrides it
+        if let Some(stem) = file_path.file_stem() {
+            builder = builder.module(stem.to_string_lossy().to_string());
+        }
+
+        // Front matter defaults; the item's own fields override the status and Needs
+        if let Some(status) = &defaults.status {
+            builder = builder.status(status.clone());
+        }
+        builder.tags(defaults.tags.clone())
+    }
+
+    /// Parse a forwarding line like `arch-->dsn, impl : req~x~1`
+    ///
+    /// As in OpenFastTrace, this creates the item `arch~x~1` covering `req~x~1`
+    /// that needs the target artifact types, so the `arch` coverage `req~x~1`
+    /// needs is delegated to those types without writing an `arch` item.
+    fn parse_forwarding(
+        &self,
+        captures: &regex::Captures,
+        file_path: &Path,
+        line: usize,
+        defaults: &DocumentDefaults,
+    ) -> Result<SpecificationItem> {
+        let skipped_type = &captures[1];
+        let covered_id =
+            SpecificationItemId::parse(&captures[3]).map_err(|e| crate::Error::Parse {
+                message: e.to_string(),
+                location: format!("{}:{}", file_path.display(), line),
+            })?;
+        let id = SpecificationItemId::new(
+            skipped_type.to_string(),
+            covered_id.name.clone(),
+            covered_id.revision,
+        );
+
+        Ok(self
+            .item_builder(id, file_path, line, defaults)
+            .needs_multiple(self.parse_list(&captures[2]))
+            .covers(covered_id)
+            .build())
+    }
+
+    /// Column headers of the requirement table whose header is at the given line, if any
+    ///
+    /// A requirement table is a markdown table with an `ID` column, e.g.
+    /// `| ID | Title | Description | Needs | Covers |`, one item per row.
+    fn item_table_columns(&self, lines: &[&str], index: usize) -> Option<Vec<String>> {
+        let header = Self::table_cells(lines[index])?;
+        let separator = Self::table_cells(lines.get(index + 1)?)?;
+        let is_separator = separator.iter().all(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+        });
+        let has_id = header.iter().any(|cell| cell.eq_ignore_ascii_case("id"));
+        (is_separator && has_id).then(|| header.iter().map(|cell| cell.to_string()).collect())
+    }
+
+    /// Split a table row into its trimmed cells
+    fn table_cells(line: &str) -> Option<Vec<&str>> {
+        let row = line.trim().strip_prefix('|')?;
+        let row = row.strip_suffix('|').unwrap_or(row);
+        Some(row.split('|').map(str::trim).collect())
+    }
+
+    /// Parse the rows of a requirement table, leaving `line_number` on its last row
+    fn parse_item_table(
+        &self,
+        lines: &[&str],
+        line_number: &mut usize,
+        file_path: &Path,
+        defaults: &DocumentDefaults,
+    ) -> Result<Vec<SpecificationItem>> {
+        let Some(headers) = self.item_table_columns(lines, *line_number) else {
+            return Ok(Vec::new());
+        };
+        let columns: Vec<_> = headers.iter().map(|header| header.to_lowercase()).collect();
+        let mut items = Vec::new();
+        *line_number += 1;
+
+        while let Some(cells) = lines
+            .get(*line_number + 1)
+            .and_then(|line| Self::table_cells(line))
+        {
+            *line_number += 1;
+            let row: Vec<_> = columns.iter().map(String::as_str).zip(cells).collect();
+            let cell = |name: &str| {
+                row.iter()
+                    .find(|(column, _)| *column == name)
+                    .map(|(_, cell)| cell.trim_matches('`').trim())
+                    .filter(|cell| !cell.is_empty())
+            };
+
+            let Some(id) = cell("id").and_then(|id| self.extract_item_reference(id)) else {
+                log::warn!(
+                    "Skipping table row without a valid item ID at {}:{}",
+                    file_path.display(),
+                    *line_number + 1
+                );
+                continue;
+            };
+            let mut builder = self.item_builder(id, file_path, *line_number + 1, defaults);
+
+            if let Some(title) = cell("title") {
+                builder = builder.title(title.to_string());
+            }
+            if let Some(description) = cell("description") {
+                builder = builder.description(description.to_string());
+            }
+            if let Some(rationale) = cell("rationale") {
+                builder = builder.rationale(rationale.to_string());
+            }
+            builder = builder.needs_multiple(match cell("needs") {
+                Some(needs) => self.parse_list(needs),
+                None => defaults.needs.clone(),
+            });
+            if let Some(covers) = cell("covers") {
+                builder = builder.covers_multiple(self.parse_covers_list(covers));
+            }
+            if let Some(depends) = cell("depends") {
+                for dep in self.parse_covers_list(depends) {
+                    builder = builder.depends(dep);
+                }
+            }
+            if let Some(tags) = cell("tags") {
+                builder = builder.tags(self.parse_list(tags));
+            }
+            if let Some(status) = cell("status") {
+                let status =
+                    ItemStatus::parse_with(status, &self.custom_statuses).ok_or_else(|| {
+                        crate::Error::Parse {
+                            message: format!("Unknown status '{}'", status),
+                            location: format!("{}:{}", file_path.display(), *line_number + 1),
+                        }
+                    })?;
+                builder = builder.status(status);
+            }
+            if let Some(module) = cell("module") {
+                builder = builder.module(module.to_string());
+            }
+            // Other columns are custom attributes
+            for (header, column) in headers.iter().zip(&columns) {
+                if column != "id" && !KNOWN_FIELDS.contains(&column.as_str()) {
+                    if let Some(value) = cell(column) {
+                        builder = builder.attribute(header.clone(), value.to_string());
+                    }
+                }
+            }
+
+            items.push(builder.build());
+        }
+
+        Ok(items)
+    }
+
     /// Append text to the appropriate section
     fn append_to_section(
         &self,
@@ -343,29 +802,170 @@ impl MarkdownImporter {
         }
     }
 
+    /// Read the YAML front matter block at the top of a document, if any
+    ///
+    /// Returns the document defaults and the content with the front matter
+    /// blanked out (keeping line numbers) and IDs without artifact type
+    /// completed with the front matter's `artifact_type`.
+    fn split_front_matter<'a>(
+        &self,
+        content: &'a str,
+        file_path: &Path,
+    ) -> Result<(DocumentDefaults, Cow<'a, str>)> {
+        let mut lines = content.lines();
+        if lines.next().map(str::trim_end) != Some("---") {
+            return Ok((DocumentDefaults::default(), Cow::Borrowed(content)));
+        }
+        let Some(end) = lines.position(|line| matches!(line.trim_end(), "---" | "...")) else {
+            return Ok((DocumentDefaults::default(), Cow::Borrowed(content)));
+        };
+
+        let invalid = |message: String| crate::Error::Parse {
+            message,
+            location: format!("{}:1", file_path.display()),
+        };
+        let yaml = content
+            .lines()
+            .skip(1)
+            .take(end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        // A document may also start with a `---` thematic break; only a mapping is front matter
+        let front_matter: FrontMatter = match serde_yaml::from_str(&yaml) {
+            Ok(serde_yaml::Value::Null) => FrontMatter::default(),
+            Ok(value @ serde_yaml::Value::Mapping(_)) => serde_yaml::from_value(value)
+                .map_err(|e| invalid(format!("Invalid front matter: {}", e)))?,
+            _ => {
+                log::warn!(
+                    "{} starts with '---' but no YAML front matter mapping, reading it as text",
+                    file_path.display()
+                );
+                return Ok((DocumentDefaults::default(), Cow::Borrowed(content)));
+            }
+        };
+
+        let status = front_matter
+            .status
+            .map(|status| {
+                ItemStatus::parse_with(&status, &self.custom_statuses)
+                    .ok_or_else(|| invalid(format!("Unknown status '{}'", status)))
+            })
+            .transpose()?;
+        let defaults = DocumentDefaults {
+            status,
+            tags: front_matter.tags,
+            needs: front_matter.needs,
+        };
+
+        // Keep the line count so item locations stay valid
+        let mut body = "\n".repeat(end + 2);
+        body.push_str(&content.lines().skip(end + 2).collect::<Vec<_>>().join("\n"));
+        match front_matter.artifact_type {
+            Some(artifact_type) if artifact_type.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let replacement = format!("`{}~${{1}}~${{2}}`", artifact_type);
+                let body = self.short_id_regex.replace_all(&body, replacement.as_str());
+                Ok((defaults, Cow::Owned(body.into_owned())))
+            }
+            Some(artifact_type) => Err(invalid(format!(
+                "Invalid artifact type '{}' in front matter",
+                artifact_type
+            ))),
+            None => Ok((defaults, Cow::Owned(body))),
+        }
+    }
+
+    /// Flag the lines of fenced code blocks, fences included
+    fn code_block_lines(&self, lines: &[&str]) -> Vec<bool> {
+        let mut in_code_block = vec![false; lines.len()];
+        if !self.skip_code_blocks {
+            return in_code_block;
+        }
+
+        // Fence character and length of the open block
+        let mut open_fence: Option<(char, usize)> = None;
+        for (index, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            let fence = ['`', '~'].into_iter().find_map(|c| {
+                let len = trimmed.chars().take_while(|&t| t == c).count();
+                (len >= 3).then_some((c, len))
+            });
+            match (open_fence, fence) {
+                (None, Some(fence)) => open_fence = Some(fence),
+                // A closing fence has no info string and is at least as long as the opening one
+                (Some((c, len)), Some((fence_c, fence_len)))
+                    if c == fence_c
+                        && fence_len >= len
+                        && trimmed[fence_len..].trim().is_empty() =>
+                {
+                    in_code_block[index] = true;
+                    open_fence = None;
+                    continue;
+                }
+                _ => {}
+            }
+            in_code_block[index] = open_fence.is_some();
+        }
+        in_code_block
+    }
+
+    /// Key and value of a `Key: value` line whose key is a declared custom attribute
+    fn custom_attribute(&self, line: &str) -> Option<(String, String)> {
+        let captures = self.attribute_regex.captures(line)?;
+        let key = self
+            .custom_attributes
+            .iter()
+            .find(|key| key.eq_ignore_ascii_case(&captures[1]))?;
+        Some((key.clone(), captures[2].trim().to_string()))
+    }
+
+    /// Consume the indented lines continuing a wrapped list field
+    ///
+    /// Returns the lines as further comma-separated list entries, so that
+    /// `Covers: req~a~1,` followed by an indented `req~b~1` yields both IDs.
+    fn continuation(
+        &self,
+        lines: &[&str],
+        in_code_block: &[bool],
+        line_number: &mut usize,
+    ) -> String {
+        let mut text = String::new();
+        while let Some(&line) = lines.get(*line_number + 1) {
+            if in_code_block[*line_number + 1] || !self.is_continuation(line) {
+                break;
+            }
+            text.push(',');
+            text.push_str(line.trim());
+            *line_number += 1;
+        }
+        text
+    }
+
+    /// Check if a line is an indented, non-empty line that is not a bullet point
+    fn is_continuation(&self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        line.starts_with([' ', '\t'])
+            && !trimmed.is_empty()
+            && !trimmed.starts_with(['-', '*', '+'])
+    }
+
     /// Check if a line is a heading
     fn is_heading(&self, line: &str) -> bool {
         line.trim_start().starts_with('#')
     }
 
+    /// Number of `#` characters of a heading line
+    fn heading_level(&self, line: &str) -> usize {
+        line.trim_start().chars().take_while(|&c| c == '#').count()
+    }
+
     /// Extract text from a heading line
     fn extract_heading_text(&self, line: &str) -> String {
         line.trim_start().trim_start_matches('#').trim().to_string()
     }
 
     /// Check if a line is a structured field that should not be added to description
-    fn is_structured_field(&self, line: &str) -> bool {
-        self.title_field_regex.is_match(line)
-            || self.description_field_regex.is_match(line)
-            || self.rationale_field_regex.is_match(line)
-            || self.needs_regex.is_match(line)
-            || self.covers_inline_regex.is_match(line)
-            || self.covers_regex.is_match(line)
-            || self.depends_regex.is_match(line)
-            || self.tags_regex.is_match(line)
-            || self.status_regex.is_match(line)
-            || self.rationale_regex.is_match(line)
-            || self.comment_regex.is_match(line)
+    fn is_structured_field(matches: &SetMatches) -> bool {
+        (NEEDS..=COMMENT).any(|index| matches.matched(index))
     }
 
     /// Parse a comma-separated list of covers
@@ -424,6 +1024,60 @@ impl MarkdownImporter {
     }
 }
 
+/// Column names with a meaning of their own, never read as custom attributes
+const KNOWN_FIELDS: &[&str] = &[
+    "needs",
+    "covers",
+    "depends",
+    "tags",
+    "status",
+    "module",
+    "rationale",
+    "comment",
+    "title",
+    "description",
+];
+
+// Indices of the patterns in `MarkdownImporter::line_patterns`. Fields are
+// checked in this order, so the first match decides what a line is.
+const ID: usize = 0;
+const FORWARDING: usize = 1;
+const END_MARKER: usize = 2;
+const NEEDS: usize = 3;
+const COVERS_INLINE: usize = 4;
+const DEPENDS_INLINE: usize = 5;
+const TITLE_FIELD: usize = 6;
+const DESCRIPTION_FIELD: usize = 7;
+const RATIONALE_FIELD: usize = 8;
+const COVERS: usize = 9;
+const DEPENDS: usize = 10;
+const TAGS: usize = 11;
+const STATUS: usize = 12;
+const MODULE: usize = 13;
+const RATIONALE: usize = 14;
+const COMMENT: usize = 15;
+const ATTRIBUTE: usize = 16;
+
+/// YAML front matter of a spec document
+#[derive(Debug, Default, Deserialize)]
+struct FrontMatter {
+    status: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    needs: Vec<String>,
+    /// Artifact type of the items whose ID is written without one
+    artifact_type: Option<String>,
+}
+
+/// Defaults applied to all items of a document
+#[derive(Debug, Default, Clone)]
+struct DocumentDefaults {
+    status: Option<ItemStatus>,
+    tags: Vec<String>,
+    needs: Vec<String>,
+}
+
 /// Current section being parsed
 #[derive(Debug, Clone, Copy)]
 enum Section {
@@ -475,6 +1129,361 @@ Status: approved
         assert_eq!(item.status, ItemStatus::Approved);
     }
 
+    #[test]
+    fn test_line_pattern_indices() {
+        let importer = MarkdownImporter::new();
+        let patterns = importer.line_patterns.patterns();
+        assert_eq!(patterns[ID], importer.id_regex.as_str());
+        assert_eq!(patterns[FORWARDING], importer.forwarding_regex.as_str());
+        assert_eq!(patterns[NEEDS], importer.needs_regex.as_str());
+        assert_eq!(patterns[STATUS], importer.status_regex.as_str());
+        assert_eq!(patterns[MODULE], importer.module_regex.as_str());
+        assert_eq!(patterns[ATTRIBUTE], importer.attribute_regex.as_str());
+        assert_eq!(patterns.len(), ATTRIBUTE + 1);
+    }
+
+    #[test]
+    fn test_fenced_code_blocks_are_skipped() {
+        let content = r#"
+`req~syntax~1`
+
+Items are written like this:
+
+```markdown
+`req~example~1`
+
+Covers: req~other~1
+Needs: impl
+```
+
+Needs: dsn
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].needs, vec!["dsn".to_string()]);
+        assert!(items[0].covers.is_empty());
+        assert!(items[0]
+            .description
+            .as_ref()
+            .unwrap()
+            .contains("`req~example~1`"));
+
+        let items = MarkdownImporter::new()
+            .skip_code_blocks(false)
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn test_front_matter_defaults() {
+        let content = r#"---
+title: Login requirements
+status: draft
+tags: [security]
+needs: [dsn, impl]
+artifact_type: req
+---
+# Login
+
+`login~1`
+
+Users shall log in.
+
+`req~logout~1`
+
+Tags: session
+Needs: impl
+Status: approved
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("login.md"))
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id.to_string(), "req~login~1");
+        assert_eq!(items[0].location.as_ref().unwrap().line, 10);
+        assert_eq!(items[0].status, ItemStatus::Draft);
+        assert_eq!(items[0].tags, vec!["security".to_string()]);
+        assert_eq!(items[0].needs, vec!["dsn".to_string(), "impl".to_string()]);
+        assert_eq!(items[1].status, ItemStatus::Approved);
+        assert_eq!(items[1].tags, vec!["security".to_string(), "session".to_string()]);
+        assert_eq!(items[1].needs, vec!["impl".to_string()]);
+    }
+
+    #[test]
+    fn test_thematic_break_is_not_front_matter() {
+        let content = "---\n`req~login~1`\n\nUsers shall log in.\n\n---\n\n`req~logout~1`\n";
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("login.md"))
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].location.as_ref().unwrap().line, 2);
+
+        let content = "---\nstatus: [draft]\n---\n`req~login~1`\n";
+        assert!(MarkdownImporter::new()
+            .parse_markdown(content, Path::new("login.md"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_table_requirements() {
+        let content = r#"`feat~auth~1`
+
+Authentication feature.
+
+| ID | Title | Description | Needs | Covers |
+|----|:------|-------------|-------|--------|
+| `req~login~1` | Login | Users shall log in. | dsn, impl | feat~auth~1 |
+| req~logout~2 | Logout | | | feat~auth~1, feat~session~1 |
+| not an id | Broken | | | |
+
+Text after the table.
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item.id.to_string()).collect();
+        assert_eq!(ids, vec!["feat~auth~1", "req~login~1", "req~logout~2"]);
+        assert_eq!(
+            items[0].description.as_deref(),
+            Some("Authentication feature.")
+        );
+
+        let login = &items[1];
+        assert_eq!(login.title.as_deref(), Some("Login"));
+        assert_eq!(login.description.as_deref(), Some("Users shall log in."));
+        assert_eq!(login.needs, vec!["dsn".to_string(), "impl".to_string()]);
+        assert_eq!(login.covers[0].to_string(), "feat~auth~1");
+        assert_eq!(login.location.as_ref().unwrap().line, 7);
+
+        let logout = &items[2];
+        assert!(logout.description.is_none());
+        assert!(logout.needs.is_empty());
+        assert_eq!(logout.covers.len(), 2);
+    }
+
+    #[test]
+    fn test_forwarding() {
+        let content = r#"`req~web-ui~1`
+
+The web UI shall use the corporate design.
+
+Needs: arch
+
+arch-->dsn, impl : req~web-ui~1
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].needs, vec!["arch".to_string()]);
+
+        let forwarded = &items[1];
+        assert_eq!(forwarded.id.to_string(), "arch~web-ui~1");
+        assert_eq!(forwarded.covers[0].to_string(), "req~web-ui~1");
+        assert_eq!(forwarded.needs, vec!["dsn".to_string(), "impl".to_string()]);
+        assert_eq!(forwarded.location.as_ref().unwrap().line, 7);
+    }
+
+    #[test]
+    fn test_inline_depends() {
+        let content = r#"`req~export~1`
+
+Depends: req~login~1, dsn~storage~2
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        let depends: Vec<_> = items[0].depends.iter().map(|id| id.to_string()).collect();
+        assert_eq!(depends, vec!["req~login~1", "dsn~storage~2"]);
+        assert!(items[0].description.is_none());
+    }
+
+    #[test]
+    fn test_description_is_kept_verbatim() {
+        let content = r#"`req~export~1`
+
+Exports support:
+
+- CSV
+- JSON
+
+  with pretty printing.
+<!-- end -->
+
+This paragraph is not part of the requirement.
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(
+            items[0].description.as_deref(),
+            Some("Exports support:\n\n- CSV\n- JSON\n\n  with pretty printing.")
+        );
+    }
+
+    #[test]
+    fn test_wrapped_lists() {
+        let content = r#"`dsn~export~1`
+
+Needs: impl,
+  utest
+Covers: req~export~1, req~csv~1,
+    req~json~2
+Depends:
+    req~storage~1, req~auth~1
+
+The exporter writes files.
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items[0].needs, vec!["impl", "utest"]);
+        let covers: Vec<_> = items[0].covers.iter().map(|id| id.to_string()).collect();
+        assert_eq!(covers, vec!["req~export~1", "req~csv~1", "req~json~2"]);
+        assert_eq!(items[0].depends.len(), 2);
+        assert_eq!(
+            items[0].description.as_deref(),
+            Some("The exporter writes files.")
+        );
+    }
+
+    #[test]
+    fn test_section_needs() {
+        let content = r#"# Authentication
+
+Needs: dsn, impl
+
+## Login
+`req~login~1`
+
+Users log in.
+
+### Details
+
+Passwords are hashed.
+
+`req~logout~1`
+
+Needs: impl
+
+# Reporting
+
+`req~report~1`
+"#;
+
+        let items = MarkdownImporter::new()
+            .inherit_section_needs(true)
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].needs, vec!["dsn", "impl"]);
+        assert!(items[0].description.as_ref().unwrap().contains("hashed"));
+        assert_eq!(items[1].needs, vec!["impl"]);
+        assert!(items[2].needs.is_empty());
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert!(items[0].needs.is_empty());
+    }
+
+    #[test]
+    fn test_custom_statuses() {
+        let content = r#"`req~print~1`
+
+Status: Obsolete
+
+`req~export~1`
+
+Status: retired
+"#;
+
+        let items = MarkdownImporter::new()
+            .with_custom_statuses(vec!["obsolete".to_string()])
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items[0].status, ItemStatus::Custom("obsolete".to_string()));
+        assert_eq!(items[1].status, ItemStatus::Approved);
+    }
+
+    #[test]
+    fn test_custom_attributes() {
+        let content = r#"`req~brake~1`
+
+ASIL: D
+**Verification-Method:** review
+Needs: dsn
+
+| ID            | Needs | ASIL |
+|---------------|-------|------|
+| `req~horn~1`  | dsn   | A    |
+"#;
+
+        let items = MarkdownImporter::new()
+            .with_custom_attributes(vec!["ASIL".to_string(), "Verification-Method".to_string()])
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        assert_eq!(items[0].metadata.get("ASIL").map(String::as_str), Some("D"));
+        assert_eq!(
+            items[0]
+                .metadata
+                .get("Verification-Method")
+                .map(String::as_str),
+            Some("review")
+        );
+        assert!(!items[0].metadata.contains_key("Needs"));
+        assert_eq!(items[1].metadata.get("ASIL").map(String::as_str), Some("A"));
+    }
+
+    #[test]
+    fn test_prose_with_colon_stays_in_description() {
+        let content = "`req~cleanup~1`
+
+Note: files older than 30 days are deleted.
+Example: a file from January is deleted in March.
+ASIL: B
+";
+
+        let items = MarkdownImporter::new()
+            .with_custom_attributes(vec!["ASIL".to_string()])
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        let description = items[0].description.as_deref().unwrap();
+        assert!(description.contains("Note: files older than 30 days are deleted."));
+        assert!(description.contains("Example: a file from January is deleted in March."));
+        assert!(!description.contains("ASIL"));
+        assert_eq!(items[0].metadata.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_module() {
+        let importer = MarkdownImporter::new();
+        let content = r#"
+`req~login~1`
+
+Module: authentication
+
+`req~report~1`
+"#;
+
+        let items = importer
+            .parse_markdown(content, Path::new("docs/system.md"))
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].module, Some("authentication".to_string()));
+        assert_eq!(items[1].module, Some("system".to_string()));
+    }
+
     #[test]
     fn test_parse_requirement_with_covers() {
         let importer = MarkdownImporter::new();
@@ -500,6 +1509,29 @@ Needs: impl, utest
         assert_eq!(item.covers[1].name, "password-validation");
     }
 
+    #[test]
+    fn test_links_point_to_covers_lines() {
+        let content = r#"# Login `dsn~login~1`
+
+Users log in with their password.
+
+Covers: req~login~1
+
+### `req~login~1`
+
+Needs: dsn
+"#;
+
+        let items = MarkdownImporter::new()
+            .parse_markdown(content, Path::new("spec.md"))
+            .unwrap();
+        let linked = crate::core::Linker::new().link_items(items).unwrap();
+        let location = |link: &crate::core::Link| link.location.as_ref().unwrap().to_string();
+        assert_eq!(location(&linked[0].outgoing_links[0]), "spec.md:5");
+        assert_eq!(location(&linked[1].incoming_links[0]), "spec.md:5");
+        assert_eq!(linked[0].item.location.as_ref().unwrap().line, 1);
+    }
+
     #[test]
     fn test_parse_requirement_with_rationale() {
         let importer = MarkdownImporter::new();
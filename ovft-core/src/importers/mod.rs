@@ -1,5 +1,45 @@
+pub mod artifact_importer;
+pub mod code_coverage_importer;
+mod code_symbols;
+mod comment_scanner;
+pub mod commit_importer;
+pub mod csv_importer;
+pub(crate) mod encoding;
+pub mod file_walker;
+pub mod gitlab_importer;
+pub mod import_cache;
+pub mod input_format;
 pub mod markdown_importer;
+pub mod oft_json_importer;
+pub mod org_importer;
+pub mod path_patterns;
+pub mod pdf_importer;
+pub mod remote_source;
+pub mod reqif_importer;
+pub mod rust_attribute_importer;
+pub mod rustdoc_json_importer;
+mod table_mapper;
 pub mod tag_importer;
+pub mod xlsx_importer;
+pub mod yaml_importer;
 
+pub use artifact_importer::ArtifactImporter;
+pub use code_coverage_importer::CodeCoverageImporter;
+pub use commit_importer::CommitImporter;
+pub use csv_importer::CsvImporter;
+pub use file_walker::FileWalker;
+pub use gitlab_importer::GitLabImporter;
+pub use import_cache::ImportCache;
+pub use input_format::InputFormat;
 pub use markdown_importer::MarkdownImporter;
-pub use tag_importer::TagImporter;
+pub use oft_json_importer::OftJsonImporter;
+pub use org_importer::OrgImporter;
+pub use path_patterns::PathPatterns;
+pub use pdf_importer::PdfImporter;
+pub use remote_source::RemoteSource;
+pub use reqif_importer::ReqIfImporter;
+pub use rust_attribute_importer::RustAttributeImporter;
+pub use rustdoc_json_importer::RustdocJsonImporter;
+pub use tag_importer::{TagFormat, TagImporter};
+pub use xlsx_importer::XlsxImporter;
+pub use yaml_importer::YamlImporter;
@@ -0,0 +1,203 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Importer for specobject exports of the Java OpenFastTrace tool in JSON form
+///
+/// The structure mirrors OpenFastTrace's `specobjects` export: specobjects are
+/// grouped by `doctype`, coverage is listed under `providescoverage` with
+/// `linksto` in `type:name` form plus a `dstversion`, and needed artifact types
+/// under `needsobj`.
+pub struct OftJsonImporter;
+
+#[derive(Debug, Deserialize)]
+struct OftExport {
+    specobjects: Vec<OftSpecObjects>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OftSpecObjects {
+    doctype: String,
+    #[serde(default)]
+    specobject: Vec<OftSpecObject>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OftSpecObject {
+    id: String,
+    version: u32,
+    status: Option<String>,
+    shortdesc: Option<String>,
+    description: Option<String>,
+    rationale: Option<String>,
+    comment: Option<String>,
+    sourcefile: Option<PathBuf>,
+    sourceline: Option<u32>,
+    #[serde(default)]
+    tags: OftTags,
+    #[serde(default)]
+    needsobj: OftNeeds,
+    #[serde(default)]
+    providescoverage: OftProvidesCoverage,
+    #[serde(default)]
+    dependencies: OftDependencies,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OftTags {
+    #[serde(default)]
+    tag: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OftNeeds {
+    #[serde(default)]
+    needscov: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OftProvidesCoverage {
+    #[serde(default)]
+    provcov: Vec<OftCoverage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OftCoverage {
+    linksto: String,
+    dstversion: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OftDependencies {
+    #[serde(default)]
+    dependson: Vec<String>,
+}
+
+impl OftJsonImporter {
+    /// Create a new OpenFastTrace JSON importer
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Import specification items from an exported JSON file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = fs::read_to_string(file_path)?;
+        self.parse_json(&content, file_path)
+    }
+
+    fn parse_json(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let export: OftExport = serde_json::from_str(content)?;
+        let mut items = Vec::new();
+
+        for group in export.specobjects {
+            for object in group.specobject {
+                items.push(Self::build_item(&group.doctype, object, file_path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn build_item(
+        doctype: &str,
+        object: OftSpecObject,
+        file_path: &Path,
+    ) -> Result<SpecificationItem> {
+        let id = SpecificationItemId::new(doctype.to_string(), object.id, object.version);
+        let mut item = SpecificationItem::new(id);
+
+        item.title = object.shortdesc;
+        item.description = object.description;
+        item.rationale = object.rationale;
+        item.comment = object.comment;
+        item.tags = object.tags.tag;
+        item.needs = object.needsobj.needscov;
+        item.status = object
+            .status
+            .as_deref()
+            .and_then(ItemStatus::parse)
+            .unwrap_or_default();
+        item.covers = object
+            .providescoverage
+            .provcov
+            .iter()
+            .map(|coverage| Self::parse_link(&coverage.linksto, coverage.dstversion))
+            .collect::<Result<_>>()?;
+        item.depends = object
+            .dependencies
+            .dependson
+            .iter()
+            .map(|id| SpecificationItemId::parse(id))
+            .collect::<Result<_>>()?;
+        // Keep the original source location, fall back to the export file
+        item.location = Some(match object.sourcefile {
+            Some(path) => Location::new(path, object.sourceline.unwrap_or(1)),
+            None => Location::new(file_path.to_path_buf(), 1),
+        });
+
+        Ok(item)
+    }
+
+    /// Parse a `linksto` target like "dsn:login-form" (or a full "dsn~login-form~2")
+    fn parse_link(linksto: &str, version: u32) -> Result<SpecificationItemId> {
+        match linksto.split_once(':') {
+            Some((artifact_type, name)) => Ok(SpecificationItemId::new(
+                artifact_type.to_string(),
+                name.to_string(),
+                version,
+            )),
+            None => SpecificationItemId::parse(linksto),
+        }
+    }
+}
+
+impl Default for OftJsonImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_oft_json() {
+        let content = r#"{
+  "specobjects": [
+    {
+      "doctype": "impl",
+      "specobject": [
+        {
+          "id": "login-handler",
+          "version": 1,
+          "status": "approved",
+          "shortdesc": "Login handler",
+          "sourcefile": "src/main/java/Login.java",
+          "sourceline": 42,
+          "needsobj": { "needscov": ["utest"] },
+          "providescoverage": {
+            "provcov": [{ "linksto": "dsn:login-form", "dstversion": 2 }]
+          }
+        }
+      ]
+    }
+  ]
+}"#;
+        let items = OftJsonImporter::new()
+            .parse_json(content, Path::new("export.json"))
+            .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id.to_string(), "impl~login-handler~1");
+        assert_eq!(items[0].needs, vec!["utest"]);
+        assert_eq!(items[0].covers[0].to_string(), "dsn~login-form~2");
+        assert_eq!(
+            items[0].location.as_ref().unwrap().to_string(),
+            "src/main/java/Login.java:42"
+        );
+    }
+}
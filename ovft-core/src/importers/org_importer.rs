@@ -0,0 +1,286 @@
+use regex::Regex;
+use std::path::Path;
+
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Importer for specification items in Emacs org-mode files
+///
+/// Every heading containing an item ID starts an item. The text below the
+/// heading becomes the description, and a property drawer carries the links:
+///
+/// ```org
+/// * req~login~1 Login                                        :security:
+///   :PROPERTIES:
+///   :NEEDS:  dsn impl
+///   :COVERS: feat~authentication~1
+///   :STATUS: approved
+///   :END:
+///   Users shall log in with their password.
+/// ```
+pub struct OrgImporter {
+    heading_regex: Regex,
+    id_regex: Regex,
+    property_regex: Regex,
+    heading_tags_regex: Regex,
+    walker: FileWalker,
+}
+
+impl OrgImporter {
+    /// Create a new org-mode importer
+    pub fn new() -> Self {
+        Self {
+            heading_regex: Regex::new(r"^\*+\s+(.*)$").unwrap(),
+            id_regex: Regex::new(r"[A-Za-z][\w-]*~[\w.-]+~\d+").unwrap(),
+            property_regex: Regex::new(r"^:([A-Za-z_-]+):\s*(.*)$").unwrap(),
+            heading_tags_regex: Regex::new(r"\s+(:[\w@#%:]+:)\s*$").unwrap(),
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import specification items from all org files in a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_org_file(path) && self.walker.is_text_file(path) {
+                items.extend(self.import_from_file(path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single org file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = read_text_file(file_path)?;
+        self.parse_org(&content, file_path)
+    }
+
+    /// Import specification items from org-mode content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.parse_org(content, origin)
+    }
+
+    /// Check if a file is an org-mode file
+    pub fn is_org_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("org"))
+    }
+
+    fn parse_org(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+        let mut current: Option<(SpecificationItem, Vec<String>)> = None;
+        let mut in_drawer = false;
+
+        for (index, line) in content.lines().enumerate() {
+            let location = Location::new(file_path.to_path_buf(), index as u32 + 1);
+
+            if let Some(captures) = self.heading_regex.captures(line) {
+                if let Some((item, body)) = current.take() {
+                    items.push(Self::finish_item(item, body));
+                }
+                in_drawer = false;
+                current = self
+                    .start_item(&captures[1], &location, file_path)?
+                    .map(|item| (item, Vec::new()));
+                continue;
+            }
+
+            let Some((item, body)) = current.as_mut() else {
+                continue;
+            };
+            let trimmed = line.trim();
+
+            if trimmed.eq_ignore_ascii_case(":PROPERTIES:") {
+                in_drawer = true;
+            } else if in_drawer {
+                if trimmed.eq_ignore_ascii_case(":END:") {
+                    in_drawer = false;
+                } else if let Some(captures) = self.property_regex.captures(trimmed) {
+                    Self::apply_property(item, &captures[1], &captures[2], &location)?;
+                }
+            } else {
+                body.push(trimmed.to_string());
+            }
+        }
+
+        if let Some((item, body)) = current {
+            items.push(Self::finish_item(item, body));
+        }
+
+        Ok(items)
+    }
+
+    /// Create an item from a heading, or `None` if the heading has no item ID
+    fn start_item(
+        &self,
+        heading: &str,
+        location: &Location,
+        file_path: &Path,
+    ) -> Result<Option<SpecificationItem>> {
+        let mut heading = heading.to_string();
+        let mut tags = Vec::new();
+        if let Some(captures) = self.heading_tags_regex.captures(&heading) {
+            tags = captures[1]
+                .split(':')
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect();
+            heading.truncate(captures.get(0).unwrap().start());
+        }
+
+        let Some(id_match) = self.id_regex.find(&heading) else {
+            return Ok(None);
+        };
+        let id = Self::parse_id(id_match.as_str(), location)?;
+
+        // Drop the ID together with any verbatim markup around it
+        let title = format!(
+            "{}{}",
+            heading[..id_match.start()].trim_end_matches(['=', '~']),
+            heading[id_match.end()..].trim_start_matches(['=', '~'])
+        );
+        let title = title.trim().trim_start_matches([':', '-']).trim();
+
+        let mut item = SpecificationItem::new(id);
+        if !title.is_empty() {
+            item.title = Some(title.to_string());
+        }
+        item.tags = tags;
+        item.module = file_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string());
+        item.location = Some(location.clone());
+
+        Ok(Some(item))
+    }
+
+    fn apply_property(
+        item: &mut SpecificationItem,
+        name: &str,
+        value: &str,
+        location: &Location,
+    ) -> Result<()> {
+        let values = || {
+            value
+                .split([' ', ',', '\t'])
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        match name.to_ascii_uppercase().as_str() {
+            "NEEDS" => item.needs.extend(values()),
+            "TAGS" => item.tags.extend(values()),
+            "COVERS" => {
+                for id in values() {
+                    item.covers.push(Self::parse_id(&id, location)?);
+                }
+            }
+            "DEPENDS" => {
+                for id in values() {
+                    item.depends.push(Self::parse_id(&id, location)?);
+                }
+            }
+            "STATUS" => {
+                item.status =
+                    ItemStatus::parse(value.trim()).ok_or_else(|| crate::Error::Parse {
+                        message: format!("Unknown status '{}'", value.trim()),
+                        location: location.to_string(),
+                    })?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn finish_item(mut item: SpecificationItem, body: Vec<String>) -> SpecificationItem {
+        let description = body.join("\n").trim().to_string();
+        if !description.is_empty() {
+            item.description = Some(description);
+        }
+        item
+    }
+
+    fn parse_id(id: &str, location: &Location) -> Result<SpecificationItemId> {
+        SpecificationItemId::parse(id).map_err(|e| crate::Error::Parse {
+            message: e.to_string(),
+            location: location.to_string(),
+        })
+    }
+}
+
+impl Default for OrgImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_org_headings_and_properties() {
+        let content = "\
+#+TITLE: Authentication
+
+* Overview
+Introductory text.
+* req~login~1 Login                                   :security:
+  :PROPERTIES:
+  :NEEDS:  dsn impl
+  :COVERS: feat~authentication~1
+  :STATUS: draft
+  :END:
+  Users shall log in
+  with their password.
+** =dsn~password-check~2= Password check
+   :PROPERTIES:
+   :COVERS: req~login~1
+   :END:
+";
+        let items = OrgImporter::new()
+            .parse_org(content, Path::new("auth.org"))
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        let login = &items[0];
+        assert_eq!(login.id.to_string(), "req~login~1");
+        assert_eq!(login.title.as_deref(), Some("Login"));
+        assert_eq!(login.tags, vec!["security"]);
+        assert_eq!(login.needs, vec!["dsn", "impl"]);
+        assert_eq!(login.covers[0].to_string(), "feat~authentication~1");
+        assert_eq!(login.status, ItemStatus::Draft);
+        assert_eq!(
+            login.description.as_deref(),
+            Some("Users shall log in\nwith their password.")
+        );
+        assert_eq!(login.location.as_ref().unwrap().line, 5);
+        assert_eq!(login.module.as_deref(), Some("auth"));
+
+        let check = &items[1];
+        assert_eq!(check.id.to_string(), "dsn~password-check~2");
+        assert_eq!(check.title.as_deref(), Some("Password check"));
+        assert_eq!(check.covers[0].to_string(), "req~login~1");
+    }
+}
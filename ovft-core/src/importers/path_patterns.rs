@@ -0,0 +1,97 @@
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::Path;
+
+/// Include and exclude glob patterns selecting the files to scan
+///
+/// Patterns are matched against paths relative to the scanned directory, with
+/// `/` as separator on all platforms and ignoring case. `*` also matches `/`,
+/// so `*.rs` selects Rust files at any depth, while `target/**` only excludes
+/// the `target` directory at the root. Invalid patterns are logged and ignored.
+#[derive(Debug, Clone)]
+pub struct PathPatterns {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl PathPatterns {
+    /// Select the files matching one of `patterns` and none of `exclude_patterns`
+    pub fn new(patterns: &[String], exclude_patterns: &[String]) -> Self {
+        Self {
+            include: Self::glob_set(patterns),
+            exclude: Self::glob_set(exclude_patterns),
+        }
+    }
+
+    /// Check if a path relative to the scanned directory is selected
+    pub fn matches(&self, path: &Path) -> bool {
+        let path = path.to_string_lossy().replace('\\', "/");
+        let path = path.trim_start_matches("./");
+        self.include.is_match(path) && !self.exclude.is_match(path)
+    }
+
+    /// Check if a file below `dir` is selected
+    pub fn matches_in(&self, dir: &Path, path: &Path) -> bool {
+        self.matches(path.strip_prefix(dir).unwrap_or(path))
+    }
+
+    fn glob_set(patterns: &[String]) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            match Self::glob(pattern) {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(e) => log::error!("Invalid file pattern '{}': {}", pattern, e),
+            }
+        }
+        builder.build().unwrap_or_else(|e| {
+            log::error!("Invalid file patterns: {}", e);
+            GlobSet::empty()
+        })
+    }
+
+    fn glob(pattern: &str) -> Result<Glob, globset::Error> {
+        GlobBuilder::new(&pattern.replace('\\', "/"))
+            .case_insensitive(true)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(include: &[&str], exclude: &[&str]) -> PathPatterns {
+        let strings =
+            |patterns: &[&str]| patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        PathPatterns::new(&strings(include), &strings(exclude))
+    }
+
+    #[test]
+    fn test_pattern_semantics() {
+        let patterns = patterns(
+            &["*.rs", "scripts/**/*.py"],
+            &["target/**", "**/generated/**"],
+        );
+
+        assert!(patterns.matches(Path::new("main.rs")));
+        assert!(patterns.matches(Path::new("core/model/item.rs")));
+        assert!(patterns.matches(Path::new("./lib.rs")));
+        assert!(patterns.matches(Path::new("scripts/tools/check.py")));
+        assert!(!patterns.matches(Path::new("tools/check.py")));
+        assert!(!patterns.matches(Path::new("target/debug/build.rs")));
+        assert!(patterns.matches(Path::new("core/target/lib.rs")));
+        assert!(!patterns.matches(Path::new("api/generated/client.rs")));
+    }
+
+    #[test]
+    fn test_case_and_separators() {
+        let patterns = patterns(&["*.rs"], &["target/**"]);
+
+        assert!(patterns.matches(Path::new("SRC/LIB.RS")));
+        assert!(patterns.matches(Path::new(r"core\model\item.rs")));
+        assert!(!patterns.matches(Path::new(r"target\debug\build.rs")));
+        assert!(!patterns.matches(Path::new("Target/debug/build.rs")));
+        assert!(!patterns.matches_in(Path::new("/repo/src"), Path::new("/repo/src/target/lib.rs")));
+    }
+}
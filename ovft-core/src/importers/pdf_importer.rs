@@ -0,0 +1,223 @@
+use regex::Regex;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use super::file_walker::FileWalker;
+use crate::core::{Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Best-effort importer for requirements in PDF documents
+///
+/// The text of each page is extracted and every line starting with an item ID
+/// starts a new item; the rest of that line becomes the title and following
+/// lines the description. `Needs:` and `Covers:` lines add links. Since PDF
+/// text has no reliable line numbers, item locations point at the page.
+pub struct PdfImporter {
+    id_line_regex: Regex,
+    needs_regex: Regex,
+    covers_regex: Regex,
+    item_ref_regex: Regex,
+    walker: FileWalker,
+}
+
+impl PdfImporter {
+    /// Create a new PDF importer
+    pub fn new() -> Self {
+        Self {
+            id_line_regex: Regex::new(r"^\s*([a-zA-Z]+~[a-zA-Z0-9._-]+~\d+)\b[\s:.-]*(.*)$")
+                .unwrap(),
+            needs_regex: Regex::new(r"^\s*Needs:\s*(.+)$").unwrap(),
+            covers_regex: Regex::new(r"^\s*Covers:\s*(.*)$").unwrap(),
+            item_ref_regex: Regex::new(r"[a-zA-Z]+~[a-zA-Z0-9._-]+~\d+").unwrap(),
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import specification items from all PDF files in a directory
+    ///
+    /// Files whose text cannot be extracted are skipped with a warning.
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_pdf_file(path) {
+                match Self::extract_pages(path) {
+                    Some(pages) => items.extend(self.parse_pages(&pages, path)?),
+                    None => log::warn!("Skipping {}: could not extract text", path.display()),
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single PDF file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let pages = Self::extract_pages(file_path).ok_or_else(|| crate::Error::Parse {
+            message: "could not extract text".to_string(),
+            location: file_path.display().to_string(),
+        })?;
+        self.parse_pages(&pages, file_path)
+    }
+
+    /// Check if a file is a PDF document
+    pub fn is_pdf_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+    }
+
+    /// Extract the text of every page
+    fn extract_pages(path: &Path) -> Option<Vec<String>> {
+        // The text extraction panics on some malformed documents
+        let path = path.to_path_buf();
+        match panic::catch_unwind(move || pdf_extract::extract_text_by_pages(path)) {
+            Ok(Ok(pages)) => Some(pages),
+            Ok(Err(e)) => {
+                log::debug!("PDF text extraction failed: {}", e);
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    fn parse_pages(&self, pages: &[String], file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+        let mut current: Option<(SpecificationItem, Vec<String>)> = None;
+        // Covers lists ending in a comma are wrapped onto the next line
+        let mut covers_continue = false;
+
+        for (page_index, page) in pages.iter().enumerate() {
+            let location = Location::new(PathBuf::from(file_path), page_index as u32 + 1);
+
+            for line in page.lines() {
+                let wrapped_covers = covers_continue && self.is_id_list(line);
+                covers_continue = false;
+
+                if let Some(captures) = self
+                    .id_line_regex
+                    .captures(line)
+                    .filter(|_| !wrapped_covers)
+                {
+                    if let Some((item, body)) = current.take() {
+                        items.push(Self::finish_item(item, body));
+                    }
+                    let mut item = SpecificationItem::new(Self::parse_id(&captures[1], &location)?);
+                    let title = captures[2].trim();
+                    if !title.is_empty() {
+                        item.title = Some(title.to_string());
+                    }
+                    item.location = Some(location.clone());
+                    current = Some((item, Vec::new()));
+                    continue;
+                }
+
+                let Some((item, body)) = current.as_mut() else {
+                    continue;
+                };
+                let covers_text = if let Some(captures) = self.covers_regex.captures(line) {
+                    Some(captures.get(1).unwrap().as_str())
+                } else if wrapped_covers {
+                    Some(line)
+                } else {
+                    None
+                };
+
+                if let Some(covers_text) = covers_text {
+                    covers_continue = covers_text.trim_end().ends_with(',');
+                    for id in self.item_ref_regex.find_iter(covers_text) {
+                        item.covers.push(Self::parse_id(id.as_str(), &location)?);
+                    }
+                } else if let Some(captures) = self.needs_regex.captures(line) {
+                    item.needs.extend(
+                        captures[1]
+                            .split(',')
+                            .map(|needed| needed.trim().to_string())
+                            .filter(|needed| !needed.is_empty()),
+                    );
+                } else if !line.trim().is_empty() {
+                    body.push(line.trim().to_string());
+                }
+            }
+        }
+
+        if let Some((item, body)) = current {
+            items.push(Self::finish_item(item, body));
+        }
+
+        Ok(items)
+    }
+
+    /// Check if a line holds nothing but item IDs and separators
+    fn is_id_list(&self, line: &str) -> bool {
+        self.item_ref_regex.is_match(line)
+            && self
+                .item_ref_regex
+                .replace_all(line, "")
+                .chars()
+                .all(|c| c.is_whitespace() || c == ',' || c == ';')
+    }
+
+    fn finish_item(mut item: SpecificationItem, body: Vec<String>) -> SpecificationItem {
+        if !body.is_empty() {
+            item.description = Some(body.join("\n"));
+        }
+        item
+    }
+
+    fn parse_id(id: &str, location: &Location) -> Result<SpecificationItemId> {
+        SpecificationItemId::parse(id).map_err(|e| crate::Error::Parse {
+            message: e.to_string(),
+            location: location.to_string(),
+        })
+    }
+}
+
+impl Default for PdfImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_extracted_pages() {
+        let pages = vec![
+            "Supplier Requirements\n\nreq~brake-response~1 Brake response\nThe brake shall respond\nwithin 10 ms.\nNeeds: dsn, impl\n".to_string(),
+            "req~brake-light~2: Brake light\nCovers: feat~braking~1,\nfeat~safety~1\n".to_string(),
+        ];
+        let items = PdfImporter::new()
+            .parse_pages(&pages, Path::new("supplier.pdf"))
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id.to_string(), "req~brake-response~1");
+        assert_eq!(items[0].title.as_deref(), Some("Brake response"));
+        assert_eq!(
+            items[0].description.as_deref(),
+            Some("The brake shall respond\nwithin 10 ms.")
+        );
+        assert_eq!(items[0].needs, vec!["dsn", "impl"]);
+
+        assert_eq!(items[1].title.as_deref(), Some("Brake light"));
+        assert_eq!(items[1].covers.len(), 2);
+        assert_eq!(items[1].description, None);
+        assert_eq!(
+            items[1].location.as_ref().unwrap().to_string(),
+            "supplier.pdf:2"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Fetches specification documents published at `http(s)://` URLs
+///
+/// Each document is stored in the cache directory under a name derived from
+/// its URL, next to the ETag the server sent for it. Later runs send the ETag
+/// so an unchanged document is not downloaded again. If the server cannot be
+/// reached, or in offline mode, the cached copy is used.
+#[derive(Debug, Clone)]
+pub struct RemoteSource {
+    cache_dir: PathBuf,
+    offline: bool,
+}
+
+impl RemoteSource {
+    /// Create a remote source storing downloaded documents in `cache_dir`
+    pub fn new<P: Into<PathBuf>>(cache_dir: P) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            offline: false,
+        }
+    }
+
+    /// Only use cached copies instead of contacting the server
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Check if a configured spec directory is a URL rather than a local path
+    pub fn is_remote(path: &Path) -> bool {
+        path.to_str()
+            .is_some_and(|path| path.starts_with("https://") || path.starts_with("http://"))
+    }
+
+    /// Return the local path of an up-to-date copy of the document at `url`
+    pub fn fetch(&self, url: &str) -> Result<PathBuf> {
+        let path = self.cache_path(url);
+        if self.offline {
+            return if path.exists() {
+                Ok(path)
+            } else {
+                Err(crate::Error::Http(format!(
+                    "{} is not cached and offline mode is enabled",
+                    url
+                )))
+            };
+        }
+
+        let etag_path = path.with_extension("etag");
+        let mut request = ureq::get(url);
+        if path.exists() {
+            if let Ok(etag) = fs::read_to_string(&etag_path) {
+                request = request.set("If-None-Match", etag.trim());
+            }
+        }
+
+        match request.call() {
+            Ok(response) if response.status() == 304 => Ok(path),
+            Ok(response) => {
+                let etag = response.header("ETag").map(str::to_string);
+                let mut content = Vec::new();
+                response.into_reader().read_to_end(&mut content)?;
+
+                fs::create_dir_all(&self.cache_dir)?;
+                fs::write(&path, content)?;
+                match etag {
+                    Some(etag) => fs::write(&etag_path, etag)?,
+                    None if etag_path.exists() => fs::remove_file(&etag_path)?,
+                    None => {}
+                }
+                Ok(path)
+            }
+            Err(e) if path.exists() => {
+                log::warn!("Could not fetch {} ({}), using cached copy", url, e);
+                Ok(path)
+            }
+            Err(e) => Err(crate::Error::Http(format!("GET {} failed: {}", url, e))),
+        }
+    }
+
+    /// Cache file of a URL; keeps the document's extension so the matching importer is used
+    fn cache_path(&self, url: &str) -> PathBuf {
+        let hash = format!("{:x}", Sha256::digest(url.as_bytes()));
+        let document = url.split(['?', '#']).next().unwrap_or(url);
+        let file_name = match document
+            .rsplit('/')
+            .next()
+            .and_then(|name| Path::new(name).extension())
+            .and_then(|ext| ext.to_str())
+        {
+            Some(ext) => format!("{}.{}", &hash[..16], ext),
+            None => format!("{}.md", &hash[..16]),
+        };
+        self.cache_dir.join(file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_offline_uses_cached_copy() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = RemoteSource::new(temp_dir.path()).offline(true);
+        let url = "https://example.com/specs/system.reqif?ref=main";
+
+        assert!(source.fetch(url).is_err());
+
+        let cached = source.cache_path(url);
+        assert_eq!(cached.extension().unwrap(), "reqif");
+        fs::write(&cached, "<REQ-IF/>").unwrap();
+        assert_eq!(source.fetch(url).unwrap(), cached);
+    }
+
+    #[test]
+    fn test_is_remote() {
+        assert!(RemoteSource::is_remote(Path::new(
+            "https://example.com/spec.md"
+        )));
+        assert!(!RemoteSource::is_remote(Path::new("docs/https")));
+    }
+}
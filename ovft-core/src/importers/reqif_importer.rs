@@ -0,0 +1,406 @@
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::file_walker::FileWalker;
+use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Attribute names (ReqIF `LONG-NAME`s) holding the item ID
+const ID_ATTRIBUTES: &[&str] = &["ReqIF.ForeignID", "ID"];
+/// Attribute names holding the item title
+const TITLE_ATTRIBUTES: &[&str] = &["ReqIF.Name", "ReqIF.ChapterName", "Title"];
+/// Attribute names holding the item description
+const DESCRIPTION_ATTRIBUTES: &[&str] = &["ReqIF.Text", "Description"];
+
+/// Importer for ReqIF exchange files as exported by DOORS, Polarion and others
+///
+/// Each `SPEC-OBJECT` becomes an item and each `SPEC-RELATION` makes its source
+/// cover its target. Item IDs are taken from the `ReqIF.ForeignID` or `ID`
+/// attribute when it holds an ID like `req~login~1`; otherwise the ReqIF
+/// identifier is used with the importer's default artifact type.
+pub struct ReqIfImporter {
+    /// Artifact type for objects without an item ID attribute
+    default_artifact_type: String,
+    /// Lists the files to scan
+    walker: FileWalker,
+}
+
+impl ReqIfImporter {
+    /// Create a new ReqIF importer creating `req` items by default
+    pub fn new() -> Self {
+        Self {
+            default_artifact_type: "req".to_string(),
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Set the artifact type used for objects without an item ID attribute
+    pub fn default_artifact_type<S: Into<String>>(mut self, artifact_type: S) -> Self {
+        self.default_artifact_type = artifact_type.into();
+        self
+    }
+
+    /// Import specification items from all ReqIF files in a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_reqif_file(path) && self.walker.is_text_file(path) {
+                items.extend(self.import_from_file(path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single ReqIF file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = fs::read_to_string(file_path)?;
+        self.parse_reqif(&content, file_path)
+    }
+
+    /// Import specification items from ReqIF content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.parse_reqif(content, origin)
+    }
+
+    /// Check if a file is a ReqIF file
+    pub fn is_reqif_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("reqif"))
+    }
+
+    /// Parse ReqIF XML content
+    fn parse_reqif(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let document = Document::parse(content)?;
+
+        // Attribute definitions and enum values are referenced by identifier
+        let attribute_names =
+            Self::long_names(&document, |name| name.starts_with("ATTRIBUTE-DEFINITION-"));
+        let enum_values = Self::long_names(&document, |name| name == "ENUM-VALUE");
+
+        let mut items = Vec::new();
+        let mut ids_by_identifier = HashMap::new();
+
+        for object in document
+            .descendants()
+            .filter(|node| node.has_tag_name("SPEC-OBJECT"))
+        {
+            let Some(identifier) = object.attribute("IDENTIFIER") else {
+                continue;
+            };
+            let attributes = Self::attribute_values(object, &attribute_names, &enum_values);
+            let item = self.build_item(identifier, &attributes, &document, object, file_path);
+
+            ids_by_identifier.insert(identifier.to_string(), item.id.clone());
+            items.push(item);
+        }
+
+        // Relations: the source object covers the target object
+        for relation in document
+            .descendants()
+            .filter(|node| node.has_tag_name("SPEC-RELATION"))
+        {
+            let source = Self::reference(relation, "SOURCE");
+            let target = Self::reference(relation, "TARGET");
+            let (Some(source), Some(target)) = (source, target) else {
+                continue;
+            };
+            let Some(target_id) = ids_by_identifier.get(&target) else {
+                log::warn!("ReqIF relation targets unknown object {}", target);
+                continue;
+            };
+            let Some(source_id) = ids_by_identifier.get(&source) else {
+                log::warn!("ReqIF relation starts at unknown object {}", source);
+                continue;
+            };
+            if let Some(item) = items.iter_mut().find(|item| &item.id == source_id) {
+                item.covers.push(target_id.clone());
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn build_item(
+        &self,
+        identifier: &str,
+        attributes: &HashMap<String, String>,
+        document: &Document,
+        object: Node,
+        file_path: &Path,
+    ) -> SpecificationItem {
+        let lookup = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| attributes.get(*name))
+                .filter(|value| !value.is_empty())
+                .cloned()
+        };
+
+        let id = lookup(ID_ATTRIBUTES)
+            .and_then(|id| SpecificationItemId::parse(&id).ok())
+            .unwrap_or_else(|| {
+                let name: String = identifier
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                            c
+                        } else {
+                            '-'
+                        }
+                    })
+                    .collect();
+                let name = name.trim_matches('-').to_string();
+                SpecificationItemId::new(self.default_artifact_type.clone(), name, 1)
+            });
+
+        let line = document.text_pos_at(object.range().start).row;
+        let mut builder = SpecificationItem::builder(id)
+            .location(Location::new(file_path.to_path_buf(), line))
+            .module(
+                file_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            );
+
+        if let Some(title) =
+            lookup(TITLE_ATTRIBUTES).or_else(|| object.attribute("LONG-NAME").map(str::to_string))
+        {
+            builder = builder.title(title);
+        }
+        if let Some(description) = lookup(DESCRIPTION_ATTRIBUTES) {
+            builder = builder.description(description);
+        }
+        if let Some(rationale) = lookup(&["Rationale"]) {
+            builder = builder.rationale(rationale);
+        }
+        if let Some(needs) = lookup(&["Needs"]) {
+            builder = builder.needs_multiple(Self::split_list(&needs));
+        }
+        if let Some(tags) = lookup(&["Tags"]) {
+            builder = builder.tags(Self::split_list(&tags));
+        }
+        if let Some(status) =
+            lookup(&["Status", "ReqIF.Status"]).and_then(|s| ItemStatus::parse(&s))
+        {
+            builder = builder.status(status);
+        }
+
+        builder.build()
+    }
+
+    /// Map the `IDENTIFIER` of all matching elements to their `LONG-NAME`
+    fn long_names<F: Fn(&str) -> bool>(document: &Document, matches: F) -> HashMap<String, String> {
+        document
+            .descendants()
+            .filter(|node| node.is_element() && matches(node.tag_name().name()))
+            .filter_map(|node| {
+                Some((
+                    node.attribute("IDENTIFIER")?.to_string(),
+                    node.attribute("LONG-NAME")?.to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Collect the attribute values of a spec object by attribute name
+    fn attribute_values(
+        object: Node,
+        attribute_names: &HashMap<String, String>,
+        enum_values: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let mut values = HashMap::new();
+        let Some(container) = object.children().find(|node| node.has_tag_name("VALUES")) else {
+            return values;
+        };
+
+        for value in container.children().filter(|node| node.is_element()) {
+            let Some(definition) = value
+                .children()
+                .find(|node| node.has_tag_name("DEFINITION"))
+                .and_then(|definition| definition.children().find(|node| node.is_element()))
+                .and_then(|reference| reference.text())
+            else {
+                continue;
+            };
+            let Some(name) = attribute_names.get(definition.trim()) else {
+                continue;
+            };
+
+            let text = match value.tag_name().name() {
+                "ATTRIBUTE-VALUE-XHTML" => value
+                    .children()
+                    .find(|node| node.has_tag_name("THE-VALUE"))
+                    .map(Self::text_content),
+                "ATTRIBUTE-VALUE-ENUMERATION" => Some(
+                    value
+                        .descendants()
+                        .filter(|node| node.has_tag_name("ENUM-VALUE-REF"))
+                        .filter_map(|node| enum_values.get(node.text()?.trim()).cloned())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                _ => value.attribute("THE-VALUE").map(str::to_string),
+            };
+
+            if let Some(text) = text {
+                values.insert(name.clone(), text);
+            }
+        }
+
+        values
+    }
+
+    /// Read the referenced object identifier of a relation end
+    fn reference(relation: Node, end: &str) -> Option<String> {
+        relation
+            .children()
+            .find(|node| node.has_tag_name(end))?
+            .children()
+            .find(|node| node.has_tag_name("SPEC-OBJECT-REF"))?
+            .text()
+            .map(|text| text.trim().to_string())
+    }
+
+    /// Concatenate the text of XHTML content, separating block elements by newlines
+    fn text_content(node: Node) -> String {
+        let mut blocks = Vec::new();
+        let mut current = String::new();
+
+        for descendant in node.descendants() {
+            if descendant.is_text() {
+                current.push_str(descendant.text().unwrap_or_default());
+            } else if matches!(descendant.tag_name().name(), "p" | "div" | "br" | "li")
+                && !current.trim().is_empty()
+            {
+                blocks.push(current.trim().to_string());
+                current.clear();
+            }
+        }
+        if !current.trim().is_empty() {
+            blocks.push(current.trim().to_string());
+        }
+
+        blocks.join("\n")
+    }
+
+    fn split_list(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(|part| part.trim().to_string())
+            .filter(|part| !part.is_empty())
+            .collect()
+    }
+}
+
+impl Default for ReqIfImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQIF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<REQ-IF xmlns="http://www.omg.org/spec/ReqIF/20110401/reqif.xsd" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+  <CORE-CONTENT>
+    <REQ-IF-CONTENT>
+      <DATATYPES>
+        <DATATYPE-DEFINITION-ENUMERATION IDENTIFIER="dt-status" LONG-NAME="Status">
+          <SPECIFIED-VALUES>
+            <ENUM-VALUE IDENTIFIER="ev-draft" LONG-NAME="Draft"/>
+          </SPECIFIED-VALUES>
+        </DATATYPE-DEFINITION-ENUMERATION>
+      </DATATYPES>
+      <SPEC-TYPES>
+        <SPEC-OBJECT-TYPE IDENTIFIER="sot" LONG-NAME="Requirement">
+          <SPEC-ATTRIBUTES>
+            <ATTRIBUTE-DEFINITION-STRING IDENTIFIER="ad-id" LONG-NAME="ReqIF.ForeignID"/>
+            <ATTRIBUTE-DEFINITION-STRING IDENTIFIER="ad-name" LONG-NAME="ReqIF.Name"/>
+            <ATTRIBUTE-DEFINITION-XHTML IDENTIFIER="ad-text" LONG-NAME="ReqIF.Text"/>
+            <ATTRIBUTE-DEFINITION-STRING IDENTIFIER="ad-needs" LONG-NAME="Needs"/>
+            <ATTRIBUTE-DEFINITION-ENUMERATION IDENTIFIER="ad-status" LONG-NAME="Status"/>
+          </SPEC-ATTRIBUTES>
+        </SPEC-OBJECT-TYPE>
+      </SPEC-TYPES>
+      <SPEC-OBJECTS>
+        <SPEC-OBJECT IDENTIFIER="_obj-1">
+          <VALUES>
+            <ATTRIBUTE-VALUE-STRING THE-VALUE="req~login~1">
+              <DEFINITION><ATTRIBUTE-DEFINITION-STRING-REF>ad-id</ATTRIBUTE-DEFINITION-STRING-REF></DEFINITION>
+            </ATTRIBUTE-VALUE-STRING>
+            <ATTRIBUTE-VALUE-STRING THE-VALUE="Login">
+              <DEFINITION><ATTRIBUTE-DEFINITION-STRING-REF>ad-name</ATTRIBUTE-DEFINITION-STRING-REF></DEFINITION>
+            </ATTRIBUTE-VALUE-STRING>
+            <ATTRIBUTE-VALUE-XHTML>
+              <DEFINITION><ATTRIBUTE-DEFINITION-XHTML-REF>ad-text</ATTRIBUTE-DEFINITION-XHTML-REF></DEFINITION>
+              <THE-VALUE><xhtml:div><xhtml:p>Users shall <xhtml:b>log in</xhtml:b>.</xhtml:p></xhtml:div></THE-VALUE>
+            </ATTRIBUTE-VALUE-XHTML>
+            <ATTRIBUTE-VALUE-STRING THE-VALUE="dsn, impl">
+              <DEFINITION><ATTRIBUTE-DEFINITION-STRING-REF>ad-needs</ATTRIBUTE-DEFINITION-STRING-REF></DEFINITION>
+            </ATTRIBUTE-VALUE-STRING>
+            <ATTRIBUTE-VALUE-ENUMERATION>
+              <DEFINITION><ATTRIBUTE-DEFINITION-ENUMERATION-REF>ad-status</ATTRIBUTE-DEFINITION-ENUMERATION-REF></DEFINITION>
+              <VALUES><ENUM-VALUE-REF>ev-draft</ENUM-VALUE-REF></VALUES>
+            </ATTRIBUTE-VALUE-ENUMERATION>
+          </VALUES>
+        </SPEC-OBJECT>
+        <SPEC-OBJECT IDENTIFIER="_obj-2" LONG-NAME="Password check">
+          <VALUES/>
+        </SPEC-OBJECT>
+      </SPEC-OBJECTS>
+      <SPEC-RELATIONS>
+        <SPEC-RELATION IDENTIFIER="rel-1">
+          <SOURCE><SPEC-OBJECT-REF>_obj-2</SPEC-OBJECT-REF></SOURCE>
+          <TARGET><SPEC-OBJECT-REF>_obj-1</SPEC-OBJECT-REF></TARGET>
+        </SPEC-RELATION>
+      </SPEC-RELATIONS>
+    </REQ-IF-CONTENT>
+  </CORE-CONTENT>
+</REQ-IF>
+"#;
+
+    #[test]
+    fn test_parse_reqif() {
+        let importer = ReqIfImporter::new().default_artifact_type("dsn");
+        let items = importer
+            .parse_reqif(REQIF, Path::new("export.reqif"))
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        let login = &items[0];
+        assert_eq!(login.id.to_string(), "req~login~1");
+        assert_eq!(login.title.as_deref(), Some("Login"));
+        assert_eq!(login.description.as_deref(), Some("Users shall log in."));
+        assert_eq!(login.needs, vec!["dsn", "impl"]);
+        assert_eq!(login.status, ItemStatus::Draft);
+        assert_eq!(login.module.as_deref(), Some("export"));
+
+        let check = &items[1];
+        assert_eq!(check.id.to_string(), "dsn~obj-2~1");
+        assert_eq!(check.title.as_deref(), Some("Password check"));
+        assert_eq!(check.covers, vec![login.id.clone()]);
+    }
+}
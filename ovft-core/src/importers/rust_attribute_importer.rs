@@ -0,0 +1,329 @@
+use std::path::Path;
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Ident, LitStr, Token};
+
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use crate::core::{Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Importer for coverage attributes on Rust items
+///
+/// Recognizes `#[ovft::covers("req~x~1")]` and `#[ovft::implements("dsn~y~2")]`
+/// on functions, methods, structs, enums and traits. Each annotated item yields
+/// one item named after its path, e.g. `impl~auth.Session.login~0` for a method
+/// `login` of `Session` in `src/auth.rs`. `implements` always produces `impl`
+/// items; `covers` produces `utest` items on test functions and `impl` items
+/// elsewhere. Unlike comment tags these survive rustfmt and refactorings that
+/// move code around within a file.
+pub struct RustAttributeImporter {
+    walker: FileWalker,
+}
+
+/// Coverage attributes collected for one annotated item
+struct AnnotatedItem {
+    artifact_type: &'static str,
+    name: String,
+    covers: Vec<SpecificationItemId>,
+    line: u32,
+}
+
+/// Collects the annotated items of one file
+struct AttributeVisitor<'a> {
+    file_path: &'a Path,
+    /// Module, impl and trait names enclosing the visited item
+    path: Vec<String>,
+    /// Annotated items in file order
+    items: Vec<AnnotatedItem>,
+    error: Option<crate::Error>,
+}
+
+impl RustAttributeImporter {
+    /// Create a new attribute importer
+    pub fn new() -> Self {
+        Self {
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import items from all Rust files in a directory; module paths are relative to `dir`
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_rust_file(path) && self.walker.is_text_file(path) {
+                let module = Self::module_path(path.strip_prefix(dir).unwrap_or(path));
+                items.extend(self.import_file(path, module)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import items from a single Rust file, treating it as a crate root
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        self.import_file(file_path, Vec::new())
+    }
+
+    /// Check if a file is a Rust source file
+    pub fn is_rust_file(path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "rs")
+    }
+
+    fn import_file(&self, file_path: &Path, module: Vec<String>) -> Result<Vec<SpecificationItem>> {
+        let content = read_text_file(file_path)?;
+        // Files that do not parse (e.g. templates or newer syntax) are skipped
+        let file = match syn::parse_file(&content) {
+            Ok(file) => file,
+            Err(e) => {
+                log::warn!("Skipping {}: {}", file_path.display(), e);
+                return Ok(Vec::new());
+            }
+        };
+
+        let mut visitor = AttributeVisitor {
+            file_path,
+            path: module,
+            items: Vec::new(),
+            error: None,
+        };
+        visitor.visit_file(&file);
+        if let Some(e) = visitor.error {
+            return Err(e);
+        }
+
+        Ok(visitor
+            .items
+            .into_iter()
+            .map(|annotated| {
+                let id = SpecificationItemId::new(
+                    annotated.artifact_type.to_string(),
+                    annotated.name,
+                    0,
+                );
+                SpecificationItem::builder(id)
+                    .covers_multiple(annotated.covers)
+                    .location(Location::new(file_path.to_path_buf(), annotated.line))
+                    .build()
+            })
+            .collect())
+    }
+
+    /// Module path of a file relative to the crate root, e.g. `auth/mod.rs` -> `auth`
+    fn module_path(relative: &Path) -> Vec<String> {
+        let mut module: Vec<String> = relative
+            .with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect();
+        if module
+            .last()
+            .is_some_and(|last| matches!(last.as_str(), "lib" | "main" | "mod"))
+        {
+            module.pop();
+        }
+        module
+    }
+}
+
+impl Default for RustAttributeImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttributeVisitor<'_> {
+    /// Record the coverage attributes of an item named `ident`
+    fn annotate(&mut self, attrs: &[Attribute], ident: &Ident) {
+        let is_test = attrs.iter().any(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "test")
+        });
+        let line = ident.span().start().line as u32;
+        let name = self
+            .path
+            .iter()
+            .cloned()
+            .chain(std::iter::once(ident.to_string()))
+            .collect::<Vec<_>>()
+            .join(".");
+
+        for attr in attrs {
+            let segments: Vec<_> = attr
+                .path()
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+            let artifact_type = match segments.as_slice() {
+                [ovft, kind] if ovft == "ovft" && kind == "implements" => "impl",
+                [ovft, kind] if ovft == "ovft" && kind == "covers" && is_test => "utest",
+                [ovft, kind] if ovft == "ovft" && kind == "covers" => "impl",
+                _ => continue,
+            };
+
+            match self.covered_ids(attr, line) {
+                Ok(covers) => {
+                    // Several attributes of the same kind add to one item
+                    match self.items.iter_mut().find(|annotated| {
+                        annotated.artifact_type == artifact_type && annotated.name == name
+                    }) {
+                        Some(annotated) => annotated.covers.extend(covers),
+                        None => self.items.push(AnnotatedItem {
+                            artifact_type,
+                            name: name.clone(),
+                            covers,
+                            line,
+                        }),
+                    }
+                }
+                Err(e) => {
+                    self.error.get_or_insert(e);
+                }
+            }
+        }
+    }
+
+    /// Parse the item IDs listed in an attribute, e.g. `("req~a~1", "req~b~2")`
+    fn covered_ids(&self, attr: &Attribute, line: u32) -> Result<Vec<SpecificationItemId>> {
+        let location = Location::new(self.file_path.to_path_buf(), line);
+        let ids = attr
+            .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+            .map_err(|e| crate::Error::Parse {
+                message: format!("Invalid coverage attribute: {}", e),
+                location: location.to_string(),
+            })?;
+        ids.iter()
+            .map(|id| SpecificationItemId::parse(&id.value()))
+            .collect()
+    }
+
+    fn nested<F: FnOnce(&mut Self)>(&mut self, name: String, visit: F) {
+        self.path.push(name);
+        visit(self);
+        self.path.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for AttributeVisitor<'_> {
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        self.nested(item.ident.to_string(), |visitor| {
+            visit::visit_item_mod(visitor, item)
+        });
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.annotate(&item.attrs, &item.sig.ident);
+    }
+
+    fn visit_item_struct(&mut self, item: &'ast syn::ItemStruct) {
+        self.annotate(&item.attrs, &item.ident);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
+        self.annotate(&item.attrs, &item.ident);
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast syn::ItemTrait) {
+        self.annotate(&item.attrs, &item.ident);
+        self.nested(item.ident.to_string(), |visitor| {
+            visit::visit_item_trait(visitor, item)
+        });
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast syn::TraitItemFn) {
+        self.annotate(&item.attrs, &item.sig.ident);
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        let self_type = match item.self_ty.as_ref() {
+            syn::Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string()),
+            _ => None,
+        };
+        match self_type {
+            Some(self_type) => {
+                self.nested(self_type, |visitor| visit::visit_item_impl(visitor, item))
+            }
+            None => visit::visit_item_impl(self, item),
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.annotate(&item.attrs, &item.sig.ident);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_attributes_on_items() {
+        let temp_dir = TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        fs::create_dir_all(src.join("auth")).unwrap();
+        fs::write(
+            src.join("auth/mod.rs"),
+            r#"
+pub struct Session;
+
+impl Session {
+    #[ovft::implements("dsn~session~1")]
+    #[ovft::covers("req~login~1", "req~logout~1")]
+    pub fn login(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ovft::covers("req~login~1")]
+    fn login_works() {}
+}
+"#,
+        )
+        .unwrap();
+
+        let items = RustAttributeImporter::new()
+            .import_from_directory(&src)
+            .unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item.id.to_string()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "impl~auth.Session.login~0",
+                "utest~auth.tests.login_works~0"
+            ]
+        );
+        assert_eq!(items[0].covers.len(), 3);
+        assert_eq!(items[0].location.as_ref().unwrap().line, 7);
+        assert_eq!(items[1].covers[0].to_string(), "req~login~1");
+    }
+
+    #[test]
+    fn test_module_path() {
+        assert_eq!(
+            RustAttributeImporter::module_path(Path::new("auth/session.rs")),
+            vec!["auth", "session"]
+        );
+        assert!(RustAttributeImporter::module_path(Path::new("lib.rs")).is_empty());
+    }
+}
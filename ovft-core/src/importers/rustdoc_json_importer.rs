@@ -0,0 +1,211 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::tag_importer::TagImporter;
+use crate::core::{Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Importer for the JSON output of `cargo doc --output-format json`
+///
+/// Coverage tags in the documentation of public items are read like tags in
+/// source comments. Tags without an explicit name are named after the fully
+/// qualified path of the documented item, e.g. `impl~shop.cart.Cart.add~0`,
+/// so the names stay stable when code moves within or between files. Several
+/// such tags of one artifact type on the same item produce a single item.
+pub struct RustdocJsonImporter {
+    tag_importer: TagImporter,
+}
+
+impl RustdocJsonImporter {
+    /// Create a new rustdoc JSON importer
+    pub fn new() -> Self {
+        Self {
+            tag_importer: TagImporter::new(),
+        }
+    }
+
+    /// Import the tagged public items of a rustdoc JSON file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = fs::read_to_string(file_path)?;
+        let document: Value = serde_json::from_str(&content)?;
+        self.parse_rustdoc(&document)
+    }
+
+    fn parse_rustdoc(&self, document: &Value) -> Result<Vec<SpecificationItem>> {
+        let empty = serde_json::Map::new();
+        let index = document["index"].as_object().unwrap_or(&empty);
+        let paths = document["paths"].as_object().unwrap_or(&empty);
+        let impl_owners = Self::impl_owners(index);
+
+        let mut ids: Vec<_> = index.keys().collect();
+        ids.sort_by_key(|id| id.parse::<u64>().unwrap_or(u64::MAX));
+
+        let mut items = Vec::new();
+        for id in ids {
+            let item = &index[id.as_str()];
+            let Some(docs) = item["docs"].as_str() else {
+                continue;
+            };
+            if item["visibility"].as_str() != Some("public") {
+                continue;
+            }
+            let Some(qualified_name) = Self::qualified_name(id, item, paths, &impl_owners) else {
+                continue;
+            };
+
+            let file = PathBuf::from(item["span"]["filename"].as_str().unwrap_or_default());
+            let line = item["span"]["begin"][0].as_u64().unwrap_or(1) as u32;
+            let tagged = self.tag_importer.import_from_content(docs, &file)?;
+            items.extend(Self::name_items(tagged, &qualified_name, &file, line));
+        }
+
+        Ok(items)
+    }
+
+    /// Give tags without an explicit name the item path and merge them per artifact type
+    fn name_items(
+        tagged: Vec<SpecificationItem>,
+        qualified_name: &str,
+        file: &Path,
+        line: u32,
+    ) -> Vec<SpecificationItem> {
+        let mut items: Vec<SpecificationItem> = Vec::new();
+
+        for mut item in tagged {
+            item.location = Some(Location::new(file.to_path_buf(), line));
+            if item.id.revision != 0 {
+                items.push(item);
+                continue;
+            }
+
+            match items.iter_mut().find(|named| {
+                named.id.revision == 0 && named.id.artifact_type == item.id.artifact_type
+            }) {
+                Some(named) => named.covers.extend(item.covers),
+                None => {
+                    item.id = SpecificationItemId::new(
+                        item.id.artifact_type.clone(),
+                        qualified_name.to_string(),
+                        0,
+                    );
+                    items.push(item);
+                }
+            }
+        }
+
+        items
+    }
+
+    /// Fully qualified path of an item joined with dots
+    ///
+    /// Top-level items are listed in `paths`; methods are found through the
+    /// impl block that contains them.
+    fn qualified_name(
+        id: &str,
+        item: &Value,
+        paths: &serde_json::Map<String, Value>,
+        impl_owners: &HashMap<String, String>,
+    ) -> Option<String> {
+        let path_of = |id: &str| {
+            paths
+                .get(id)
+                .and_then(|summary| summary["path"].as_array())
+                .map(|path| {
+                    path.iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+        };
+
+        if let Some(path) = path_of(id) {
+            return Some(path);
+        }
+        let name = item["name"].as_str()?;
+        let owner = impl_owners.get(id).and_then(|owner| path_of(owner))?;
+        Some(format!("{}.{}", owner, name))
+    }
+
+    /// Map the ID of every impl member to the ID of the implementing type
+    fn impl_owners(index: &serde_json::Map<String, Value>) -> HashMap<String, String> {
+        let mut owners = HashMap::new();
+
+        for item in index.values() {
+            let impl_block = &item["inner"]["impl"];
+            let Some(owner) = Self::id_string(&impl_block["for"]["resolved_path"]["id"]) else {
+                continue;
+            };
+            for member in impl_block["items"].as_array().into_iter().flatten() {
+                if let Some(member) = Self::id_string(member) {
+                    owners.insert(member, owner.clone());
+                }
+            }
+        }
+
+        owners
+    }
+
+    /// Item IDs are numbers in current rustdoc versions and strings in older ones
+    fn id_string(id: &Value) -> Option<String> {
+        match id {
+            Value::Number(number) => Some(number.to_string()),
+            Value::String(string) => Some(string.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for RustdocJsonImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_named_after_item_path() {
+        let document = serde_json::json!({
+            "root": 0,
+            "index": {
+                "1": {
+                    "name": "Cart", "visibility": "public",
+                    "docs": "Shopping cart [impl->dsn~cart~1]",
+                    "span": {"filename": "src/cart.rs", "begin": [3, 0], "end": [5, 1]},
+                    "inner": {"struct": {}}
+                },
+                "2": {
+                    "name": null, "visibility": "default", "docs": null,
+                    "inner": {"impl": {"for": {"resolved_path": {"id": 1}}, "items": [3, 4]}}
+                },
+                "3": {
+                    "name": "add", "visibility": "public",
+                    "docs": "Add an article\n\n[impl->req~add~1]\n[impl->req~limit~2]",
+                    "span": {"filename": "src/cart.rs", "begin": [9, 4], "end": [12, 5]},
+                    "inner": {"function": {}}
+                },
+                "4": {
+                    "name": "clear", "visibility": "crate",
+                    "docs": "[impl->req~clear~1]",
+                    "inner": {"function": {}}
+                }
+            },
+            "paths": {
+                "1": {"crate_id": 0, "path": ["shop", "cart", "Cart"], "kind": "struct"}
+            }
+        });
+
+        let items = RustdocJsonImporter::new().parse_rustdoc(&document).unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item.id.to_string()).collect();
+        assert_eq!(
+            ids,
+            vec!["impl~shop.cart.Cart~0", "impl~shop.cart.Cart.add~0"]
+        );
+        assert_eq!(items[1].covers.len(), 2);
+        assert_eq!(items[1].location.as_ref().unwrap().line, 9);
+    }
+}
@@ -0,0 +1,112 @@
+use regex::Regex;
+
+use crate::config::ColumnMapping;
+use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Maps table rows (CSV records, spreadsheet rows) to specification items
+pub(crate) struct TableMapper {
+    id: usize,
+    title: Option<usize>,
+    description: Option<usize>,
+    rationale: Option<usize>,
+    comment: Option<usize>,
+    covers: Option<usize>,
+    depends: Option<usize>,
+    needs: Option<usize>,
+    tags: Option<usize>,
+    status: Option<usize>,
+    module: Option<usize>,
+    item_ref_regex: Regex,
+}
+
+impl TableMapper {
+    /// Resolve the column mapping against a header row
+    ///
+    /// Returns `None` if the table has no ID column.
+    pub(crate) fn new(mapping: &ColumnMapping, headers: &[String]) -> Option<Self> {
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name.trim()))
+        };
+
+        Some(Self {
+            id: column(&mapping.id)?,
+            title: column(&mapping.title),
+            description: column(&mapping.description),
+            rationale: column(&mapping.rationale),
+            comment: column(&mapping.comment),
+            covers: column(&mapping.covers),
+            depends: column(&mapping.depends),
+            needs: column(&mapping.needs),
+            tags: column(&mapping.tags),
+            status: column(&mapping.status),
+            module: column(&mapping.module),
+            item_ref_regex: Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap(),
+        })
+    }
+
+    /// Build an item from a row, skipping rows with an empty ID cell
+    pub(crate) fn item(
+        &self,
+        row: &[String],
+        location: Location,
+    ) -> Result<Option<SpecificationItem>> {
+        let cell = |index: Option<usize>| {
+            index
+                .and_then(|index| row.get(index))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let Some(id) = cell(Some(self.id)) else {
+            return Ok(None);
+        };
+        let id = SpecificationItemId::parse(id).map_err(|e| crate::Error::Parse {
+            message: e.to_string(),
+            location: location.to_string(),
+        })?;
+
+        let mut item = SpecificationItem::new(id);
+        item.title = cell(self.title).map(str::to_string);
+        item.description = cell(self.description).map(str::to_string);
+        item.rationale = cell(self.rationale).map(str::to_string);
+        item.comment = cell(self.comment).map(str::to_string);
+        item.covers = cell(self.covers)
+            .map(|value| self.item_refs(value))
+            .unwrap_or_default();
+        item.depends = cell(self.depends)
+            .map(|value| self.item_refs(value))
+            .unwrap_or_default();
+        item.needs = cell(self.needs).map(Self::split_list).unwrap_or_default();
+        item.tags = cell(self.tags).map(Self::split_list).unwrap_or_default();
+        if let Some(status) = cell(self.status) {
+            item.status = ItemStatus::parse(status).ok_or_else(|| crate::Error::Parse {
+                message: format!("Unknown status '{}'", status),
+                location: location.to_string(),
+            })?;
+        }
+        item.module = cell(self.module).map(str::to_string);
+        item.location = Some(location);
+
+        Ok(Some(item))
+    }
+
+    /// Extract all item IDs from a cell like "req~a~1, req~b~2"
+    fn item_refs(&self, value: &str) -> Vec<SpecificationItemId> {
+        self.item_ref_regex
+            .find_iter(value)
+            .filter_map(|id| SpecificationItemId::parse(id.as_str()).ok())
+            .collect()
+    }
+
+    /// Split a cell listing values separated by commas, semicolons or newlines
+    fn split_list(value: &str) -> Vec<String> {
+        value
+            .split([',', ';', '\n'])
+            .map(|part| part.trim().to_string())
+            .filter(|part| !part.is_empty())
+            .collect()
+    }
+}
@@ -1,128 +1,409 @@
+use rayon::prelude::*;
 use regex::Regex;
-use std::fs;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
-use walkdir::WalkDir;
-
-use crate::config::Config;
+use std::sync::Arc;
+
+use super::code_symbols::{Language, SymbolFinder, SymbolIndex};
+use super::comment_scanner::{comment_text, strict_text};
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use super::import_cache::ImportCache;
+use super::path_patterns::PathPatterns;
+use crate::config::{CommentStyle, Config};
 use crate::core::{Location, SpecificationItem, SpecificationItemId};
 use crate::Result;
 
 /// Importer for parsing requirement tags from source code files
 /// [impl->dsn~tag-importer-module~1]
+#[derive(Clone)]
 pub struct TagImporter {
     /// Regex for matching full coverage tags like [impl->dsn~validate-authentication-request~1]
+    /// or [impl->req~a~1, req~b~2]
     full_tag_regex: Regex,
+    /// Regex for matching the item IDs in the covered list of a full tag
+    item_ref_regex: Regex,
+    /// Regex for matching the [end] marker closing a block tag
+    end_tag_regex: Regex,
     /// Regex for matching short tags like [[req~name~1:impl]]
     short_tag_regex: Regex,
+    /// User-defined tag formats matched in addition to the built-in ones
+    tag_formats: Vec<TagFormat>,
+    /// Artifact type of items of tag formats without `{type}`
+    default_artifact_type: String,
+    /// Selects the files to scan in directories
+    patterns: PathPatterns,
+    /// Comment styles by lowercase file extension
+    comment_styles: BTreeMap<String, CommentStyle>,
+    /// Only read tags inside comments of files with a known comment style
+    comments_only: bool,
+    /// Ignore tags inside string literals
+    strict: bool,
+    /// Finds the code symbols that name auto-generated items
+    symbol_finder: SymbolFinder,
+    /// Lists the files to scan
+    walker: FileWalker,
+    /// Previously parsed items of unchanged files
+    cache: Option<Arc<ImportCache>>,
+}
+
+/// User-defined tag syntax such as `@covers {id}` or `[{type}->{id}]`
+///
+/// `{id}` stands for the covered item ID and `{type}` for the artifact type of
+/// the tagging item, which is `impl` if the template has no `{type}`, unless
+/// the source directory configures another artifact type. Other
+/// text must appear literally; any whitespace in the template matches any
+/// run of whitespace.
+#[derive(Debug, Clone)]
+pub struct TagFormat {
+    regex: Regex,
+}
+
+impl TagFormat {
+    /// Artifact type of items of templates without `{type}`
+    const DEFAULT_ARTIFACT_TYPE: &'static str = "impl";
+
+    /// Compile a tag format template
+    pub fn parse(template: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            crate::Error::Config(format!("Invalid tag format '{}': {}", template, reason))
+        };
+
+        let mut pattern = String::new();
+        let mut rest = template;
+        let mut has_id = false;
+        let mut has_type = false;
+        while let Some(start) = rest.find('{') {
+            pattern.push_str(&Self::literal(&rest[..start]));
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| invalid("unclosed placeholder"))?
+                + start;
+            match &rest[start + 1..end] {
+                "id" if !has_id => {
+                    pattern.push_str(r"(?P<id>[a-zA-Z]+~[a-zA-Z0-9._-]+~\d+)");
+                    has_id = true;
+                }
+                "type" if !has_type => {
+                    pattern.push_str(r"(?P<type>[a-zA-Z]+)");
+                    has_type = true;
+                }
+                placeholder => {
+                    return Err(invalid(&format!(
+                        "unexpected placeholder {{{}}}",
+                        placeholder
+                    )))
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+        pattern.push_str(&Self::literal(rest));
+
+        if !has_id {
+            return Err(invalid("missing {id} placeholder"));
+        }
+        Ok(Self {
+            regex: Regex::new(&pattern)?,
+        })
+    }
+
+    /// Escape literal template text, letting whitespace match any whitespace
+    fn literal(text: &str) -> String {
+        let mut pattern = String::new();
+        let mut in_whitespace = false;
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+            } else if !in_whitespace {
+                pattern.push_str(r"\s+");
+            }
+            in_whitespace = c.is_whitespace();
+        }
+        pattern
+    }
 }
 
 impl TagImporter {
     /// Create a new tag importer
     pub fn new() -> Self {
+        let defaults = Config::default();
         Self {
-            // Full tag format: [artifact_type->covered_id] or [artifact_type~name~revision->covered_id]
+            // Full tag format: [artifact_type->covered_id] or [artifact_type~name~revision->covered_id],
+            // optionally covering a comma separated list of IDs; `begin` opens a block tag
             full_tag_regex: Regex::new(
-                r"\[\s*([a-zA-Z]+)(?:~([a-zA-Z0-9._-]+)~(\d+))?\s*->\s*([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)\s*(?:>>\s*([a-zA-Z0-9,\s]+))?\s*\]"
+                r"\[\s*([a-zA-Z]+)(?:~([a-zA-Z0-9._-]+)~(\d+))?\s*->\s*([a-zA-Z]+~[a-zA-Z0-9._-]+~\d+(?:\s*,\s*[a-zA-Z]+~[a-zA-Z0-9._-]+~\d+)*)\s*(?:>>\s*([a-zA-Z0-9,\s]+?))?(?:\s+(begin))?\s*\]"
             ).unwrap(),
+            item_ref_regex: Regex::new(r"([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)").unwrap(),
+            end_tag_regex: Regex::new(r"\[\s*end\s*\]").unwrap(),
             // Short tag format: [[item_id:artifact_type]]
             short_tag_regex: Regex::new(
                 r"\[\[\s*([a-zA-Z]+)~([a-zA-Z0-9._-]+)~(\d+)\s*:\s*([a-zA-Z]+)\s*\]\]"
             ).unwrap(),
+            tag_formats: Vec::new(),
+            default_artifact_type: TagFormat::DEFAULT_ARTIFACT_TYPE.to_string(),
+            patterns: PathPatterns::new(&defaults.source_patterns, &defaults.exclude_patterns),
+            comment_styles: CommentStyle::defaults(),
+            comments_only: true,
+            strict: false,
+            symbol_finder: SymbolFinder::new(),
+            walker: FileWalker::new(),
+            cache: None,
         }
     }
 
-    /// Import specification items from a directory
-    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
-        let mut items = Vec::new();
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
 
-        if !dir.exists() {
-            log::warn!("Directory does not exist: {}", dir.display());
-            return Ok(items);
+    /// Also recognize tags in the given user-defined formats
+    pub fn with_tag_formats(mut self, formats: Vec<TagFormat>) -> Self {
+        self.tag_formats.extend(formats);
+        self
+    }
+
+    /// Give items of tag formats without `{type}` this artifact type instead of `impl`
+    pub fn with_default_artifact_type<S: Into<String>>(mut self, artifact_type: S) -> Self {
+        self.default_artifact_type = artifact_type.into();
+        self
+    }
+
+    /// Scan only the files of a directory selected by the patterns
+    pub fn with_patterns(mut self, patterns: PathPatterns) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    /// Add or override comment styles by file extension
+    pub fn with_comment_styles(mut self, styles: &BTreeMap<String, CommentStyle>) -> Self {
+        for (extension, style) in styles {
+            self.comment_styles
+                .insert(extension.to_lowercase(), style.clone());
         }
+        self
+    }
 
-        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
+    /// Only read tags inside comments (the default), or scan the whole file content
+    pub fn comments_only(mut self, comments_only: bool) -> Self {
+        self.comments_only = comments_only;
+        self
+    }
 
-            if path.is_file() && self.should_scan_file(path) {
-                let file_items = self.import_from_file(path)?;
-                items.extend(file_items);
-            }
+    /// Ignore tags inside string literals, e.g. in the fixtures of parser tests
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Reuse items of unchanged files from the given cache
+    pub fn with_cache(mut self, cache: Arc<ImportCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Import specification items from a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        if !dir.exists() {
+            log::warn!("Directory does not exist: {}", dir.display());
+            return Ok(Vec::new());
         }
 
-        Ok(items)
+        // Parse files in parallel; collecting keeps the walker's file order
+        let files: Vec<_> = self
+            .walker
+            .files(dir)
+            .into_iter()
+            .filter(|path| self.patterns.matches_in(dir, path) && self.walker.is_text_file(path))
+            .collect();
+        let file_items = files
+            .par_iter()
+            .map(|path| match &self.cache {
+                Some(cache) => cache.get_or_import("tag", path, |path| self.import_from_file(path)),
+                None => self.import_from_file(path),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(file_items.into_iter().flatten().collect())
     }
 
     /// Import specification items from a single file
     pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
-        let content = fs::read_to_string(file_path)?;
+        let content = read_text_file(file_path)?;
+        let style = self.comment_style(file_path);
+        let scanned = match style {
+            _ if self.strict => Cow::Owned(strict_text(
+                &content,
+                style.unwrap_or(&CommentStyle::default()),
+                self.comments_only && style.is_some(),
+            )),
+            Some(style) if self.comments_only => Cow::Owned(comment_text(&content, style)),
+            _ => Cow::Borrowed(content.as_str()),
+        };
+        self.import_text(&scanned, &content, file_path)
+    }
+
+    /// Comment style of a file, judged by its extension
+    fn comment_style(&self, path: &Path) -> Option<&CommentStyle> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        self.comment_styles.get(&extension)
+    }
+
+    /// Import specification items from source content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.import_text(content, content, origin)
+    }
+
+    /// Import the tags found in `text`, naming generated items after the
+    /// symbols of the `source` code the text was taken from
+    fn import_text(
+        &self,
+        text: &str,
+        source: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        let symbols =
+            Language::from_path(origin).map(|language| self.symbol_finder.index(source, language));
         let mut items = Vec::new();
+        // Indices of the items of block tags that are not closed yet
+        let mut open_blocks = Vec::new();
+        let mut ids = HashSet::new();
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line_number = line_number as u32 + 1;
+            let tags = self.parse_line_tags(line, origin, line_number, symbols.as_ref())?;
+            for (mut item, is_block) in tags {
+                // Two tags in one symbol covering the same item must still differ
+                if !ids.insert(item.id.clone()) && item.id.revision == 0 {
+                    item.id.name = format!("{}-{}", item.id.name, line_number);
+                }
+                if is_block {
+                    open_blocks.push(items.len());
+                }
+                items.push(item);
+            }
+
+            for _ in self.end_tag_regex.find_iter(line) {
+                match open_blocks.pop() {
+                    Some(index) => {
+                        items[index].end_location =
+                            Some(Location::new(origin.to_path_buf(), line_number));
+                    }
+                    None => log::warn!(
+                        "[end] without a matching block tag at {}:{}",
+                        origin.display(),
+                        line_number
+                    ),
+                }
+            }
+        }
 
-        for (line_number, line) in content.lines().enumerate() {
-            let line_items = self.parse_line(line, file_path, line_number as u32 + 1)?;
-            items.extend(line_items);
+        for index in open_blocks {
+            log::warn!(
+                "Block tag of {} is never closed with [end]",
+                items[index].id
+            );
         }
 
         Ok(items)
     }
 
     /// Parse a single line for requirement tags
+    #[cfg(test)]
     fn parse_line(
         &self,
         line: &str,
         file_path: &Path,
         line_number: u32,
     ) -> Result<Vec<SpecificationItem>> {
+        let tags = self.parse_line_tags(line, file_path, line_number, None)?;
+        Ok(tags.into_iter().map(|(item, _)| item).collect())
+    }
+
+    /// Parse a single line for requirement tags, flagging those opening a block
+    fn parse_line_tags(
+        &self,
+        line: &str,
+        file_path: &Path,
+        line_number: u32,
+        symbols: Option<&SymbolIndex>,
+    ) -> Result<Vec<(SpecificationItem, bool)>> {
         let mut items = Vec::new();
         let location = Location::new(file_path.to_path_buf(), line_number);
+        // Generated names use the enclosing symbol, looked up once the line has a tag
+        let symbol = std::cell::OnceCell::new();
+        let generated_name = |covered_name: &str| {
+            let symbol =
+                symbol.get_or_init(|| symbols.and_then(|symbols| symbols.symbol_at(line_number)));
+            self.generated_name(covered_name, &location, symbol.as_deref())
+        };
 
         // Try to match full tag format
         for captures in self.full_tag_regex.captures_iter(line) {
-            if let Some(item) = self.parse_full_tag(&captures, &location)? {
-                items.push(item);
+            if let Some(item) = self.parse_full_tag(&captures, &location, &generated_name)? {
+                items.push((item, captures.get(6).is_some()));
             }
         }
 
         // Try to match short tag format
         for captures in self.short_tag_regex.captures_iter(line) {
-            if let Some(item) = self.parse_short_tag(&captures, &location)? {
-                items.push(item);
+            if let Some(item) = self.parse_short_tag(&captures, &location, &generated_name)? {
+                items.push((item, false));
+            }
+        }
+
+        for format in &self.tag_formats {
+            for captures in format.regex.captures_iter(line) {
+                items.push((
+                    self.parse_custom_tag(&captures, &location, &generated_name)?,
+                    false,
+                ));
             }
         }
 
         Ok(items)
     }
 
-    /// Parse a full tag like [impl->dsn~validate-authentication-request~1]
+    /// Parse a full tag like [impl->dsn~validate-authentication-request~1]; a tag
+    /// listing several IDs yields one item covering all of them
     fn parse_full_tag(
         &self,
         captures: &regex::Captures,
         location: &Location,
+        generated_name: &dyn Fn(&str) -> String,
     ) -> Result<Option<SpecificationItem>> {
         let artifact_type = captures.get(1).unwrap().as_str();
         let name = captures.get(2).map(|m| m.as_str());
         let revision = captures.get(3).map(|m| m.as_str());
-        let covered_artifact_type = captures.get(4).unwrap().as_str();
-        let covered_name = captures.get(5).unwrap().as_str();
-        let covered_revision_str = captures.get(6).unwrap().as_str();
-        let covered_revision =
-            covered_revision_str
-                .parse::<u32>()
-                .map_err(|_| crate::Error::Parse {
-                    message: format!("Invalid revision number: {}", covered_revision_str),
+        let covered_ids = self
+            .item_ref_regex
+            .captures_iter(captures.get(4).unwrap().as_str())
+            .map(|id| {
+                let covered_revision = id[3].parse::<u32>().map_err(|_| crate::Error::Parse {
+                    message: format!("Invalid revision number: {}", &id[3]),
                     location: location.to_string(),
                 })?;
-        let needs_str = captures.get(7).map(|m| m.as_str());
+                Ok(SpecificationItemId::new(
+                    id[1].to_string(),
+                    id[2].to_string(),
+                    covered_revision,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let covered_name = &covered_ids[0].name;
+        let needs_str = captures.get(5).map(|m| m.as_str());
 
         // Create the covering item
         let item_name = if let (Some(name), Some(_revision)) = (name, revision) {
             name.to_string()
         } else {
-            // Generate a name based on the covered item and location
-            format!(
-                "{}-{}",
-                covered_name,
-                self.generate_hash(&location.to_string())
-            )
+            generated_name(covered_name)
         };
 
         let item_revision = if let Some(revision) = revision {
@@ -136,14 +417,8 @@ impl TagImporter {
 
         let item_id = SpecificationItemId::new(artifact_type.to_string(), item_name, item_revision);
 
-        let covered_id = SpecificationItemId::new(
-            covered_artifact_type.to_string(),
-            covered_name.to_string(),
-            covered_revision,
-        );
-
         let mut builder = SpecificationItem::builder(item_id)
-            .covers(covered_id)
+            .covers_multiple(covered_ids)
             .location(location.clone());
 
         // Parse needs if present
@@ -160,6 +435,7 @@ impl TagImporter {
         &self,
         captures: &regex::Captures,
         location: &Location,
+        generated_name: &dyn Fn(&str) -> String,
     ) -> Result<Option<SpecificationItem>> {
         let covered_artifact_type = captures.get(1).unwrap().as_str();
         let covered_name = captures.get(2).unwrap().as_str();
@@ -174,11 +450,7 @@ impl TagImporter {
         let artifact_type = captures.get(4).unwrap().as_str();
 
         // Create the covering item
-        let item_name = format!(
-            "{}-{}",
-            covered_name,
-            self.generate_hash(&location.to_string())
-        );
+        let item_name = generated_name(covered_name);
         let item_id = SpecificationItemId::new(
             artifact_type.to_string(),
             item_name,
@@ -199,6 +471,27 @@ impl TagImporter {
         Ok(Some(item))
     }
 
+    /// Parse a tag matched by a user-defined format
+    fn parse_custom_tag(
+        &self,
+        captures: &regex::Captures,
+        location: &Location,
+        generated_name: &dyn Fn(&str) -> String,
+    ) -> Result<SpecificationItem> {
+        let covered_id = SpecificationItemId::parse(&captures["id"])?;
+        let artifact_type = captures
+            .name("type")
+            .map_or(self.default_artifact_type.as_str(), |m| m.as_str());
+
+        let item_name = generated_name(&covered_id.name);
+        let item_id = SpecificationItemId::new(artifact_type.to_string(), item_name, 0);
+
+        Ok(SpecificationItem::builder(item_id)
+            .covers(covered_id)
+            .location(location.clone())
+            .build())
+    }
+
     /// Parse a comma-separated list of needed artifact types
     fn parse_needs_list(&self, needs_str: &str) -> Vec<String> {
         needs_str
@@ -208,6 +501,41 @@ impl TagImporter {
             .collect()
     }
 
+    /// Name of an item generated for a tag covering `covered_name`
+    ///
+    /// Inside a known code symbol the name is built from the file stem and the
+    /// symbol so that it survives unrelated edits; elsewhere it falls back to
+    /// a hash of the location.
+    fn generated_name(
+        &self,
+        covered_name: &str,
+        location: &Location,
+        symbol: Option<&str>,
+    ) -> String {
+        let stem = location
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default();
+        match symbol {
+            Some(symbol) => format!("{}-{}.{}", covered_name, stem, symbol)
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect(),
+            None => format!(
+                "{}-{}",
+                covered_name,
+                self.generate_hash(&location.to_string())
+            ),
+        }
+    }
+
     /// Generate a hash for auto-generated item names
     fn generate_hash(&self, input: &str) -> u64 {
         use std::collections::hash_map::DefaultHasher;
@@ -217,13 +545,6 @@ impl TagImporter {
         input.hash(&mut hasher);
         hasher.finish()
     }
-
-    /// Check if a file should be scanned for tags
-    fn should_scan_file(&self, path: &Path) -> bool {
-        // Use default config for now, could be made configurable
-        let config = Config::default();
-        config.matches_source_pattern(path)
-    }
 }
 
 impl Default for TagImporter {
@@ -255,6 +576,149 @@ mod tests {
         assert_eq!(item.covers[0].revision, 1);
     }
 
+    #[test]
+    fn test_parse_tag_covering_several_items() {
+        let importer = TagImporter::new();
+        let content = "// [impl->req~a~1, req~b~2,dsn~c~3]";
+        let temp_file = NamedTempFile::new().unwrap();
+
+        let items = importer.parse_line(content, temp_file.path(), 1).unwrap();
+        assert_eq!(items.len(), 1);
+
+        let covers: Vec<_> = items[0].covers.iter().map(|id| id.to_string()).collect();
+        assert_eq!(covers, vec!["req~a~1", "req~b~2", "dsn~c~3"]);
+        assert!(items[0].id.name.starts_with("a-"));
+    }
+
+    #[test]
+    fn test_block_tags() {
+        let content = "\
+// [impl->dsn~outer~1 begin]
+fn outer() {
+    // [impl->dsn~inner~1>>utest begin]
+    inner();
+    // [end]
+}
+// [end]
+// [impl->dsn~single~1]
+";
+        let items = TagImporter::new()
+            .import_from_content(content, Path::new("lib.rs"))
+            .unwrap();
+        assert_eq!(items.len(), 3);
+
+        let range = |item: &SpecificationItem| {
+            let end = item.end_location.as_ref().map(|end| end.line);
+            (item.location.as_ref().unwrap().line, end)
+        };
+        assert_eq!(range(&items[0]), (1, Some(7)));
+        assert_eq!(range(&items[1]), (3, Some(5)));
+        assert_eq!(items[1].needs, vec!["utest"]);
+        assert_eq!(range(&items[2]), (8, None));
+    }
+
+    #[test]
+    fn test_tags_outside_comments_are_ignored() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("parser.rs");
+        std::fs::write(
+            &source,
+            "// [impl->dsn~parser~1]\nlet example = \"[impl->dsn~example~1]\";\n",
+        )
+        .unwrap();
+
+        let items = TagImporter::new().import_from_file(&source).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].covers[0].name, "parser");
+
+        let items = TagImporter::new()
+            .comments_only(false)
+            .import_from_file(&source)
+            .unwrap();
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn test_strict_mode_ignores_tags_in_strings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("parser_test.rs");
+        std::fs::write(
+            &source,
+            "// [utest->dsn~parser~1]\nlet fixture = \"// [impl->dsn~example~1]\";\n",
+        )
+        .unwrap();
+
+        let items = TagImporter::new()
+            .comments_only(false)
+            .import_from_file(&source)
+            .unwrap();
+        assert_eq!(items.len(), 2);
+
+        for comments_only in [true, false] {
+            let items = TagImporter::new()
+                .strict(true)
+                .comments_only(comments_only)
+                .import_from_file(&source)
+                .unwrap();
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].covers[0].name, "parser");
+        }
+    }
+
+    #[test]
+    fn test_generated_names_follow_code_symbols() {
+        let content = "\
+impl Session {
+    // [impl->dsn~login~1]
+    pub fn login(&self) {
+        // [impl->dsn~login~1]
+    }
+}
+// [impl->dsn~config~1]
+";
+        let items = TagImporter::new()
+            .import_from_content(content, Path::new("src/session.rs"))
+            .unwrap();
+        let names: Vec<_> = items.iter().map(|item| item.id.name.as_str()).collect();
+        assert_eq!(names[0], "login-session.Session.login");
+        assert_eq!(names[1], "login-session.Session.login-4");
+        assert!(names[2].starts_with("config-") && !names[2].contains('.'));
+
+        // Moving the code keeps the names
+        let shifted = format!("\n\n{}", content);
+        let moved = TagImporter::new()
+            .import_from_content(&shifted, Path::new("src/session.rs"))
+            .unwrap();
+        assert_eq!(moved[0].id, items[0].id);
+    }
+
+    #[test]
+    fn test_user_defined_tag_formats() {
+        let importer = TagImporter::new().with_tag_formats(vec![
+            TagFormat::parse("@covers {id}").unwrap(),
+            TagFormat::parse("<{type} implements {id}>").unwrap(),
+        ]);
+        let content = "/**\n * @covers  req~login~1\n * <utest implements req~logout~2>\n */";
+
+        let items = importer
+            .import_from_content(content, Path::new("Login.java"))
+            .unwrap();
+        let found: Vec<_> = items
+            .iter()
+            .map(|item| (item.id.artifact_type.as_str(), item.covers[0].to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("impl", "req~login~1".to_string()),
+                ("utest", "req~logout~2".to_string())
+            ]
+        );
+
+        assert!(TagFormat::parse("@covers").is_err());
+        assert!(TagFormat::parse("@covers {id} {owner}").is_err());
+    }
+
     #[test]
     fn test_parse_tag_with_needs() {
         let importer = TagImporter::new();
@@ -306,4 +770,31 @@ mod tests {
         assert_eq!(impl_item.covers[0].name, "authenticate-user");
         assert_eq!(test_item.covers[0].name, "authenticate-user");
     }
+
+    #[test]
+    fn test_parallel_import_keeps_file_order() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for index in 0..20 {
+            std::fs::write(
+                temp_dir.path().join(format!("file_{:02}.rs", index)),
+                format!("// [impl->dsn~feature-{}~1]\n", index),
+            )
+            .unwrap();
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        let items = pool
+            .install(|| TagImporter::new().import_from_directory(temp_dir.path()))
+            .unwrap();
+
+        let covered: Vec<_> = items
+            .iter()
+            .map(|item| item.covers[0].name.clone())
+            .collect();
+        let expected: Vec<_> = (0..20).map(|index| format!("feature-{}", index)).collect();
+        assert_eq!(covered, expected);
+    }
 }
@@ -0,0 +1,175 @@
+use calamine::{open_workbook_auto, Reader};
+use std::path::Path;
+
+use super::file_walker::FileWalker;
+use super::table_mapper::TableMapper;
+use crate::config::SheetMapping;
+use crate::core::{Location, SpecificationItem};
+use crate::Result;
+
+/// Importer for requirement sheets in Excel workbooks
+///
+/// With no sheet mappings configured every sheet is read with headers in its
+/// first row; otherwise only the mapped sheets are read.
+pub struct XlsxImporter {
+    sheets: Vec<SheetMapping>,
+    walker: FileWalker,
+}
+
+impl XlsxImporter {
+    /// Create an importer for the given sheet mappings
+    pub fn new(sheets: Vec<SheetMapping>) -> Self {
+        Self {
+            sheets,
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import specification items from all workbooks in a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_xlsx_file(path) {
+                items.extend(self.import_from_file(path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single workbook
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut workbook = open_workbook_auto(file_path)?;
+        let mut items = Vec::new();
+
+        for sheet_name in workbook.sheet_names() {
+            let Some(mapping) = self.mapping_for(&sheet_name) else {
+                continue;
+            };
+
+            let range = workbook.worksheet_range(&sheet_name)?;
+            // Row numbers in the sheet are offset by the first used cell
+            let first_row = range.start().map(|(row, _)| row as usize).unwrap_or(0);
+            let rows: Vec<Vec<String>> = range
+                .rows()
+                .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+                .collect();
+
+            items.extend(Self::rows_to_items(
+                &mapping,
+                &rows,
+                first_row,
+                &sheet_name,
+                file_path,
+            )?);
+        }
+
+        Ok(items)
+    }
+
+    /// Check if a file is an Excel workbook
+    pub fn is_xlsx_file(path: &Path) -> bool {
+        // Skip lock files Excel creates next to open workbooks
+        let is_lock_file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("~$"));
+        !is_lock_file
+            && path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("xlsx"))
+    }
+
+    fn mapping_for(&self, sheet_name: &str) -> Option<SheetMapping> {
+        if self.sheets.is_empty() {
+            return Some(SheetMapping::new(sheet_name));
+        }
+        self.sheets
+            .iter()
+            .find(|mapping| mapping.sheet == sheet_name)
+            .cloned()
+    }
+
+    /// Convert the rows of a sheet into items
+    ///
+    /// `first_row` is the 0-based sheet row of `rows[0]`.
+    fn rows_to_items(
+        mapping: &SheetMapping,
+        rows: &[Vec<String>],
+        first_row: usize,
+        sheet_name: &str,
+        file_path: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        let header_index = mapping.header_row.saturating_sub(1 + first_row);
+        let Some(headers) = rows.get(header_index) else {
+            return Ok(Vec::new());
+        };
+        let Some(mapper) = TableMapper::new(&mapping.columns, headers) else {
+            log::warn!(
+                "Skipping sheet '{}' in {}: no '{}' column",
+                sheet_name,
+                file_path.display(),
+                mapping.columns.id
+            );
+            return Ok(Vec::new());
+        };
+
+        let mut items = Vec::new();
+        for (index, row) in rows.iter().enumerate().skip(header_index + 1) {
+            let line = (first_row + index + 1) as u32;
+            if let Some(mut item) =
+                mapper.item(row, Location::new(file_path.to_path_buf(), line))?
+            {
+                item.module.get_or_insert_with(|| sheet_name.to_string());
+                items.push(item);
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rows_with_header_row_mapping() {
+        let mut mapping = SheetMapping::new("Requirements");
+        mapping.header_row = 2;
+        mapping.columns.id = "Req ID".to_string();
+
+        let rows = vec![
+            row(&["Customer requirements", "", ""]),
+            row(&["Req ID", "Title", "Needs"]),
+            row(&["req~export~1", "Export", "dsn"]),
+            row(&["", "", ""]),
+            row(&["req~import~1", "Import", ""]),
+        ];
+        let items =
+            XlsxImporter::rows_to_items(&mapping, &rows, 0, "Requirements", Path::new("reqs.xlsx"))
+                .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id.to_string(), "req~export~1");
+        assert_eq!(items[0].needs, vec!["dsn"]);
+        assert_eq!(items[0].module.as_deref(), Some("Requirements"));
+        assert_eq!(items[1].location.as_ref().unwrap().line, 5);
+    }
+}
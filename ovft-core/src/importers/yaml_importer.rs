@@ -0,0 +1,243 @@
+use serde::Deserialize;
+use std::path::Path;
+
+use super::encoding::read_text_file;
+use super::file_walker::FileWalker;
+use crate::core::{ItemStatus, Location, SpecificationItem, SpecificationItemId};
+use crate::Result;
+
+/// Importer for structured requirement definitions in YAML files
+///
+/// A file holds one item, a list of items, or several `---` separated
+/// documents of either kind:
+///
+/// ```yaml
+/// - id: req~login~1
+///   title: Login
+///   description: Users shall log in with their password.
+///   needs: [dsn, impl]
+///   covers: [feat~authentication~1]
+///   tags: [security]
+///   status: approved
+/// ```
+pub struct YamlImporter {
+    walker: FileWalker,
+}
+
+/// One or more items in a YAML document
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum YamlDocument {
+    Many(Vec<YamlItem>),
+    One(Box<YamlItem>),
+}
+
+/// Item definition as written in YAML
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct YamlItem {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    rationale: Option<String>,
+    comment: Option<String>,
+    #[serde(default)]
+    needs: Vec<String>,
+    #[serde(default)]
+    covers: Vec<String>,
+    #[serde(default)]
+    depends: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    status: Option<String>,
+    module: Option<String>,
+}
+
+impl YamlImporter {
+    /// Create a new YAML importer
+    pub fn new() -> Self {
+        Self {
+            walker: FileWalker::new(),
+        }
+    }
+
+    /// Use the given walker to list the files of a directory
+    pub fn with_walker(mut self, walker: FileWalker) -> Self {
+        self.walker = walker;
+        self
+    }
+
+    /// Import specification items from all YAML files in a directory
+    pub fn import_from_directory(&self, dir: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        if !dir.exists() {
+            return Ok(items);
+        }
+
+        for path in &self.walker.files(dir) {
+            if Self::is_yaml_file(path) && self.walker.is_text_file(path) {
+                // Other YAML files (e.g. CI or site configuration) may live next to the specs
+                match self.import_from_file(path) {
+                    Ok(file_items) => items.extend(file_items),
+                    Err(crate::Error::Yaml(e)) => {
+                        log::warn!(
+                            "Skipping {}: not a requirement file ({})",
+                            path.display(),
+                            e
+                        );
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Import specification items from a single YAML file
+    pub fn import_from_file(&self, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let content = read_text_file(file_path)?;
+        self.parse_yaml(&content, file_path)
+    }
+
+    /// Import specification items from YAML content;
+    /// `origin` names the source in item locations
+    pub fn import_from_content(
+        &self,
+        content: &str,
+        origin: &Path,
+    ) -> Result<Vec<SpecificationItem>> {
+        self.parse_yaml(content, origin)
+    }
+
+    /// Check if a file is a YAML file
+    pub fn is_yaml_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"))
+    }
+
+    /// Parse all documents of a YAML file
+    fn parse_yaml(&self, content: &str, file_path: &Path) -> Result<Vec<SpecificationItem>> {
+        let mut items = Vec::new();
+
+        for document in serde_yaml::Deserializer::from_str(content) {
+            let yaml_items = match Option::<YamlDocument>::deserialize(document)? {
+                Some(YamlDocument::Many(items)) => items,
+                Some(YamlDocument::One(item)) => vec![*item],
+                None => continue,
+            };
+            for yaml_item in yaml_items {
+                items.push(Self::build_item(yaml_item, content, file_path)?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn build_item(
+        yaml_item: YamlItem,
+        content: &str,
+        file_path: &Path,
+    ) -> Result<SpecificationItem> {
+        let location = Location::new(
+            file_path.to_path_buf(),
+            Self::find_line(content, &yaml_item.id),
+        );
+        let parse_id = |id: &str| {
+            SpecificationItemId::parse(id).map_err(|e| crate::Error::Parse {
+                message: e.to_string(),
+                location: location.to_string(),
+            })
+        };
+
+        let mut item = SpecificationItem::new(parse_id(&yaml_item.id)?);
+        item.title = yaml_item.title;
+        item.description = yaml_item.description.map(|d| d.trim_end().to_string());
+        item.rationale = yaml_item.rationale;
+        item.comment = yaml_item.comment;
+        item.needs = yaml_item.needs;
+        item.tags = yaml_item.tags;
+        item.covers = yaml_item
+            .covers
+            .iter()
+            .map(|id| parse_id(id))
+            .collect::<Result<_>>()?;
+        item.depends = yaml_item
+            .depends
+            .iter()
+            .map(|id| parse_id(id))
+            .collect::<Result<_>>()?;
+        if let Some(status) = yaml_item.status {
+            item.status = ItemStatus::parse(&status).ok_or_else(|| crate::Error::Parse {
+                message: format!("Unknown status '{}'", status),
+                location: location.to_string(),
+            })?;
+        }
+        item.module = yaml_item.module.or_else(|| {
+            file_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+        });
+        item.location = Some(location);
+
+        Ok(item)
+    }
+
+    /// Find the line defining an item ID (1-based), falling back to the first line
+    fn find_line(content: &str, id: &str) -> u32 {
+        content
+            .lines()
+            .position(|line| line.contains("id:") && line.contains(id))
+            .map(|index| index as u32 + 1)
+            .unwrap_or(1)
+    }
+}
+
+impl Default for YamlImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yaml_list_and_documents() {
+        let content = "\
+- id: req~login~1
+  title: Login
+  description: |
+    Users shall log in.
+  needs: [dsn, impl]
+  covers: [feat~authentication~1]
+  tags: [security]
+  status: draft
+---
+id: dsn~login-form~1
+covers:
+  - req~login~1
+";
+        let items = YamlImporter::new()
+            .parse_yaml(content, Path::new("docs/auth.yaml"))
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id.to_string(), "req~login~1");
+        assert_eq!(items[0].description.as_deref(), Some("Users shall log in."));
+        assert_eq!(items[0].needs, vec!["dsn", "impl"]);
+        assert_eq!(items[0].status, ItemStatus::Draft);
+        assert_eq!(items[0].module.as_deref(), Some("auth"));
+        assert_eq!(items[1].covers[0].to_string(), "req~login~1");
+        assert_eq!(items[1].location.as_ref().unwrap().line, 10);
+    }
+
+    #[test]
+    fn test_invalid_id_is_error() {
+        let result = YamlImporter::new().parse_yaml("id: not-an-id\n", Path::new("bad.yml"));
+        assert!(result.is_err());
+    }
+}
@@ -29,12 +29,17 @@ pub mod error;
 pub mod importers;
 pub mod reporters;
 
-pub use config::Config;
+pub use config::{
+    CommentStyle, ComplianceMatrix, ComplianceStandard, Config, GitLabConfig, MinCoverage,
+    Namespace, NamingRules, ReportLanguage, ReportSplit, RevisionMatching, Severity,
+    SeverityPolicy, SourceDir, TagFilter, Waiver,
+};
 pub use core::{TraceResult, Tracer};
 pub use error::{Error, Result};
 
 /// Re-export commonly used types
 pub use crate::core::{
-    CoverageStatus, CoverageSummary, Defect, DefectType, ItemStatus, LinkStatus,
-    LinkedSpecificationItem, Location, SpecificationItem, SpecificationItemId,
+    CoverageStatus, CoverageSummary, Defect, DefectType, ImplementationMetrics, ItemStatus,
+    LinkStatus, LinkedSpecificationItem, Location, ModuleSummary, ReleaseVerdict,
+    SpecificationItem, SpecificationItemId, VerdictStatus,
 };
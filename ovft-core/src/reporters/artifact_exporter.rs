@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+
+use crate::core::SpecificationItem;
+use crate::importers::artifact_importer::{ArtifactFile, ARTIFACT_FORMAT_VERSION};
+use crate::Result;
+
+/// Exports specification items as an artifact file for other projects
+///
+/// Another repository lists the file in `external_artifacts` to cover these
+/// items or be covered by them, so coverage can be traced across the
+/// repositories of a product.
+pub struct ArtifactExporter {
+    project: Option<String>,
+}
+
+impl ArtifactExporter {
+    pub fn new() -> Self {
+        Self { project: None }
+    }
+
+    /// Name the exporting project, e.g. after its repository
+    pub fn with_project<S: Into<String>>(mut self, project: S) -> Self {
+        self.project = Some(project.into());
+        self
+    }
+
+    /// Render the artifact file, with the items ordered by ID
+    pub fn render<'a, I>(&self, items: I) -> Result<String>
+    where
+        I: IntoIterator<Item = &'a SpecificationItem>,
+    {
+        let mut items: Vec<_> = items.into_iter().cloned().collect();
+        items.sort_by_key(|item| item.id.to_string());
+        let file = ArtifactFile {
+            format_version: ARTIFACT_FORMAT_VERSION,
+            project: self.project.clone(),
+            items,
+        };
+        Ok(serde_json::to_string(&file)?)
+    }
+
+    /// Write the artifact file
+    pub fn write<'a, I>(&self, items: I, output_path: &Path) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a SpecificationItem>,
+    {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(items)?)?;
+        Ok(())
+    }
+}
+
+impl Default for ArtifactExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, SpecificationItemId};
+    use crate::importers::ArtifactImporter;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_artifacts_round_trip() {
+        let item = SpecificationItem::builder(SpecificationItemId::new(
+            "sysreq".to_string(),
+            "braking".to_string(),
+            2,
+        ))
+        .title("Braking".to_string())
+        .needs("req".to_string())
+        .location(Location::new(PathBuf::from("docs/system.md"), 4))
+        .build();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("system.ovft.json");
+
+        ArtifactExporter::new()
+            .with_project("system")
+            .write([&item], &path)
+            .unwrap();
+        let imported = ArtifactImporter::new().import_from_file(&path).unwrap();
+
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].id, item.id);
+        assert_eq!(imported[0].needs, item.needs);
+        assert_eq!(
+            imported[0].location.as_ref().unwrap().to_string(),
+            "system/docs/system.md:4"
+        );
+    }
+}
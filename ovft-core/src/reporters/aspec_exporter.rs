@@ -0,0 +1,284 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::core::{DeepCoverage, LinkedSpecificationItem, SpecificationItemId, TraceResult};
+use crate::Result;
+
+/// Exports a trace as OpenFastTrace `aspec` XML
+///
+/// The `aspec` format is the augmented specobject export of the Java
+/// OpenFastTrace tool. Items are grouped into one `<specobjects>` element per
+/// artifact type and carry the coverage computed by the trace.
+pub struct AspecExporter;
+
+impl AspecExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the trace result to an `aspec` XML file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result))?;
+        Ok(())
+    }
+
+    /// Render the trace result as an `aspec` XML document
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let deep_coverage = DeepCoverage::new(&trace_result.items);
+        let items_by_id: HashMap<_, _> = trace_result
+            .items
+            .iter()
+            .map(|item| (item.id(), item))
+            .collect();
+
+        let mut by_type: BTreeMap<&str, Vec<&LinkedSpecificationItem>> = BTreeMap::new();
+        for item in &trace_result.items {
+            by_type
+                .entry(item.id().artifact_type.as_str())
+                .or_default()
+                .push(item);
+        }
+
+        let mut xml =
+            String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n");
+        if !trace_result.metadata.is_empty() {
+            let _ = writeln!(xml, "<!-- {} -->", trace_result.metadata.summary());
+        }
+        xml.push_str("<specdocument>\n");
+        for (artifact_type, mut items) in by_type {
+            items.sort_by_key(|item| item.id().to_string());
+            let _ = writeln!(
+                xml,
+                "  <specobjects doctype=\"{}\">",
+                Self::escape(artifact_type)
+            );
+            for item in items {
+                self.write_item(&mut xml, item, &items_by_id, &deep_coverage);
+            }
+            xml.push_str("  </specobjects>\n");
+        }
+        xml.push_str("</specdocument>\n");
+        xml
+    }
+
+    fn write_item(
+        &self,
+        xml: &mut String,
+        linked_item: &LinkedSpecificationItem,
+        items_by_id: &HashMap<&SpecificationItemId, &LinkedSpecificationItem>,
+        deep_coverage: &DeepCoverage,
+    ) {
+        let item = &linked_item.item;
+        xml.push_str("    <specobject>\n");
+        Self::element(xml, 3, "id", &item.id.name);
+        Self::element(xml, 3, "status", &item.status.to_string());
+        Self::element(xml, 3, "version", &item.id.revision.to_string());
+        if let Some(location) = &item.location {
+            Self::element(xml, 3, "sourcefile", &location.path.display().to_string());
+            Self::element(xml, 3, "sourceline", &location.line.to_string());
+        }
+        for (name, value) in [
+            ("shortdesc", &item.title),
+            ("description", &item.description),
+            ("rationale", &item.rationale),
+            ("comment", &item.comment),
+        ] {
+            if let Some(value) = value {
+                Self::element(xml, 3, name, value);
+            }
+        }
+
+        if !item.tags.is_empty() {
+            xml.push_str("      <tags>\n");
+            for tag in &item.tags {
+                Self::element(xml, 4, "tag", tag);
+            }
+            xml.push_str("      </tags>\n");
+        }
+
+        if !item.needs.is_empty() {
+            xml.push_str("      <needscoverage>\n");
+            for needed_type in &item.needs {
+                Self::element(xml, 4, "needsobj", needed_type);
+            }
+            xml.push_str("      </needscoverage>\n");
+        }
+
+        if !item.covers.is_empty() {
+            xml.push_str("      <providescoverage>\n");
+            for covered_id in &item.covers {
+                xml.push_str("        <provcov>\n");
+                Self::element(xml, 5, "linksto", &Self::link_target(covered_id));
+                Self::element(xml, 5, "dstversion", &covered_id.revision.to_string());
+                xml.push_str("        </provcov>\n");
+            }
+            xml.push_str("      </providescoverage>\n");
+        }
+
+        if !item.depends.is_empty() {
+            xml.push_str("      <dependencies>\n");
+            for dependency in &item.depends {
+                Self::element(
+                    xml,
+                    4,
+                    "dependson",
+                    &format!(
+                        "{}, version {}",
+                        Self::link_target(dependency),
+                        dependency.revision
+                    ),
+                );
+            }
+            xml.push_str("      </dependencies>\n");
+        }
+
+        let covering: Vec<_> = linked_item
+            .incoming_links
+            .iter()
+            .filter_map(|link| link.source_id.as_ref())
+            .collect();
+        if !covering.is_empty() {
+            xml.push_str("      <coveringSpecObjects>\n");
+            for covering_id in covering {
+                xml.push_str("        <coveringSpecObject>\n");
+                Self::element(xml, 5, "id", &covering_id.name);
+                Self::element(xml, 5, "version", &covering_id.revision.to_string());
+                Self::element(xml, 5, "doctype", &covering_id.artifact_type);
+                if let Some(covering_item) = items_by_id.get(covering_id) {
+                    Self::element(xml, 5, "status", &covering_item.item.status.to_string());
+                    Self::element(xml, 5, "ownCoverageStatus", Self::own_status(covering_item));
+                }
+                Self::element(
+                    xml,
+                    5,
+                    "deepCoverageStatus",
+                    Self::deep_status(deep_coverage.is_deeply_covered(covering_id)),
+                );
+                let covering_status = if item.needs.contains(&covering_id.artifact_type) {
+                    "COVERING"
+                } else {
+                    "UNWANTED"
+                };
+                Self::element(xml, 5, "coveringStatus", covering_status);
+                xml.push_str("        </coveringSpecObject>\n");
+            }
+            xml.push_str("      </coveringSpecObjects>\n");
+        }
+
+        let missing = linked_item.missing_coverage_types();
+        let covered: Vec<_> = item
+            .needs
+            .iter()
+            .filter(|needed_type| !missing.contains(needed_type))
+            .collect();
+        if !covered.is_empty() {
+            xml.push_str("      <coveredTypes>\n");
+            for covered_type in covered {
+                Self::element(xml, 4, "coveredType", covered_type);
+            }
+            xml.push_str("      </coveredTypes>\n");
+        }
+        if !missing.is_empty() {
+            xml.push_str("      <uncoveredTypes>\n");
+            for missing_type in &missing {
+                Self::element(xml, 4, "uncoveredType", missing_type);
+            }
+            xml.push_str("      </uncoveredTypes>\n");
+        }
+
+        Self::element(xml, 3, "ownCoverageStatus", Self::own_status(linked_item));
+        Self::element(
+            xml,
+            3,
+            "deepCoverageStatus",
+            Self::deep_status(deep_coverage.is_deeply_covered(&item.id)),
+        );
+        xml.push_str("    </specobject>\n");
+    }
+
+    /// Link target in OFT notation (`type:name`)
+    fn link_target(id: &SpecificationItemId) -> String {
+        format!("{}:{}", id.artifact_type, id.name)
+    }
+
+    fn own_status(item: &LinkedSpecificationItem) -> &'static str {
+        if item.is_covered() {
+            "COVERED"
+        } else {
+            "UNCOVERED"
+        }
+    }
+
+    fn deep_status(deeply_covered: bool) -> &'static str {
+        if deeply_covered {
+            "COVERED"
+        } else {
+            "UNCOVERED"
+        }
+    }
+
+    fn element(xml: &mut String, depth: usize, name: &str, value: &str) {
+        let _ = writeln!(
+            xml,
+            "{}<{name}>{}</{name}>",
+            "  ".repeat(depth),
+            Self::escape(value)
+        );
+    }
+
+    fn escape(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+impl Default for AspecExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+
+    #[test]
+    fn test_render_aspec() {
+        let req = SpecificationItemId::parse("req~export~1").unwrap();
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .title("Export <CSV>".to_string())
+                .needs_multiple(vec!["dsn".to_string(), "impl".to_string()])
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("dsn~export~2").unwrap())
+                .covers(req)
+                .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(linked);
+
+        let xml = AspecExporter::new().render(&trace_result);
+        let document = roxmltree::Document::parse(&xml).unwrap();
+        let doctypes: Vec<_> = document
+            .descendants()
+            .filter(|node| node.has_tag_name("specobjects"))
+            .filter_map(|node| node.attribute("doctype"))
+            .collect();
+        assert_eq!(doctypes, vec!["dsn", "req"]);
+
+        assert!(xml.contains("<shortdesc>Export &lt;CSV&gt;</shortdesc>"));
+        assert!(xml.contains("<linksto>req:export</linksto>"));
+        assert!(xml.contains("<coveredType>dsn</coveredType>"));
+        assert!(xml.contains("<uncoveredType>impl</uncoveredType>"));
+        assert!(xml.contains("<coveringStatus>COVERING</coveringStatus>"));
+    }
+}
@@ -0,0 +1,109 @@
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::AuditConfig;
+use crate::core::TraceResult;
+use crate::Result;
+
+/// File name of the serialized trace result covered by the manifest
+pub const TRACE_RESULT_FILE: &str = "trace-result.json";
+/// File name of the checksum manifest
+pub const MANIFEST_FILE: &str = "SHA256SUMS";
+
+/// Writes a SHA-256 checksum manifest over report files and the trace result
+///
+/// The manifest uses the `sha256sum` format, so archived evidence can be
+/// verified with `sha256sum -c SHA256SUMS` from the output directory.
+pub struct AuditWriter<'a> {
+    config: &'a AuditConfig,
+}
+
+impl<'a> AuditWriter<'a> {
+    pub fn new(config: &'a AuditConfig) -> Self {
+        Self { config }
+    }
+
+    /// Serialize the trace result into `dir`, write the manifest and sign it if configured
+    ///
+    /// Returns the path of the written manifest.
+    pub fn write(
+        &self,
+        trace_result: &TraceResult,
+        report_files: &[PathBuf],
+        dir: &Path,
+    ) -> Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+
+        let trace_result_path = dir.join(TRACE_RESULT_FILE);
+        fs::write(
+            &trace_result_path,
+            serde_json::to_string_pretty(trace_result)?,
+        )?;
+
+        let mut manifest = String::new();
+        for path in report_files.iter().chain([&trace_result_path]) {
+            let digest = Sha256::digest(fs::read(path)?);
+            let name = path.strip_prefix(dir).unwrap_or(path);
+            manifest.push_str(&format!("{:x}  {}\n", digest, name.display()));
+        }
+
+        let manifest_path = dir.join(MANIFEST_FILE);
+        fs::write(&manifest_path, manifest)?;
+
+        if let Some(sign_command) = &self.config.sign_command {
+            Self::sign(sign_command, &manifest_path)?;
+        }
+
+        Ok(manifest_path)
+    }
+
+    /// Run the external signing command for the manifest
+    fn sign(sign_command: &str, manifest_path: &Path) -> Result<()> {
+        let manifest = manifest_path.to_string_lossy();
+        let mut parts = sign_command
+            .split_whitespace()
+            .map(|part| part.replace("{file}", &manifest));
+        let program = parts
+            .next()
+            .ok_or_else(|| crate::Error::Audit("Empty sign command".to_string()))?;
+
+        let status = Command::new(&program).args(parts).status()?;
+        if !status.success() {
+            return Err(crate::Error::Audit(format!(
+                "Sign command '{}' failed with {}",
+                program, status
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_write_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let report = temp_dir.path().join("report.html");
+        fs::write(&report, "<html></html>").unwrap();
+
+        let trace_result = TraceResult::from_items(vec![]);
+
+        let config = AuditConfig::default();
+        let manifest_path = AuditWriter::new(&config)
+            .write(&trace_result, &[report], temp_dir.path())
+            .unwrap();
+        let manifest = fs::read_to_string(manifest_path).unwrap();
+        let lines: Vec<_> = manifest.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("  report.html"));
+        assert_eq!(lines[0].split_whitespace().next().unwrap().len(), 64);
+        assert!(lines[1].ends_with("  trace-result.json"));
+        assert!(temp_dir.path().join(TRACE_RESULT_FILE).exists());
+    }
+}
@@ -0,0 +1,180 @@
+use std::fs;
+use std::path::Path;
+
+use crate::core::{CoverageStatus, LinkedSpecificationItem, TraceResult};
+use crate::Result;
+
+/// A requirement that still lacks coverage, ready to be filed as an issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacklogEntry {
+    /// Item ID as string
+    pub id: String,
+    /// Issue title
+    pub title: String,
+    /// Item description
+    pub description: String,
+    /// Artifact types that are needed but not covered
+    pub missing: Vec<String>,
+    /// Link to the item in the HTML report
+    pub link: String,
+}
+
+/// Exports uncovered and partially covered items as a remediation backlog
+pub struct BacklogExporter {
+    /// Location of the HTML report used for item anchors
+    report_url: String,
+}
+
+impl BacklogExporter {
+    /// Create an exporter linking to the HTML report at the given URL or path
+    pub fn new<S: Into<String>>(report_url: S) -> Self {
+        Self {
+            report_url: report_url.into(),
+        }
+    }
+
+    /// Collect backlog entries for all items lacking coverage, sorted by ID
+    pub fn entries(&self, trace_result: &TraceResult) -> Vec<BacklogEntry> {
+        let mut entries: Vec<_> = trace_result
+            .items
+            .iter()
+            .filter(|item| {
+                !item.item.needs.is_empty()
+                    && !matches!(item.coverage_status, CoverageStatus::Covered)
+            })
+            .map(|item| self.entry(item))
+            .collect();
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        entries
+    }
+
+    /// Write the backlog as a single CSV file
+    pub fn write_csv(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        let mut csv = String::from("id,title,description,missing,link\n");
+        for entry in self.entries(trace_result) {
+            let fields = [
+                entry.id,
+                entry.title,
+                entry.description,
+                entry.missing.join(" "),
+                entry.link,
+            ];
+            let row: Vec<_> = fields.iter().map(|field| Self::csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, csv)?;
+        Ok(())
+    }
+
+    /// Write one issue template (markdown with front matter) per backlog entry
+    ///
+    /// The files follow the GitHub issue template layout and can be used as
+    /// GitLab issue descriptions as well.
+    pub fn write_issue_bundle(&self, trace_result: &TraceResult, output_dir: &Path) -> Result<()> {
+        fs::create_dir_all(output_dir)?;
+
+        for entry in self.entries(trace_result) {
+            let mut labels = vec!["traceability".to_string()];
+            labels.extend(entry.missing.iter().map(|t| format!("needs-{}", t)));
+
+            let content = format!(
+                "---\nname: {id}\nabout: Missing coverage for {id}\ntitle: \"{title}\"\nlabels: [{labels}]\n---\n\n\
+                 ## {id}\n\n{description}\n\n**Missing coverage:** {missing}\n\n[View in traceability report]({link})\n",
+                id = entry.id,
+                title = entry.title.replace('"', "'"),
+                labels = labels.join(", "),
+                description = entry.description,
+                missing = entry.missing.join(", "),
+                link = entry.link,
+            );
+
+            let file_name = format!("{}.md", entry.id.replace('~', "-"));
+            fs::write(output_dir.join(file_name), content)?;
+        }
+
+        Ok(())
+    }
+
+    fn entry(&self, item: &LinkedSpecificationItem) -> BacklogEntry {
+        let missing = item.missing_coverage_types();
+        BacklogEntry {
+            id: item.id().to_string(),
+            title: format!(
+                "Cover {} ({}) by {}",
+                item.id(),
+                item.title(),
+                missing.join(", ")
+            ),
+            description: item.item.description.clone().unwrap_or_default(),
+            link: format!("{}#{}", self.report_url, item.id().to_html_id()),
+            missing,
+        }
+    }
+
+    /// Quote a CSV field if it contains separators, quotes or line breaks
+    fn csv_field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem, SpecificationItemId};
+    use tempfile::TempDir;
+
+    fn trace_result() -> TraceResult {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .description("Export data, quickly".to_string())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+
+        TraceResult::from_items(linked)
+    }
+
+    #[test]
+    fn test_backlog_entries_and_csv() {
+        let exporter = BacklogExporter::new("report.html");
+        let trace_result = trace_result();
+
+        let entries = exporter.entries(&trace_result);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].missing, vec!["utest"]);
+        assert_eq!(entries[0].link, "report.html#item_req_export_1");
+
+        let temp_dir = TempDir::new().unwrap();
+        let csv_path = temp_dir.path().join("backlog.csv");
+        exporter.write_csv(&trace_result, &csv_path).unwrap();
+        let csv = fs::read_to_string(csv_path).unwrap();
+        assert!(csv.contains(
+            "req~export~1,Cover req~export~1 (export) by utest,\"Export data, quickly\",utest,"
+        ));
+
+        exporter
+            .write_issue_bundle(&trace_result, &temp_dir.path().join("issues"))
+            .unwrap();
+        let issue = fs::read_to_string(temp_dir.path().join("issues/req-export-1.md")).unwrap();
+        assert!(issue.contains("labels: [traceability, needs-utest]"));
+    }
+}
@@ -0,0 +1,188 @@
+use askama::Template;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::config::{ComplianceColumn, ComplianceMatrix};
+use crate::core::{
+    DeepCoverage, LinkedSpecificationItem, ReportMetadata, SpecificationItemId, TraceResult,
+};
+use crate::Result;
+
+/// One row of a compliance matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplianceRow {
+    /// Traced item
+    pub id: SpecificationItemId,
+    /// Title of the traced item
+    pub title: String,
+    /// Where the traced item is defined
+    pub location: String,
+    /// Tracing item IDs per column, with locations
+    pub cells: Vec<Vec<String>>,
+    /// Whether the item is covered down the whole chain
+    pub complete: bool,
+}
+
+/// Reporter laying out trace data as a standard-specific compliance matrix
+pub struct ComplianceReporter<'a> {
+    matrix: &'a ComplianceMatrix,
+}
+
+impl<'a> ComplianceReporter<'a> {
+    pub fn new(matrix: &'a ComplianceMatrix) -> Self {
+        Self { matrix }
+    }
+
+    /// Build the matrix rows, one per item of the matrix's artifact type
+    pub fn rows(&self, trace_result: &TraceResult) -> Vec<ComplianceRow> {
+        let columns = self.matrix.effective_columns();
+        let deep_coverage = DeepCoverage::new(&trace_result.items);
+        let items_by_id: HashMap<_, _> = trace_result
+            .items
+            .iter()
+            .map(|item| (item.id(), item))
+            .collect();
+
+        let mut rows: Vec<_> = trace_result
+            .items
+            .iter()
+            .filter(|item| item.item.id.artifact_type == self.matrix.artifact_type)
+            .map(|item| {
+                let tracing = Self::tracing_items(item, &items_by_id);
+                ComplianceRow {
+                    id: item.item.id.clone(),
+                    title: item.item.title.clone().unwrap_or_default(),
+                    location: item
+                        .item
+                        .location
+                        .as_ref()
+                        .map(|location| location.to_string())
+                        .unwrap_or_default(),
+                    cells: columns
+                        .iter()
+                        .map(|column| Self::cell(column, &tracing))
+                        .collect(),
+                    complete: deep_coverage.is_deeply_covered(item.id()),
+                }
+            })
+            .collect();
+
+        rows.sort_by_key(|row| row.id.to_string());
+        rows
+    }
+
+    /// Render the matrix as HTML and write it to `output_path`
+    pub fn generate_report(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        let template = ComplianceTemplate {
+            title: self.matrix.standard.title(),
+            item_heading: self.matrix.standard.item_heading(),
+            headings: self
+                .matrix
+                .effective_columns()
+                .into_iter()
+                .map(|column| column.heading)
+                .collect(),
+            rows: self.rows(trace_result),
+            metadata: &trace_result.metadata,
+            css: include_str!("../assets/report.css"),
+        };
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, template.render()?)?;
+        Ok(())
+    }
+
+    /// Collect all items tracing to `item`, directly or further down the chain
+    fn tracing_items<'b>(
+        item: &'b LinkedSpecificationItem,
+        items_by_id: &HashMap<&'b SpecificationItemId, &'b LinkedSpecificationItem>,
+    ) -> Vec<&'b LinkedSpecificationItem> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![item];
+        let mut result = Vec::new();
+
+        while let Some(current) = pending.pop() {
+            for source_id in current
+                .incoming_links
+                .iter()
+                .filter(|link| link.status.is_valid_coverage())
+                .filter_map(|link| link.source_id.as_ref())
+            {
+                if let Some(source) = items_by_id.get(source_id) {
+                    if visited.insert(source_id) {
+                        result.push(*source);
+                        pending.push(*source);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn cell(column: &ComplianceColumn, tracing: &[&LinkedSpecificationItem]) -> Vec<String> {
+        let mut entries: Vec<_> = tracing
+            .iter()
+            .filter(|item| column.artifact_types.contains(&item.item.id.artifact_type))
+            .map(|item| match &item.item.location {
+                Some(location) => format!("{} ({})", item.item.id, location),
+                None => item.item.id.to_string(),
+            })
+            .collect();
+        entries.sort();
+        entries
+    }
+}
+
+/// Template for compliance matrices
+#[derive(Template)]
+#[template(path = "compliance.html")]
+struct ComplianceTemplate<'a> {
+    title: &'a str,
+    item_heading: &'a str,
+    headings: Vec<String>,
+    rows: Vec<ComplianceRow>,
+    metadata: &'a ReportMetadata,
+    css: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ComplianceStandard;
+    use crate::core::{Linker, SpecificationItem};
+
+    #[test]
+    fn test_do178c_rows_follow_chain() {
+        let req = SpecificationItemId::parse("req~login~1").unwrap();
+        let dsn = SpecificationItemId::parse("dsn~login-form~1").unwrap();
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .title("Login".to_string())
+                .needs("dsn".to_string())
+                .build(),
+            SpecificationItem::builder(dsn.clone())
+                .covers(req.clone())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::parse("utest~login-form~1").unwrap())
+                .covers(dsn)
+                .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(linked);
+
+        let matrix = ComplianceMatrix::new(ComplianceStandard::Do178c, "do178c.html");
+        let rows = ComplianceReporter::new(&matrix).rows(&trace_result);
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, req);
+        assert_eq!(rows[0].cells[0], vec!["dsn~login-form~1".to_string()]);
+        assert!(rows[0].cells[1].is_empty());
+        assert_eq!(rows[0].cells[2], vec!["utest~login-form~1".to_string()]);
+        assert!(rows[0].complete);
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+use crate::core::{CoverageStatus, Defect, TraceResult, VerdictStatus};
+
+/// Width of the coverage bars in characters
+const BAR_WIDTH: usize = 20;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const DIM: &str = "\x1b[2m";
+
+/// When the console summary uses colors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Colors if the output is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always colors, e.g. for CI logs that render ANSI escapes
+    Always,
+    /// Never colors
+    Never,
+}
+
+impl ColorChoice {
+    /// Decide whether to use colors when writing to the given stream
+    pub fn enabled(self, stream: &impl IsTerminal) -> bool {
+        match self {
+            Self::Auto => stream.is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(crate::Error::Config(format!(
+                "Unknown color choice '{}' (expected auto, always or never)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+            Self::Never => "never",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Renders the summary of a trace for the terminal
+///
+/// Shows a coverage bar per artifact type, the defects grouped by defect
+/// type, failed tag gates, module readiness, the release verdict and the
+/// warnings. Colors are plain ANSI escapes and can be switched off.
+pub struct ConsoleReporter {
+    color: bool,
+    defects: bool,
+}
+
+impl ConsoleReporter {
+    pub fn new() -> Self {
+        Self {
+            color: false,
+            defects: true,
+        }
+    }
+
+    /// Colorize the output with ANSI escapes
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Whether to show the item count and defect list, which the text report shows itself
+    pub fn with_defects(mut self, defects: bool) -> Self {
+        self.defects = defects;
+        self
+    }
+
+    /// Render the summary
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let mut output = String::new();
+
+        if self.defects {
+            let status = if trace_result.is_success {
+                self.paint(GREEN, "ok")
+            } else {
+                self.paint(RED, "not ok")
+            };
+            let _ = writeln!(
+                output,
+                "{} - {} items, {} defects",
+                status, trace_result.total_items, trace_result.defect_count
+            );
+        }
+
+        let summaries: BTreeMap<_, _> = trace_result.coverage_summary.iter().collect();
+        if !summaries.is_empty() {
+            let _ = writeln!(output, "\n{}", self.paint(BOLD, "Coverage:"));
+            let width = summaries.keys().map(|name| name.len()).max().unwrap_or(0);
+            for (artifact_type, summary) in summaries {
+                let color = match summary.status {
+                    CoverageStatus::Covered => GREEN,
+                    CoverageStatus::Partial => YELLOW,
+                    CoverageStatus::Uncovered => RED,
+                };
+                let _ = writeln!(
+                    output,
+                    "  {:<width$} {} {:>5.1}% ({}/{})",
+                    artifact_type,
+                    self.bar(summary.percentage, color),
+                    summary.percentage,
+                    summary.covered,
+                    summary.total,
+                );
+            }
+        }
+
+        if self.defects && !trace_result.defects.is_empty() {
+            self.write_defects(&mut output, "Defects", &trace_result.defects, RED);
+        }
+
+        for gate in trace_result.failed_gates() {
+            let _ = writeln!(
+                output,
+                "\n{}",
+                self.paint(RED, &format!("Tag gate '{}' failed:", gate.tag))
+            );
+            for violation in &gate.violations {
+                let _ = writeln!(output, "  - {}", violation);
+            }
+        }
+
+        let modules = trace_result.module_summaries();
+        if !modules.is_empty() {
+            let _ = writeln!(output, "\n{}", self.paint(BOLD, "Modules:"));
+            for module in modules {
+                let ready = if module.is_ready() {
+                    self.paint(GREEN, "ready")
+                } else {
+                    self.paint(RED, "not ready")
+                };
+                let _ = writeln!(
+                    output,
+                    "  {}: {}/{} ({:.1}%), {} open defects, {}",
+                    module.module,
+                    module.covered,
+                    module.total,
+                    module.percentage,
+                    module.open_defects,
+                    ready
+                );
+            }
+        }
+
+        let verdict = &trace_result.release_verdict;
+        let verdict_color = match verdict.status {
+            VerdictStatus::Pass => GREEN,
+            VerdictStatus::PassWithWaivers => YELLOW,
+            VerdictStatus::Fail => RED,
+        };
+        let _ = writeln!(
+            output,
+            "\n{} {}",
+            self.paint(BOLD, "Release readiness:"),
+            self.paint(verdict_color, &verdict.status.to_string())
+        );
+        for reason in &verdict.blocking {
+            let _ = writeln!(output, "  - blocking: {}", reason);
+        }
+        for reason in &verdict.waived {
+            let _ = writeln!(output, "  - waived: {}", reason);
+        }
+
+        if !trace_result.warnings.is_empty() {
+            self.write_defects(&mut output, "Warnings", &trace_result.warnings, YELLOW);
+        }
+        output
+    }
+
+    /// List defects under a heading, grouped by defect type
+    fn write_defects(&self, output: &mut String, heading: &str, defects: &[Defect], color: &str) {
+        let mut by_type: BTreeMap<String, Vec<&Defect>> = BTreeMap::new();
+        for defect in defects {
+            by_type
+                .entry(defect.defect_type.to_string())
+                .or_default()
+                .push(defect);
+        }
+
+        let heading = format!("{} ({}):", heading, defects.len());
+        let _ = writeln!(output, "\n{}", self.paint(BOLD, &heading));
+        for (defect_type, defects) in by_type {
+            let _ = writeln!(
+                output,
+                "  {} ({})",
+                self.paint(color, &defect_type),
+                defects.len()
+            );
+            for defect in defects {
+                let _ = writeln!(output, "    - {}", defect.description);
+            }
+        }
+    }
+
+    fn bar(&self, percentage: f64, color: &str) -> String {
+        let filled = ((percentage / 100.0) * BAR_WIDTH as f64).round() as usize;
+        let filled = filled.min(BAR_WIDTH);
+        format!(
+            "{}{}",
+            self.paint(color, &"█".repeat(filled)),
+            self.paint(DIM, &"░".repeat(BAR_WIDTH - filled))
+        )
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color && !text.is_empty() {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+impl Default for ConsoleReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        CoverageSummary, DefectType, Linker, SpecificationItem, SpecificationItemId,
+    };
+    use std::collections::HashMap;
+
+    fn trace_result() -> TraceResult {
+        let req = SpecificationItemId::new("req".to_string(), "console".to_string(), 1);
+        let items = vec![SpecificationItem::builder(req.clone())
+            .needs("impl".to_string())
+            .build()];
+        let linked = Linker::new().link_items(items).unwrap();
+        let defect = |defect_type, description: &str| Defect {
+            defect_type,
+            description: description.to_string(),
+            item_id: Some(req.clone()),
+            location: None,
+        };
+        TraceResult {
+            defect_count: 3,
+            defects: vec![
+                defect(DefectType::UncoveredItem, "Missing coverage: impl"),
+                defect(DefectType::OrphanedCoverage, "Covers unknown item"),
+                defect(DefectType::UncoveredItem, "Missing coverage: utest"),
+            ],
+            coverage_summary: HashMap::from([(
+                "req".to_string(),
+                CoverageSummary {
+                    total: 4,
+                    covered: 2,
+                    percentage: 50.0,
+                    status: CoverageStatus::Partial,
+                },
+            )]),
+            is_success: false,
+            ..TraceResult::from_items(linked)
+        }
+    }
+
+    #[test]
+    fn test_coverage_bars_and_grouped_defects() {
+        let output = ConsoleReporter::new().render(&trace_result());
+        assert!(output.starts_with("not ok - 1 items, 3 defects\n"));
+        assert!(output.contains("  req ██████████░░░░░░░░░░  50.0% (2/4)\n"));
+        assert!(output.contains(
+            "Defects (3):\n  orphaned (1)\n    - Covers unknown item\n  uncovered (2)\n"
+        ));
+        assert!(!output.contains('\x1b'));
+
+        let output = ConsoleReporter::new()
+            .with_defects(false)
+            .render(&trace_result());
+        assert!(!output.contains("Defects"));
+    }
+
+    #[test]
+    fn test_colors() {
+        let output = ConsoleReporter::new()
+            .with_color(true)
+            .render(&trace_result());
+        assert!(output.starts_with("\x1b[31mnot ok\x1b[0m"));
+        assert!(output.contains("\x1b[33m██████████\x1b[0m"));
+
+        assert!(ColorChoice::Always.enabled(&std::io::stdout()));
+        assert!(!ColorChoice::Never.enabled(&std::io::stdout()));
+        assert_eq!("never".parse::<ColorChoice>().unwrap(), ColorChoice::Never);
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
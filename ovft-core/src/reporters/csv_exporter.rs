@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::Path;
+
+use crate::core::{LinkedSpecificationItem, TraceResult};
+use crate::Result;
+
+/// Exports the trace as two CSV tables for spreadsheet analysis
+///
+/// `items.csv` has one row per item with all of its fields and its coverage,
+/// `links.csv` one row per link of an item with the link status. List fields
+/// are separated by spaces, custom attributes are written as `key=value` pairs
+/// separated by semicolons.
+pub struct CsvExporter;
+
+impl CsvExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write `items.csv` and `links.csv` into the given directory
+    pub fn write(&self, trace_result: &TraceResult, output_dir: &Path) -> Result<()> {
+        fs::create_dir_all(output_dir)?;
+        let mut items: Vec<_> = trace_result.items.iter().collect();
+        items.sort_by_key(|item| item.id().to_string());
+
+        let mut writer = csv::Writer::from_path(output_dir.join("items.csv"))?;
+        writer.write_record([
+            "id",
+            "artifact_type",
+            "name",
+            "revision",
+            "title",
+            "status",
+            "description",
+            "rationale",
+            "comment",
+            "needs",
+            "covers",
+            "depends",
+            "tags",
+            "module",
+            "location",
+            "coverage",
+            "missing_coverage",
+            "defect",
+            "attributes",
+        ])?;
+        for item in &items {
+            writer.write_record(Self::item_record(item))?;
+        }
+        writer.flush()?;
+
+        let mut writer = csv::Writer::from_path(output_dir.join("links.csv"))?;
+        writer.write_record(["item", "direction", "linked_item", "status"])?;
+        for item in &items {
+            let id = item.id().to_string();
+            for link in &item.outgoing_links {
+                let target = link.target_id.to_string();
+                writer.write_record([id.as_str(), "outgoing", &target, &link.status.to_string()])?;
+            }
+            for link in &item.incoming_links {
+                let source = link.source_id.as_ref().unwrap_or(&link.target_id).to_string();
+                writer.write_record([id.as_str(), "incoming", &source, &link.status.to_string()])?;
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn item_record(linked_item: &LinkedSpecificationItem) -> Vec<String> {
+        let item = &linked_item.item;
+        let join_ids = |ids: &[crate::core::SpecificationItemId]| {
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        vec![
+            item.id.to_string(),
+            item.id.artifact_type.clone(),
+            item.id.name.clone(),
+            item.id.revision.to_string(),
+            item.title.clone().unwrap_or_default(),
+            item.status.to_string(),
+            item.description.clone().unwrap_or_default(),
+            item.rationale.clone().unwrap_or_default(),
+            item.comment.clone().unwrap_or_default(),
+            item.needs.join(" "),
+            join_ids(&item.covers),
+            join_ids(&item.depends),
+            item.tags.join(" "),
+            item.module.clone().unwrap_or_default(),
+            item.location
+                .as_ref()
+                .map(|location| format!("{}:{}", location.path.display(), location.line))
+                .unwrap_or_default(),
+            linked_item.coverage_status.to_string(),
+            linked_item.missing_coverage_types().join(" "),
+            linked_item.is_defect.to_string(),
+            item.metadata
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ]
+    }
+}
+
+impl Default for CsvExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem, SpecificationItemId};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_items_and_links_are_exported() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .description("Export data, \"quickly\"".to_string())
+                .needs("impl".to_string())
+                .attribute("ASIL".to_string(), "B".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(linked);
+
+        let dir = TempDir::new().unwrap();
+        CsvExporter::new().write(&trace_result, dir.path()).unwrap();
+
+        let items_csv = fs::read_to_string(dir.path().join("items.csv")).unwrap();
+        assert_eq!(items_csv.lines().count(), 3);
+        assert!(items_csv.contains("\"Export data, \"\"quickly\"\"\""));
+        assert!(items_csv.contains(",covered,,false,ASIL=B"));
+
+        let links_csv = fs::read_to_string(dir.path().join("links.csv")).unwrap();
+        assert!(links_csv.contains("impl~export~1,outgoing,req~export~1,covers\n"));
+        assert!(links_csv.contains("req~export~1,incoming,impl~export~1,covered shallow\n"));
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use super::messages::Messages;
+use crate::config::ReportLanguage;
+use crate::core::{Defect, SpecificationItemId, TraceDiff};
+use crate::Result;
+
+/// Renders the changes between two traces as Markdown
+///
+/// Lists new and fixed defects, added and removed items and the coverage
+/// changes per artifact type, so that a review of a change only has to look
+/// at what it altered. The output can be posted as a pull request comment.
+pub struct DeltaReporter {
+    messages: &'static Messages,
+}
+
+impl DeltaReporter {
+    pub fn new() -> Self {
+        Self {
+            messages: Messages::for_language(ReportLanguage::default()),
+        }
+    }
+
+    /// Write the headings and labels in the given language
+    pub fn with_language(mut self, language: ReportLanguage) -> Self {
+        self.messages = Messages::for_language(language);
+        self
+    }
+
+    /// Render the delta report
+    pub fn render(&self, diff: &TraceDiff) -> String {
+        let messages = self.messages;
+        let mut report = format!("# {}\n\n", messages.delta_title);
+        if diff.is_empty() {
+            let _ = writeln!(report, "{}", messages.no_changes);
+            self.write_metadata(&mut report, diff);
+            return report;
+        }
+
+        let _ = writeln!(
+            report,
+            "{} {:+.1}%, {} {}, {} {}, {} {}, {} {}",
+            messages.coverage,
+            diff.coverage_change,
+            diff.new_defects.len(),
+            messages.new_defect_count,
+            diff.fixed_defects.len(),
+            messages.fixed_defect_count,
+            diff.added_items.len(),
+            messages.added_item_count,
+            diff.removed_items.len(),
+            messages.removed_item_count
+        );
+
+        Self::write_defects(&mut report, messages.new_defects, &diff.new_defects);
+        Self::write_defects(&mut report, messages.fixed_defects, &diff.fixed_defects);
+        Self::write_items(&mut report, messages.added_items, &diff.added_items);
+        Self::write_items(&mut report, messages.removed_items, &diff.removed_items);
+
+        if !diff.coverage_changes.is_empty() {
+            let _ = writeln!(report, "\n## {}\n", messages.coverage);
+            let _ = writeln!(
+                report,
+                "| {} | {} | {} | {} |",
+                messages.artifact_type, messages.before, messages.after, messages.change
+            );
+            report.push_str("|---|---|---|---|\n");
+            for change in &diff.coverage_changes {
+                let _ = writeln!(
+                    report,
+                    "| {} | {}/{} | {}/{} | {:+.1}% |",
+                    change.artifact_type,
+                    change.previous.0,
+                    change.previous.1,
+                    change.current.0,
+                    change.current.1,
+                    change.percentage_change
+                );
+            }
+        }
+        self.write_metadata(&mut report, diff);
+        report
+    }
+
+    /// Write the delta report to a file
+    pub fn write(&self, diff: &TraceDiff, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(diff))?;
+        Ok(())
+    }
+
+    fn write_defects(report: &mut String, heading: &str, defects: &[Defect]) {
+        if defects.is_empty() {
+            return;
+        }
+        let _ = writeln!(report, "\n## {}\n", heading);
+        for defect in defects {
+            let _ = writeln!(
+                report,
+                "- **{}**: {}",
+                defect.defect_type, defect.description
+            );
+        }
+    }
+
+    fn write_metadata(&self, report: &mut String, diff: &TraceDiff) {
+        let metadata = &diff.metadata;
+        if metadata.is_empty() {
+            return;
+        }
+        let _ = writeln!(
+            report,
+            "\n---\n\n_{} ovft {} {} {}, {} {}, {} sha256:{}_",
+            self.messages.generated_by,
+            metadata.tool_version,
+            self.messages.generated_at,
+            metadata.generated_at,
+            self.messages.revision,
+            metadata
+                .revision
+                .as_deref()
+                .unwrap_or(self.messages.unknown),
+            self.messages.configuration,
+            metadata.config_digest
+        );
+    }
+
+    fn write_items(report: &mut String, heading: &str, items: &[SpecificationItemId]) {
+        if items.is_empty() {
+            return;
+        }
+        let _ = writeln!(report, "\n## {}\n", heading);
+        for id in items {
+            let _ = writeln!(report, "- `{}`", id);
+        }
+    }
+}
+
+impl Default for DeltaReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CoverageChange, DefectType, ReportMetadata};
+
+    #[test]
+    fn test_delta_sections() {
+        let diff = TraceDiff {
+            new_defects: vec![Defect {
+                defect_type: DefectType::UncoveredItem,
+                description: "Missing coverage: impl".to_string(),
+                item_id: None,
+                location: None,
+            }],
+            fixed_defects: vec![],
+            added_items: vec![SpecificationItemId::new(
+                "req".to_string(),
+                "delta".to_string(),
+                1,
+            )],
+            removed_items: vec![],
+            coverage_changes: vec![CoverageChange {
+                artifact_type: "req".to_string(),
+                previous: (2, 2),
+                current: (2, 3),
+                percentage_change: -33.3,
+            }],
+            coverage_change: -33.3,
+            metadata: ReportMetadata {
+                tool_version: "1.0.0".to_string(),
+                generated_at: "2024-05-17T08:30:00Z".to_string(),
+                revision: None,
+                config_digest: "abc".to_string(),
+            },
+        };
+
+        let report = DeltaReporter::new().render(&diff);
+        assert!(report.contains("Coverage -33.3%, 1 new defect(s), 0 fixed defect(s)"));
+        assert!(report.contains("## New Defects\n\n- **uncovered**: Missing coverage: impl\n"));
+        assert!(report.contains("## Added Items\n\n- `req~delta~1`\n"));
+        assert!(report.contains("| req | 2/2 | 2/3 | -33.3% |\n"));
+        assert!(!report.contains("Fixed Defects"));
+        assert!(report.ends_with(
+            "\n---\n\n_Generated by ovft 1.0.0 at 2024-05-17T08:30:00Z, revision unknown, \
+             configuration sha256:abc_\n"
+        ));
+    }
+}
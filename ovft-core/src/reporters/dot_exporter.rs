@@ -0,0 +1,313 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::core::{
+    CoverageStatus, LinkStatus, LinkedSpecificationItem, SpecificationItemId, TraceResult,
+};
+use crate::{Error, Result};
+
+/// Exports the trace as a Graphviz DOT graph of items and their links
+///
+/// Nodes are colored by the coverage status of the item, coverage edges by
+/// their link status; dependencies are drawn dashed. The graph can be limited
+/// to the items of one artifact type or to the coverage tree below a root
+/// item, plus the items directly linked to them.
+pub struct DotExporter {
+    artifact_type: Option<String>,
+    root: Option<SpecificationItemId>,
+}
+
+impl DotExporter {
+    pub fn new() -> Self {
+        Self {
+            artifact_type: None,
+            root: None,
+        }
+    }
+
+    /// Only show items of the given artifact type and the items linked to them
+    pub fn with_artifact_type(mut self, artifact_type: String) -> Self {
+        self.artifact_type = Some(artifact_type);
+        self
+    }
+
+    /// Only show the given item and the items covering it, transitively
+    pub fn with_root(mut self, root: SpecificationItemId) -> Self {
+        self.root = Some(root);
+        self
+    }
+
+    /// Write the graph to a DOT file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result)?)?;
+        Ok(())
+    }
+
+    /// Render the graph as a DOT document
+    pub fn render(&self, trace_result: &TraceResult) -> Result<String> {
+        let mut items: Vec<_> = trace_result.items.iter().collect();
+        items.sort_by_key(|item| item.id().to_string());
+        let mut by_id: HashMap<&SpecificationItemId, &LinkedSpecificationItem> = HashMap::new();
+        for item in &items {
+            by_id.entry(item.id()).or_insert(item);
+        }
+
+        let mut selected: HashSet<&SpecificationItemId> = match &self.root {
+            Some(root) => {
+                if !by_id.contains_key(root) {
+                    return Err(Error::Config(format!(
+                        "Root item {} not found in the trace",
+                        root
+                    )));
+                }
+                Self::coverage_tree(root, &by_id)
+            }
+            None => items.iter().map(|item| item.id()).collect(),
+        };
+        if let Some(artifact_type) = &self.artifact_type {
+            selected.retain(|id| &id.artifact_type == artifact_type);
+        }
+
+        // Keyed by the ID string, so that the output is stable
+        let mut nodes = BTreeMap::new();
+        let mut edges = Vec::new();
+        for item in items.iter().filter(|item| selected.contains(item.id())) {
+            nodes.insert(item.id().to_string(), item.id());
+            for link in &item.outgoing_links {
+                if matches!(link.status, LinkStatus::BrokenDependency) {
+                    continue;
+                }
+                nodes.insert(link.target_id.to_string(), &link.target_id);
+                edges.push(Self::coverage_edge(
+                    item.id(),
+                    &link.target_id,
+                    &link.status,
+                ));
+            }
+            // Coverage from items outside the selection is only known to the covered item
+            for link in &item.incoming_links {
+                let Some(source) = &link.source_id else {
+                    continue;
+                };
+                if !selected.contains(source) {
+                    nodes.insert(source.to_string(), source);
+                    edges.push(Self::coverage_edge(source, item.id(), &link.status));
+                }
+            }
+            for dependency in &item.item.depends {
+                let broken = item.outgoing_links.iter().any(|link| {
+                    matches!(link.status, LinkStatus::BrokenDependency)
+                        && &link.target_id == dependency
+                });
+                nodes.insert(dependency.to_string(), dependency);
+                edges.push(format!(
+                    "  \"{}\" -> \"{}\" [style=dashed, color={}{}];",
+                    Self::escape(&item.id().to_string()),
+                    Self::escape(&dependency.to_string()),
+                    if broken { "red" } else { "gray40" },
+                    if broken {
+                        ", label=\"broken dependency\""
+                    } else {
+                        ""
+                    }
+                ));
+            }
+        }
+
+        let mut dot = String::from("digraph trace {\n");
+        if !trace_result.metadata.is_empty() {
+            dot.push_str(&format!("  // {}\n", trace_result.metadata.summary()));
+        }
+        dot.push_str("  rankdir=BT;\n");
+        dot.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+        dot.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+        for id in nodes.into_values() {
+            Self::write_node(&mut dot, id, by_id.get(id).copied());
+        }
+        for edge in edges {
+            dot.push_str(&edge);
+            dot.push('\n');
+        }
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// The root item and all items covering it, directly or indirectly
+    fn coverage_tree<'a>(
+        root: &'a SpecificationItemId,
+        by_id: &HashMap<&'a SpecificationItemId, &'a LinkedSpecificationItem>,
+    ) -> HashSet<&'a SpecificationItemId> {
+        let mut tree = HashSet::new();
+        let mut pending = vec![root];
+        while let Some(id) = pending.pop() {
+            if !tree.insert(id) {
+                continue;
+            }
+            if let Some(item) = by_id.get(id) {
+                pending.extend(
+                    item.incoming_links
+                        .iter()
+                        .filter_map(|link| link.source_id.as_ref()),
+                );
+            }
+        }
+        tree
+    }
+
+    fn write_node(
+        dot: &mut String,
+        id: &SpecificationItemId,
+        item: Option<&LinkedSpecificationItem>,
+    ) {
+        let id_str = Self::escape(&id.to_string());
+        let Some(item) = item else {
+            // Linked to, but not defined anywhere
+            let _ = writeln!(
+                dot,
+                "  \"{}\" [style=\"rounded,dashed\", color=red, fontcolor=red];",
+                id_str
+            );
+            return;
+        };
+
+        let label = match &item.item.title {
+            Some(title) => format!("{}\\n{}", id_str, Self::escape(title)),
+            None => id_str.clone(),
+        };
+        let color = match item.coverage_status {
+            CoverageStatus::Covered => "palegreen",
+            CoverageStatus::Partial => "khaki",
+            CoverageStatus::Uncovered => "lightcoral",
+        };
+        let _ = writeln!(
+            dot,
+            "  \"{}\" [label=\"{}\", fillcolor={}{}];",
+            id_str,
+            label,
+            color,
+            if item.is_defect { ", penwidth=2" } else { "" }
+        );
+    }
+
+    fn coverage_edge(
+        source: &SpecificationItemId,
+        target: &SpecificationItemId,
+        status: &LinkStatus,
+    ) -> String {
+        let (color, label) = match status {
+            LinkStatus::Covers | LinkStatus::CoveredShallow => ("darkgreen", None),
+            LinkStatus::Outdated
+            | LinkStatus::Predated
+            | LinkStatus::CoveredOutdated
+            | LinkStatus::CoveredPredated => ("orange", Some(status)),
+            _ => ("red", Some(status)),
+        };
+        let mut edge = format!(
+            "  \"{}\" -> \"{}\" [color={}",
+            Self::escape(&source.to_string()),
+            Self::escape(&target.to_string()),
+            color
+        );
+        if let Some(status) = label {
+            let _ = write!(edge, ", label=\"{}\"", status);
+        }
+        edge.push_str("];");
+        edge
+    }
+
+    fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
+
+impl Default for DotExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+
+    fn trace_result() -> TraceResult {
+        let feat = SpecificationItemId::new("feat".to_string(), "export".to_string(), 1);
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(feat.clone())
+                .title("Export".to_string())
+                .needs("req".to_string())
+                .build(),
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .covers(feat)
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "req".to_string(),
+                "import".to_string(),
+                1,
+            ))
+            .needs("impl".to_string())
+            .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(SpecificationItemId::new(
+                "req".to_string(),
+                "export".to_string(),
+                2,
+            ))
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        TraceResult::from_items(linked)
+    }
+
+    #[test]
+    fn test_nodes_and_edges_are_colored() {
+        let dot = DotExporter::new().render(&trace_result()).unwrap();
+        assert!(dot.starts_with("digraph trace {\n"));
+        assert!(dot.contains(
+            "\"feat~export~1\" [label=\"feat~export~1\\nExport\", fillcolor=palegreen];"
+        ));
+        assert!(dot.contains("\"req~export~1\" -> \"feat~export~1\" [color=darkgreen];"));
+        assert!(dot
+            .contains("\"impl~export~1\" -> \"req~export~2\" [color=orange, label=\"predated\"];"));
+        assert!(dot.contains("\"req~export~2\" [style=\"rounded,dashed\", color=red"));
+        assert!(dot.contains("\"req~import~1\" [label=\"req~import~1\", fillcolor=lightcoral"));
+    }
+
+    #[test]
+    fn test_restrict_to_root_and_artifact_type() {
+        let trace_result = trace_result();
+        let root = SpecificationItemId::new("feat".to_string(), "export".to_string(), 1);
+        let dot = DotExporter::new()
+            .with_root(root)
+            .render(&trace_result)
+            .unwrap();
+        assert!(dot.contains("\"req~export~1\" -> \"feat~export~1\""));
+        assert!(!dot.contains("req~import~1"));
+
+        let dot = DotExporter::new()
+            .with_artifact_type("feat".to_string())
+            .render(&trace_result)
+            .unwrap();
+        assert!(dot.contains("\"req~export~1\" -> \"feat~export~1\""));
+        assert!(!dot.contains("req~import~1"));
+        assert!(!dot.contains("impl~export~1"));
+
+        let unknown = SpecificationItemId::new("feat".to_string(), "unknown".to_string(), 1);
+        assert!(DotExporter::new()
+            .with_root(unknown)
+            .render(&trace_result)
+            .is_err());
+    }
+}
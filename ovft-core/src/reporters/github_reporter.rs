@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::Path;
+
+use crate::core::{Defect, TraceResult};
+use crate::Result;
+
+/// Renders defects as GitHub Actions workflow commands
+///
+/// Each defect becomes an `::error` and each warning a `::warning` line with
+/// the file and line of the defect, so that GitHub shows them inline
+/// on the changed files of a pull request.
+pub struct GithubReporter;
+
+impl GithubReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render one workflow command per defect and warning
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let mut output = String::new();
+        if !trace_result.metadata.is_empty() {
+            output.push_str(&format!("::debug::{}\n", trace_result.metadata.summary()));
+        }
+        let defects = trace_result.defects.iter().map(|defect| ("error", defect));
+        let warnings = trace_result
+            .warnings
+            .iter()
+            .map(|warning| ("warning", warning));
+        for (level, defect) in defects.chain(warnings) {
+            output.push_str(&Self::command(level, defect));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Write the workflow commands to a file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result))?;
+        Ok(())
+    }
+
+    fn command(level: &str, defect: &Defect) -> String {
+        let mut properties = Vec::new();
+        if let Some(location) = &defect.location {
+            let path = location.path.to_string_lossy().replace('\\', "/");
+            let path = path.trim_start_matches("./");
+            properties.push(format!("file={}", Self::escape_property(path)));
+            properties.push(format!("line={}", location.line));
+        }
+        let title = match &defect.item_id {
+            Some(id) => format!("{}: {}", defect.defect_type, id),
+            None => defect.defect_type.to_string(),
+        };
+        properties.push(format!("title={}", Self::escape_property(&title)));
+
+        format!(
+            "::{} {}::{}",
+            level,
+            properties.join(","),
+            Self::escape_data(&defect.description)
+        )
+    }
+
+    fn escape_data(value: &str) -> String {
+        value
+            .replace('%', "%25")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    fn escape_property(value: &str) -> String {
+        Self::escape_data(value)
+            .replace(':', "%3A")
+            .replace(',', "%2C")
+    }
+}
+
+impl Default for GithubReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DefectType, Linker, Location, SpecificationItem, SpecificationItemId};
+
+    #[test]
+    fn test_defects_become_workflow_commands() {
+        let req = SpecificationItemId::new("req".to_string(), "annotate".to_string(), 1);
+        let items = vec![SpecificationItem::builder(req.clone())
+            .needs("impl".to_string())
+            .location(Location::new("./docs/spec.md".into(), 7))
+            .build()];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult {
+            defect_count: 1,
+            defects: vec![Defect {
+                defect_type: DefectType::UncoveredItem,
+                description: "Missing coverage: impl\n100% needed".to_string(),
+                item_id: Some(req),
+                location: Some(Location::new("./docs/spec.md".into(), 7)),
+            }],
+            warnings: vec![Defect {
+                defect_type: DefectType::BrokenReference,
+                description: "Unknown item".to_string(),
+                item_id: None,
+                location: None,
+            }],
+            is_success: false,
+            ..TraceResult::from_items(linked)
+        };
+
+        let output = GithubReporter::new().render(&trace_result);
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(
+            lines[0],
+            "::error file=docs/spec.md,line=7,title=uncovered%3A req~annotate~1\
+             ::Missing coverage: impl%0A100%25 needed"
+        );
+        assert!(lines[1].starts_with("::warning title="));
+        assert!(lines[1].ends_with("::Unknown item"));
+    }
+}
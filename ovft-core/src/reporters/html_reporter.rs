@@ -1,20 +1,135 @@
 use askama::Template;
+use git2::Repository;
+use handlebars::Handlebars;
 use pulldown_cmark::{html, Options, Parser};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
-use crate::config::Config;
-use crate::core::TraceResult;
-use crate::Result;
+use super::json_exporter::{JsonExport, JsonExporter};
+use super::messages::Messages;
+use crate::config::{Config, ReportSplit};
+use crate::core::{
+    CoverageStatus, DeepCoverage, Link, LinkStatus, LinkedSpecificationItem, Location,
+    SpecificationItemId, TraceResult,
+};
+use crate::{Error, Result};
+
+/// Width of the label column of the summary charts, in SVG units
+const CHART_LABEL_WIDTH: f64 = 190.0;
+/// Width of a full bar of the summary charts, in SVG units
+const CHART_BAR_WIDTH: f64 = 200.0;
+/// Height of a bar row of the summary charts, in SVG units
+const CHART_ROW_HEIGHT: usize = 24;
 
 /// HTML reporter that generates OpenFastTrace-compatible HTML reports
+///
+/// A configured template directory may override the embedded stylesheet with
+/// a `report.css` and the embedded template with a handlebars `report.hbs`;
+/// the other `.hbs` files in it are registered as partials.
+///
+/// The embedded template can also be split into an index page with the
+/// overview and one page per artifact type or document, for projects whose
+/// single report would be too large for a browser.
 /// [impl->dsn~html-reporter-module~1]
-pub struct HtmlReporter;
+pub struct HtmlReporter {
+    template_dir: Option<PathBuf>,
+    source_links: Option<SourceLinks>,
+    split: ReportSplit,
+    messages: &'static Messages,
+}
+
+/// Builds links from report locations to the source, e.g. on a code hosting site
+struct SourceLinks {
+    pattern: String,
+    rev: String,
+    /// Root of the repository, which link paths are relative to
+    root: Option<PathBuf>,
+}
+
+impl SourceLinks {
+    fn new(pattern: &str, rev: Option<&str>) -> Self {
+        let repository = env::current_dir()
+            .ok()
+            .and_then(|dir| Repository::discover(dir).ok());
+        let root = repository
+            .as_ref()
+            .and_then(|repo| repo.workdir())
+            .and_then(|dir| dir.canonicalize().ok());
+        let rev = match rev {
+            Some(rev) => rev.to_string(),
+            None => repository
+                .as_ref()
+                .and_then(|repo| repo.head().ok()?.peel_to_commit().ok())
+                .map(|commit| commit.id().to_string())
+                .unwrap_or_else(|| "HEAD".to_string()),
+        };
+        Self {
+            pattern: pattern.to_string(),
+            rev,
+            root,
+        }
+    }
+
+    fn url(&self, location: &Location) -> String {
+        let path = match (&self.root, location.path.canonicalize()) {
+            (Some(root), Ok(absolute)) => absolute
+                .strip_prefix(root)
+                .map(Path::to_path_buf)
+                .unwrap_or(absolute),
+            _ => location.path.clone(),
+        };
+        let path = path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => Some(Self::encode(&part.to_string_lossy())),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        self.pattern
+            .replace("{rev}", &self.rev)
+            .replace("{path}", &path)
+            .replace("{line}", &location.line.to_string())
+    }
+
+    /// Percent-encode a path segment
+    fn encode(segment: &str) -> String {
+        segment
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+}
+
+/// Data passed to a custom report template: the JSON export plus the stylesheet
+#[derive(Serialize)]
+struct CustomTemplateContext<'a> {
+    #[serde(flatten)]
+    trace: JsonExport<'a>,
+    css: &'a str,
+}
 
 impl HtmlReporter {
     /// Create a new HTML reporter
-    pub fn new(_config: &Config) -> Self {
-        Self
+    pub fn new(config: &Config) -> Self {
+        Self {
+            template_dir: config.report_template_dir.clone(),
+            source_links: config
+                .source_link_base
+                .as_deref()
+                .map(|pattern| SourceLinks::new(pattern, config.source_link_rev.as_deref())),
+            split: config.report_split,
+            messages: Messages::for_language(config.report_language),
+        }
     }
 
     /// Generate an HTML report for the trace result
@@ -22,31 +137,209 @@ impl HtmlReporter {
     pub fn generate_report(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
         // Convert markdown descriptions to HTML
         let processed_trace_result = self.process_markdown_content(trace_result);
-        
-        let template = HtmlReportTemplate {
-            trace_result: &processed_trace_result,
-            css: include_str!("../assets/report.css"),
+        let template_dir = self.template_dir.as_deref().filter(|dir| {
+            let exists = dir.is_dir();
+            if !exists {
+                log::warn!(
+                    "Report template directory {} not found, using the embedded template",
+                    dir.display()
+                );
+            }
+            exists
+        });
+        let css = match template_dir.map(|dir| dir.join("report.css")) {
+            Some(path) if path.is_file() => fs::read_to_string(path)?,
+            _ => include_str!("../assets/report.css").to_string(),
         };
 
-        let html = template.render()?;
-
         // Ensure output directory exists
         if let Some(parent) = output_path.parent() {
             fs::create_dir_all(parent)?;
         }
 
+        let html = match template_dir.map(|dir| dir.join("report.hbs")) {
+            Some(path) if path.is_file() => {
+                Self::render_custom(&path, &processed_trace_result, &css)?
+            }
+            _ if self.split != ReportSplit::None => {
+                return self.write_pages(&processed_trace_result, &css, output_path);
+            }
+            _ => {
+                let items: Vec<_> = processed_trace_result.items.iter().collect();
+                self.template(&processed_trace_result, items, &css).render()?
+            }
+        };
+
         fs::write(output_path, html)?;
         Ok(())
     }
+
+    /// The report template showing the overview and the given items
+    fn template<'a>(
+        &'a self,
+        trace_result: &'a TraceResult,
+        items: Vec<&'a LinkedSpecificationItem>,
+        css: &'a str,
+    ) -> HtmlReportTemplate<'a> {
+        let (matrix_types, matrix) = Self::traceability_matrix(&items);
+        let deep_coverage = Self::deep_coverage_tables(trace_result, &items);
+        let coverage_tree = Self::coverage_tree(trace_result, &items);
+        HtmlReportTemplate {
+            trace_result,
+            items,
+            charts: Self::summary_charts(trace_result, self.messages),
+            matrix_types,
+            matrix,
+            deep_coverage,
+            coverage_tree,
+            page: None,
+            pages: vec![],
+            items_script: None,
+            source_links: self.source_links.as_ref(),
+            messages: self.messages,
+            css,
+        }
+    }
+
+    /// Write the index page, one page per group of items, and a script
+    /// mapping item IDs to their pages, which resolves links across pages
+    fn write_pages(&self, trace_result: &TraceResult, css: &str, output_path: &Path) -> Result<()> {
+        let mut groups: BTreeMap<String, Vec<&LinkedSpecificationItem>> = BTreeMap::new();
+        for item in &trace_result.items {
+            let group = match self.split {
+                ReportSplit::Document => item
+                    .item
+                    .location
+                    .as_ref()
+                    .map(|location| location.path.display().to_string())
+                    .unwrap_or_else(|| "Without location".to_string()),
+                _ => item.item.id.artifact_type.clone(),
+            };
+            groups.entry(group).or_default().push(item);
+        }
+
+        let stem = output_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| "report".to_string());
+        let index_file = output_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| format!("{}.html", stem));
+        let items_script = format!("{}.items.js", stem);
+
+        let mut used_files = HashSet::new();
+        let mut pages = Vec::new();
+        let mut item_pages = BTreeMap::new();
+        for (title, items) in groups {
+            let mut file = format!("{}.{}.html", stem, Self::file_name(&title));
+            if !used_files.insert(file.clone()) {
+                file = format!("{}.{}-{}.html", stem, Self::file_name(&title), pages.len());
+                used_files.insert(file.clone());
+            }
+            for item in &items {
+                item_pages.insert(
+                    item.item.id.to_string(),
+                    format!("{}#{}", file, item.item.id.to_html_id()),
+                );
+            }
+            pages.push((
+                PageLink {
+                    defects: items.iter().filter(|item| item.is_defect).count(),
+                    items: items.len(),
+                    title,
+                    file,
+                },
+                items,
+            ));
+        }
+
+        let write = |file: &str, html: String| match output_path.parent() {
+            Some(dir) => fs::write(dir.join(file), html),
+            None => fs::write(file, html),
+        };
+        write(
+            &items_script,
+            format!("const itemPages = {};\n", serde_json::to_string(&item_pages)?),
+        )?;
+
+        for (link, items) in &pages {
+            let mut template = self.template(trace_result, items.clone(), css);
+            template.page = Some(PageHeader {
+                title: link.title.clone(),
+                index_file: index_file.clone(),
+            });
+            template.items_script = Some(items_script.clone());
+            write(&link.file, template.render()?)?;
+        }
+
+        let mut index = self.template(trace_result, vec![], css);
+        index.pages = pages.into_iter().map(|(link, _)| link).collect();
+        index.items_script = Some(items_script);
+        fs::write(output_path, index.render()?)?;
+        Ok(())
+    }
+
+    /// File name part for a page title, e.g. `docs-spec-md` for `docs/spec.md`
+    fn file_name(title: &str) -> String {
+        title
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '-' })
+            .collect::<String>()
+            .trim_matches('-')
+            .to_string()
+    }
     
+    /// Render a handlebars report template, with the other templates of its
+    /// directory as partials
+    fn render_custom(template: &Path, trace_result: &TraceResult, css: &str) -> Result<String> {
+        let template_error = |e: &dyn std::fmt::Display| {
+            Error::CustomTemplate(format!("{}: {}", template.display(), e))
+        };
+
+        let mut handlebars = Handlebars::new();
+        handlebars.set_strict_mode(true);
+        if let Some(dir) = template.parent() {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path == template || path.extension().is_none_or(|ext| ext != "hbs") {
+                    continue;
+                }
+                let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+                handlebars
+                    .register_partial(name, fs::read_to_string(&path)?)
+                    .map_err(|e| template_error(&e))?;
+            }
+        }
+        handlebars
+            .register_template_string("report", fs::read_to_string(template)?)
+            .map_err(|e| template_error(&e))?;
+
+        let context = CustomTemplateContext {
+            trace: JsonExporter::new().export(trace_result),
+            css,
+        };
+        handlebars
+            .render("report", &context)
+            .map_err(|e| template_error(&e))
+    }
+
     /// Process markdown content in descriptions and convert to HTML
     fn process_markdown_content(&self, trace_result: &TraceResult) -> TraceResult {
         let processed_items = trace_result.items.iter().map(|linked_item| {
             let mut processed_item = linked_item.clone();
             
-            // Convert markdown in description to HTML
-            if let Some(ref description) = processed_item.item.description {
-                processed_item.item.description = Some(self.markdown_to_html(description));
+            // Convert markdown in description, rationale and comment to HTML
+            for text in [
+                &mut processed_item.item.description,
+                &mut processed_item.item.rationale,
+                &mut processed_item.item.comment,
+            ] {
+                if let Some(markdown) = text.as_deref() {
+                    *text = Some(self.markdown_to_html(markdown));
+                }
             }
             
             processed_item
@@ -71,11 +364,244 @@ impl HtmlReporter {
             total_items: trace_result.total_items,
             defect_count: trace_result.defect_count,
             defects: trace_result.defects.clone(),
+            warnings: trace_result.warnings.clone(),
             coverage_summary: trace_result.coverage_summary.clone(),
+            gate_results: trace_result.gate_results.clone(),
+            release_verdict: trace_result.release_verdict.clone(),
+            implementation_metrics: trace_result.implementation_metrics.clone(),
+            metadata: trace_result.metadata.clone(),
             is_success: trace_result.is_success,
         }
     }
     
+    /// Build the traceability matrix: one row per item that needs coverage and
+    /// one column per needed artifact type
+    fn traceability_matrix<'a>(
+        items: &[&'a LinkedSpecificationItem],
+    ) -> (Vec<String>, Vec<MatrixRow<'a>>) {
+        let needed_types: BTreeSet<&String> =
+            items.iter().flat_map(|item| item.item.needs.iter()).collect();
+
+        let mut rows: Vec<_> = items
+            .iter()
+            .copied()
+            .filter(|item| !item.item.needs.is_empty())
+            .map(|item| MatrixRow {
+                item,
+                cells: needed_types
+                    .iter()
+                    .map(|needed_type| Self::matrix_cell(item, needed_type))
+                    .collect(),
+            })
+            .collect();
+        rows.sort_by_key(|row| row.item.item.id.to_string());
+
+        (needed_types.into_iter().cloned().collect(), rows)
+    }
+
+    fn matrix_cell<'a>(item: &'a LinkedSpecificationItem, needed_type: &str) -> MatrixCell<'a> {
+        if !item.item.needs.iter().any(|needed| needed == needed_type) {
+            return MatrixCell::NotNeeded;
+        }
+        let links: Vec<_> = item
+            .incoming_links
+            .iter()
+            .filter_map(|link| link.source_id.as_ref().map(|source| (source, &link.status)))
+            .filter(|(source, _)| item.satisfies_need(needed_type, &source.artifact_type))
+            .map(|(source, status)| (source, Self::link_class(status)))
+            .collect();
+
+        if links.is_empty() {
+            MatrixCell::Missing
+        } else if links.iter().all(|(_, class)| *class == "link-valid") {
+            MatrixCell::Covered(links)
+        } else {
+            MatrixCell::Partial(links)
+        }
+    }
+
+    /// Build one deep coverage table per artifact type of the items that need
+    /// coverage, with the direct and transitive coverage per needed type
+    fn deep_coverage_tables<'a>(
+        trace_result: &'a TraceResult,
+        items: &[&'a LinkedSpecificationItem],
+    ) -> Vec<DeepCoverageTable<'a>> {
+        let deep_coverage = DeepCoverage::new(&trace_result.items);
+        let mut by_type: BTreeMap<&str, Vec<&LinkedSpecificationItem>> = BTreeMap::new();
+        for item in items.iter().filter(|item| !item.item.needs.is_empty()) {
+            by_type
+                .entry(item.item.id.artifact_type.as_str())
+                .or_default()
+                .push(item);
+        }
+
+        by_type
+            .into_iter()
+            .map(|(artifact_type, mut type_items)| {
+                type_items.sort_by_key(|item| item.item.id.to_string());
+                let needed_types: BTreeSet<&str> = type_items
+                    .iter()
+                    .flat_map(|item| item.item.needs.iter().map(String::as_str))
+                    .collect();
+                let rows = type_items
+                    .into_iter()
+                    .map(|item| DeepCoverageRow {
+                        item,
+                        cells: needed_types
+                            .iter()
+                            .map(|needed_type| {
+                                Self::deep_coverage_cell(&deep_coverage, item, needed_type)
+                            })
+                            .collect(),
+                        deep: deep_coverage.is_deeply_covered(&item.item.id),
+                    })
+                    .collect();
+                DeepCoverageTable {
+                    artifact_type,
+                    needed_types: needed_types.into_iter().collect(),
+                    rows,
+                }
+            })
+            .collect()
+    }
+
+    fn deep_coverage_cell(
+        deep_coverage: &DeepCoverage,
+        item: &LinkedSpecificationItem,
+        needed_type: &str,
+    ) -> Option<DeepCoverageCell> {
+        let direct = match Self::matrix_cell(item, needed_type) {
+            MatrixCell::NotNeeded => return None,
+            MatrixCell::Covered(_) => true,
+            MatrixCell::Missing | MatrixCell::Partial(_) => false,
+        };
+        Some(DeepCoverageCell {
+            direct,
+            deep: direct && deep_coverage.is_deeply_covered_by(&item.item.id, needed_type),
+        })
+    }
+
+    /// Flatten the coverage tree of the given top-level items, i.e. items that
+    /// need coverage but cover nothing themselves, in depth-first order
+    fn coverage_tree<'a>(
+        trace_result: &'a TraceResult,
+        items: &[&'a LinkedSpecificationItem],
+    ) -> Vec<TreeNode<'a>> {
+        let by_id: HashMap<&SpecificationItemId, &LinkedSpecificationItem> = trace_result
+            .items
+            .iter()
+            .map(|item| (&item.item.id, item))
+            .collect();
+        let mut roots: Vec<_> = items
+            .iter()
+            .copied()
+            .filter(|item| !item.item.needs.is_empty() && item.outgoing_links.is_empty())
+            .collect();
+        roots.sort_by_key(|item| item.item.id.to_string());
+
+        let mut nodes = Vec::new();
+        for root in roots {
+            Self::push_tree_node(root, "", &by_id, &mut Vec::new(), &mut nodes);
+        }
+        nodes
+    }
+
+    fn push_tree_node<'a>(
+        item: &'a LinkedSpecificationItem,
+        link_class: &'static str,
+        by_id: &HashMap<&SpecificationItemId, &'a LinkedSpecificationItem>,
+        path: &mut Vec<&'a SpecificationItemId>,
+        nodes: &mut Vec<TreeNode<'a>>,
+    ) {
+        let mut children: Vec<_> = item
+            .incoming_links
+            .iter()
+            .filter_map(|link| {
+                let source = by_id.get(link.source_id.as_ref()?)?;
+                Some((*source, Self::link_class(&link.status)))
+            })
+            // Circular coverage would never end
+            .filter(|(source, _)| !path.contains(&&source.item.id))
+            .collect();
+        children.sort_by_key(|(source, _)| source.item.id.to_string());
+
+        nodes.push(TreeNode {
+            item,
+            link_class,
+            has_children: !children.is_empty(),
+            closes: 0,
+        });
+        if children.is_empty() {
+            return;
+        }
+
+        path.push(&item.item.id);
+        for (child, class) in children {
+            Self::push_tree_node(child, class, by_id, path, nodes);
+        }
+        path.pop();
+        if let Some(last) = nodes.last_mut() {
+            last.closes += 1;
+        }
+    }
+
+    /// Bar charts of the defects by defect type and the coverage by artifact type
+    fn summary_charts(trace_result: &TraceResult, messages: &'static Messages) -> Vec<Chart> {
+        let mut charts = Vec::new();
+
+        let mut defects: Vec<_> = trace_result
+            .defect_statistics()
+            .into_iter()
+            .map(|(defect_type, count)| (defect_type.to_string(), count))
+            .collect();
+        defects.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let max = defects.first().map(|(_, count)| *count).unwrap_or(1);
+        if !defects.is_empty() {
+            let bars = defects
+                .into_iter()
+                .map(|(defect_type, count)| {
+                    let width = count as f64 / max as f64 * CHART_BAR_WIDTH;
+                    (defect_type, width, count.to_string(), "chart-defect")
+                })
+                .collect();
+            charts.push(Chart::new(messages.defects_by_type, bars));
+        }
+
+        let coverage: BTreeMap<_, _> = trace_result.coverage_summary.iter().collect();
+        if !coverage.is_empty() {
+            let bars = coverage
+                .into_iter()
+                .map(|(artifact_type, summary)| {
+                    let class = match summary.status {
+                        CoverageStatus::Covered => "chart-covered",
+                        CoverageStatus::Partial => "chart-partial",
+                        CoverageStatus::Uncovered => "chart-uncovered",
+                    };
+                    (
+                        artifact_type.clone(),
+                        summary.percentage / 100.0 * CHART_BAR_WIDTH,
+                        format!(
+                            "{:.1}% ({}/{})",
+                            summary.percentage, summary.covered, summary.total
+                        ),
+                        class,
+                    )
+                })
+                .collect();
+            charts.push(Chart::new(messages.coverage_by_artifact_type, bars));
+        }
+        charts
+    }
+
+    /// CSS class of an incoming link in the matrix and the tree view
+    fn link_class(status: &LinkStatus) -> &'static str {
+        match status {
+            LinkStatus::CoveredShallow => "link-valid",
+            LinkStatus::CoveredOutdated | LinkStatus::CoveredPredated => "link-orphaned",
+            _ => "link-invalid",
+        }
+    }
+
     /// Convert markdown text to HTML
     fn markdown_to_html(&self, markdown: &str) -> String {
         let mut options = Options::empty();
@@ -96,13 +622,177 @@ impl HtmlReporter {
 #[template(path = "report.html")]
 struct HtmlReportTemplate<'a> {
     trace_result: &'a TraceResult,
+    /// Items listed on this page
+    items: Vec<&'a LinkedSpecificationItem>,
+    /// Summary charts shown on the overview
+    charts: Vec<Chart>,
+    matrix_types: Vec<String>,
+    matrix: Vec<MatrixRow<'a>>,
+    /// Deep coverage tables per artifact type
+    deep_coverage: Vec<DeepCoverageTable<'a>>,
+    coverage_tree: Vec<TreeNode<'a>>,
+    /// Set on the item pages of a split report
+    page: Option<PageHeader>,
+    /// Item pages linked from the index page of a split report
+    pages: Vec<PageLink>,
+    /// Script mapping item IDs to their pages in a split report
+    items_script: Option<String>,
+    source_links: Option<&'a SourceLinks>,
+    messages: &'a Messages,
     css: &'a str,
 }
 
+/// Title of an item page and the index page it belongs to
+struct PageHeader {
+    title: String,
+    index_file: String,
+}
+
+/// An item page as listed on the index page
+struct PageLink {
+    title: String,
+    file: String,
+    items: usize,
+    defects: usize,
+}
+
+impl HtmlReportTemplate<'_> {
+    /// Link to the source line of a location, if source links are configured
+    fn source_link(&self, location: &Location) -> Option<String> {
+        self.source_links.map(|links| links.url(location))
+    }
+
+    /// Link to the line declaring a link, e.g. the code tag covering an item
+    fn link_source(&self, link: &Link) -> Option<String> {
+        self.source_link(link.location.as_ref()?)
+    }
+
+    /// Status of a link and where it is declared, shown when hovering it
+    fn link_title(&self, link: &Link) -> String {
+        match &link.location {
+            Some(location) => format!("{} ({})", link.status, location),
+            None => link.status.to_string(),
+        }
+    }
+
+    /// The artifact types an item needs, with the items covering them
+    fn coverage_needs<'b>(
+        &self,
+        item: &'b LinkedSpecificationItem,
+    ) -> Vec<(&'b str, MatrixCell<'b>)> {
+        item.item
+            .needs
+            .iter()
+            .map(|needed_type| {
+                (
+                    needed_type.as_str(),
+                    HtmlReporter::matrix_cell(item, needed_type),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A horizontal bar chart rendered as inline SVG
+struct Chart {
+    title: &'static str,
+    width: f64,
+    height: usize,
+    bars: Vec<ChartBar>,
+}
+
+/// A bar of a chart with its SVG coordinates
+struct ChartBar {
+    label: String,
+    value: String,
+    class: &'static str,
+    /// Baseline of the label and value texts
+    text_y: usize,
+    bar_y: usize,
+    bar_x: f64,
+    width: f64,
+    value_x: f64,
+}
+
+impl Chart {
+    /// Lay out bars given as label, bar width, value text and CSS class
+    fn new(title: &'static str, bars: Vec<(String, f64, String, &'static str)>) -> Self {
+        let height = bars.len() * CHART_ROW_HEIGHT;
+        let bars = bars
+            .into_iter()
+            .enumerate()
+            .map(|(row, (label, width, value, class))| ChartBar {
+                label,
+                value,
+                class,
+                text_y: row * CHART_ROW_HEIGHT + 16,
+                bar_y: row * CHART_ROW_HEIGHT + 4,
+                bar_x: CHART_LABEL_WIDTH,
+                width,
+                value_x: CHART_LABEL_WIDTH + width + 6.0,
+            })
+            .collect();
+        Self {
+            title,
+            width: CHART_LABEL_WIDTH + CHART_BAR_WIDTH + 90.0,
+            height,
+            bars,
+        }
+    }
+}
+
+/// An item in the flattened coverage tree
+///
+/// Items with children open a collapsible element; `closes` is the number of
+/// elements to close after the item, as its last descendant ends them.
+struct TreeNode<'a> {
+    item: &'a LinkedSpecificationItem,
+    /// CSS class of the link to the parent item, empty for top-level items
+    link_class: &'static str,
+    has_children: bool,
+    closes: usize,
+}
+
+/// A row of the traceability matrix
+struct MatrixRow<'a> {
+    item: &'a LinkedSpecificationItem,
+    cells: Vec<MatrixCell<'a>>,
+}
+
+/// Direct and transitive coverage of the items of one artifact type
+struct DeepCoverageTable<'a> {
+    artifact_type: &'a str,
+    needed_types: Vec<&'a str>,
+    rows: Vec<DeepCoverageRow<'a>>,
+}
+
+/// A row of a deep coverage table, with a cell per needed type if the item needs it
+struct DeepCoverageRow<'a> {
+    item: &'a LinkedSpecificationItem,
+    cells: Vec<Option<DeepCoverageCell>>,
+    /// Whether the item is deeply covered by all its needed types
+    deep: bool,
+}
+
+/// Whether an item is covered by a needed type directly and down the whole chain
+struct DeepCoverageCell {
+    direct: bool,
+    deep: bool,
+}
+
+/// A cell of the traceability matrix with the covering items and their link CSS classes
+enum MatrixCell<'a> {
+    NotNeeded,
+    Missing,
+    Covered(Vec<(&'a SpecificationItemId, &'static str)>),
+    Partial(Vec<(&'a SpecificationItemId, &'static str)>),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{LinkedSpecificationItem, SpecificationItem, SpecificationItemId};
+    use crate::config::ReportLanguage;
+    use crate::core::{Linker, SpecificationItem};
     use std::collections::HashMap;
 
     #[test]
@@ -126,17 +816,21 @@ mod tests {
             .build(),
         )];
 
-        let trace_result = TraceResult {
-            items,
-            total_items: 1,
-            defect_count: 0,
-            defects: vec![],
-            coverage_summary: HashMap::new(),
-            is_success: true,
-        };
+        let trace_result = TraceResult::from_items(items);
 
         let template = HtmlReportTemplate {
             trace_result: &trace_result,
+            items: trace_result.items.iter().collect(),
+            charts: vec![],
+            matrix_types: vec![],
+            matrix: vec![],
+            deep_coverage: vec![],
+            coverage_tree: vec![],
+            page: None,
+            pages: vec![],
+            items_script: None,
+            source_links: None,
+            messages: Messages::for_language(Default::default()),
             css: "/* test css */",
         };
 
@@ -145,4 +839,358 @@ mod tests {
         assert!(template.trace_result.is_success);
         assert_eq!(template.css, "/* test css */");
     }
+
+    #[test]
+    fn test_traceability_matrix() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult {
+            defect_count: 1,
+            is_success: false,
+            ..TraceResult::from_items(items)
+        };
+
+        let items: Vec<_> = trace_result.items.iter().collect();
+        let (matrix_types, matrix) = HtmlReporter::traceability_matrix(&items);
+        assert_eq!(matrix_types, vec!["impl", "utest"]);
+        let deep_coverage = HtmlReporter::deep_coverage_tables(&trace_result, &items);
+        let html = HtmlReportTemplate {
+            trace_result: &trace_result,
+            items: trace_result.items.iter().collect(),
+            charts: vec![],
+            matrix_types,
+            matrix,
+            deep_coverage,
+            coverage_tree: vec![],
+            page: None,
+            pages: vec![],
+            items_script: None,
+            source_links: None,
+            messages: Messages::for_language(Default::default()),
+            css: "",
+        }
+        .render()
+        .unwrap();
+        assert!(html.contains("<h2>Traceability Matrix</h2>"));
+        assert!(html.contains(
+            "<td class=\"matrix-cell matrix-covered\">\
+             <span class=\"requirement-link link-valid\">impl~export~1</span></td>"
+        ));
+        assert!(html.contains("<td class=\"matrix-cell matrix-missing\">missing</td>"));
+
+        // impl~export~1 needs nothing, so req is deeply covered by impl but not by utest
+        assert!(html.contains("<h2>Deep Coverage</h2><h3>req</h3>"));
+        assert!(html.contains(
+            "<td class=\"deep-mark deep-yes\">✓</td>\
+             <td class=\"deep-mark deep-yes\">✓</td>\
+             <td class=\"deep-mark deep-no\">✗</td>\
+             <td class=\"deep-mark deep-no\">✗</td>\
+             <td class=\"deep-mark deep-no\">✗</td>"
+        ));
+    }
+
+    #[test]
+    fn test_coverage_tree() {
+        let feat = SpecificationItemId::new("feat".to_string(), "export".to_string(), 1);
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(feat.clone())
+                .needs("req".to_string())
+                .build(),
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .covers(feat)
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+
+        let items: Vec<_> = trace_result.items.iter().collect();
+        let tree = HtmlReporter::coverage_tree(&trace_result, &items);
+        let nodes: Vec<_> = tree
+            .iter()
+            .map(|node| (node.item.item.id.to_string(), node.has_children, node.closes))
+            .collect();
+        assert_eq!(
+            nodes,
+            vec![
+                ("feat~export~1".to_string(), true, 0),
+                ("req~export~1".to_string(), true, 0),
+                ("impl~export~1".to_string(), false, 2),
+            ]
+        );
+
+        let html = HtmlReportTemplate {
+            trace_result: &trace_result,
+            items: trace_result.items.iter().collect(),
+            charts: vec![],
+            matrix_types: vec![],
+            matrix: vec![],
+            deep_coverage: vec![],
+            coverage_tree: tree,
+            page: None,
+            pages: vec![],
+            items_script: None,
+            source_links: None,
+            messages: Messages::for_language(Default::default()),
+            css: "",
+        }
+        .render()
+        .unwrap();
+        assert_eq!(html.matches("<details class=\"tree-node\">").count(), 2);
+        assert_eq!(html.matches("</details>").count(), 2);
+    }
+
+    #[test]
+    fn test_custom_template_dir() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("report.css"), "body { color: navy; }").unwrap();
+        fs::write(
+            dir.path().join("report.hbs"),
+            "<style>{{css}}</style><h1>{{total_items}} items</h1>\
+             {{#each items}}{{> item}}{{/each}}",
+        )
+        .unwrap();
+        fs::write(dir.path().join("item.hbs"), "<p>{{item.id.name}}</p>").unwrap();
+
+        let trace_result = TraceResult::from_items(vec![LinkedSpecificationItem::new(
+            SpecificationItem::builder(SpecificationItemId::new(
+                "req".to_string(),
+                "themed".to_string(),
+                1,
+            ))
+            .build(),
+        )]);
+
+        let output = dir.path().join("out/report.html");
+        let config = Config::default().report_template_dir(dir.path());
+        HtmlReporter::new(&config)
+            .generate_report(&trace_result, &output)
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            "<style>body { color: navy; }</style><h1>1 items</h1><p>themed</p>"
+        );
+
+        // Without report.hbs only the stylesheet is replaced
+        fs::remove_file(dir.path().join("report.hbs")).unwrap();
+        HtmlReporter::new(&config)
+            .generate_report(&trace_result, &output)
+            .unwrap();
+        let html = fs::read_to_string(&output).unwrap();
+        assert!(html.contains("<style>body { color: navy; }</style>"));
+        assert!(html.contains("<h1>Requirements Tracing Report</h1>"));
+    }
+
+    #[test]
+    fn test_source_links() {
+        let links = SourceLinks::new(
+            "https://example.com/repo/blob/{rev}/{path}#L{line}",
+            Some("v1.0"),
+        );
+        let location = Location::new("./docs/missing spec.md".into(), 12);
+        assert_eq!(
+            links.url(&location),
+            "https://example.com/repo/blob/v1.0/docs/missing%20spec.md#L12"
+        );
+
+        let req = SpecificationItemId::new("req".to_string(), "linked".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .location(location)
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "linked".to_string(),
+                1,
+            ))
+            .covers(req)
+            .location(Location::new("src/linked.rs".into(), 3))
+            .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+        let html = HtmlReportTemplate {
+            trace_result: &trace_result,
+            items: trace_result.items.iter().collect(),
+            charts: vec![],
+            matrix_types: vec![],
+            matrix: vec![],
+            deep_coverage: vec![],
+            coverage_tree: vec![],
+            page: None,
+            pages: vec![],
+            items_script: None,
+            source_links: Some(&links),
+            messages: Messages::for_language(Default::default()),
+            css: "",
+        }
+        .render()
+        .unwrap();
+        assert!(html.contains(
+            "<a href=\"https://example.com/repo/blob/v1.0/docs/missing%20spec.md#L12\" \
+             class=\"source-link\">./docs/missing spec.md:12</a>"
+        ));
+        assert!(html.contains(
+            "<a href=\"https://example.com/repo/blob/v1.0/src/linked.rs#L3\" \
+             class=\"requirement-link link-covered shallow\" \
+             title=\"covered shallow (src/linked.rs:3)\">impl~linked~1</a>"
+        ));
+    }
+
+    #[test]
+    fn test_split_report() {
+        let req = SpecificationItemId::new("req".to_string(), "split".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "split".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(items);
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = Config::default().report_split(ReportSplit::ArtifactType);
+        HtmlReporter::new(&config)
+            .generate_report(&trace_result, &dir.path().join("report.html"))
+            .unwrap();
+
+        let index = fs::read_to_string(dir.path().join("report.html")).unwrap();
+        assert!(index.contains("<a href=\"report.req.html\">req</a>"));
+        assert!(!index.contains("class=\"spec-item\""));
+        let page = fs::read_to_string(dir.path().join("report.impl.html")).unwrap();
+        assert!(page.contains("data-req-id=\"impl~split~1\""));
+        assert!(!page.contains("data-req-id=\"req~split~1\""));
+        let script = fs::read_to_string(dir.path().join("report.items.js")).unwrap();
+        assert!(script.contains("\"req~split~1\":\"report.req.html#"));
+    }
+
+    #[test]
+    fn test_item_card_sections() {
+        let req = SpecificationItemId::new("req".to_string(), "card".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .rationale("Auditors *need* it".to_string())
+                .comment("See `RFC 1`".to_string())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "card".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "dsn".to_string(),
+                "card".to_string(),
+                1,
+            ))
+            .covers(SpecificationItemId::new("req".to_string(), "card".to_string(), 2))
+            .build(),
+        ];
+        let items = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult {
+            defect_count: 1,
+            is_success: false,
+            ..TraceResult::from_items(items)
+        };
+
+        let reporter = HtmlReporter::new(&Config::default());
+        let processed = reporter.process_markdown_content(&trace_result);
+        let html = reporter
+            .template(&processed, processed.items.iter().collect(), "")
+            .render()
+            .unwrap();
+        assert!(html.contains("<p>Auditors <em>need</em> it</p>"));
+        assert!(html.contains(
+            "dsn~card~1</span> <span class=\"link-reason\">(covered predated)</span>"
+        ));
+        assert!(html.contains(
+            "<a class=\"item-permalink\" href=\"#item_req_card_1\" \
+             title=\"Copy link to this item\""
+        ));
+        assert!(html.contains("<p>See <code>RFC 1</code></p>"));
+        assert!(html.contains(
+            "<td class=\"artifact-type\">impl</td><td class=\"matrix-cell matrix-covered\">\
+             <span class=\"requirement-link link-valid\">impl~card~1</span></td>"
+        ));
+        assert!(html.contains(
+            "<td class=\"artifact-type\">utest</td>\
+             <td class=\"matrix-cell matrix-missing\">missing</td>"
+        ));
+    }
+
+    #[test]
+    fn test_summary_charts() {
+        let defect = |defect_type| crate::core::Defect {
+            defect_type,
+            description: String::new(),
+            item_id: None,
+            location: None,
+        };
+        let trace_result = TraceResult {
+            defect_count: 3,
+            defects: vec![
+                defect(crate::core::DefectType::OrphanedCoverage),
+                defect(crate::core::DefectType::UncoveredItem),
+                defect(crate::core::DefectType::UncoveredItem),
+            ],
+            coverage_summary: HashMap::from([(
+                "req".to_string(),
+                crate::core::CoverageSummary {
+                    total: 4,
+                    covered: 1,
+                    percentage: 25.0,
+                    status: CoverageStatus::Partial,
+                },
+            )]),
+            is_success: false,
+            ..TraceResult::from_items(vec![])
+        };
+
+        let messages = Messages::for_language(ReportLanguage::German);
+        let charts = HtmlReporter::summary_charts(&trace_result, messages);
+        assert_eq!(charts.len(), 2);
+        assert_eq!(charts[0].title, "Mängel nach Art");
+        let defects: Vec<_> = charts[0]
+            .bars
+            .iter()
+            .map(|bar| (bar.label.as_str(), bar.width))
+            .collect();
+        assert_eq!(defects, vec![("uncovered", 200.0), ("orphaned", 100.0)]);
+        let coverage = &charts[1].bars[0];
+        assert_eq!(coverage.value, "25.0% (1/4)");
+        assert_eq!(coverage.class, "chart-partial");
+        assert_eq!(charts[1].height, CHART_ROW_HEIGHT);
+    }
 }
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::core::{
+    CoverageSummary, Defect, DocumentSummary, GateResult, ImplementationMetrics,
+    LinkedSpecificationItem, ModuleSummary, ReleaseVerdict, ReportMetadata, TagSummary,
+    TraceResult,
+};
+use crate::Result;
+
+/// Version of the export schema as `major.minor`
+///
+/// Minor versions only add fields, so consumers written against an older minor
+/// version keep working; renaming, removing or retyping a field needs a new major.
+pub const SCHEMA_VERSION: &str = "1.11";
+
+/// JSON Schema of the export, also shipped as `schema/export.schema.json`
+pub const SCHEMA: &str = include_str!("../../schema/export.schema.json");
+
+/// Exports the complete trace as JSON
+///
+/// Besides the summary numbers, the export contains every linked item with
+/// its fields, location, coverage status and incoming and outgoing links, all
+/// serialized from the model types. The structure is described by [`SCHEMA`].
+pub struct JsonExporter;
+
+/// The exported document
+#[derive(Serialize)]
+pub(crate) struct JsonExport<'a> {
+    schema_version: &'static str,
+    metadata: &'a ReportMetadata,
+    total_items: usize,
+    defect_count: usize,
+    is_success: bool,
+    coverage_percentage: f64,
+    /// Sorted by artifact type, so that exports of the same trace are identical
+    coverage_summary: BTreeMap<&'a str, &'a CoverageSummary>,
+    defects: &'a [Defect],
+    warnings: &'a [Defect],
+    gate_results: &'a [GateResult],
+    modules: Vec<ModuleSummary>,
+    tags: Vec<TagSummary>,
+    documents: Vec<DocumentSummary>,
+    release_verdict: &'a ReleaseVerdict,
+    implementation_metrics: &'a BTreeMap<String, ImplementationMetrics>,
+    items: &'a [LinkedSpecificationItem],
+}
+
+impl JsonExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the trace result to a JSON file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result)?)?;
+        Ok(())
+    }
+
+    /// Render the trace result as a pretty-printed JSON document
+    pub fn render(&self, trace_result: &TraceResult) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.export(trace_result))?)
+    }
+
+    /// The exported document, also the data of custom HTML report templates
+    pub(crate) fn export<'a>(&self, trace_result: &'a TraceResult) -> JsonExport<'a> {
+        JsonExport {
+            schema_version: SCHEMA_VERSION,
+            metadata: &trace_result.metadata,
+            total_items: trace_result.total_items,
+            defect_count: trace_result.defect_count,
+            is_success: trace_result.is_success,
+            coverage_percentage: trace_result.coverage_percentage(),
+            coverage_summary: trace_result
+                .coverage_summary
+                .iter()
+                .map(|(artifact_type, summary)| (artifact_type.as_str(), summary))
+                .collect(),
+            defects: &trace_result.defects,
+            warnings: &trace_result.warnings,
+            gate_results: &trace_result.gate_results,
+            modules: trace_result.module_summaries(),
+            tags: trace_result.tag_summaries(),
+            documents: trace_result.document_summaries(),
+            release_verdict: &trace_result.release_verdict,
+            implementation_metrics: &trace_result.implementation_metrics,
+            items: &trace_result.items,
+        }
+    }
+}
+
+impl Default for JsonExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, Location, SpecificationItem, SpecificationItemId};
+
+    #[test]
+    fn test_items_and_links_are_exported() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .location(Location::new("docs/spec.md".into(), 3))
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(linked);
+
+        let json = JsonExporter::new().render(&trace_result).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["total_items"], 2);
+        let req = &value["items"][0];
+        assert_eq!(req["item"]["id"]["name"], "export");
+        assert_eq!(req["item"]["location"]["line"], 3);
+        assert_eq!(req["coverage_status"], "Covered");
+        assert_eq!(req["incoming_links"][0]["status"], "CoveredShallow");
+        assert_eq!(value["items"][1]["outgoing_links"][0]["status"], "Covers");
+    }
+
+    #[test]
+    fn test_schema_describes_export() {
+        let schema: serde_json::Value = serde_json::from_str(SCHEMA).unwrap();
+        assert_eq!(schema["properties"]["schema_version"]["const"], SCHEMA_VERSION);
+
+        let trace_result = TraceResult::from_items(vec![]);
+        let json = JsonExporter::new().render(&trace_result).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let fields: Vec<_> = value.as_object().unwrap().keys().collect();
+        let required: Vec<_> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field.as_str().unwrap())
+            .collect();
+        assert_eq!(fields.len(), required.len());
+        assert!(fields.iter().all(|field| required.contains(&field.as_str())));
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::core::{Defect, LinkedSpecificationItem, TraceResult};
+use crate::Result;
+
+/// Renders the trace as JUnit XML for CI test dashboards
+///
+/// Every specification item becomes a test case in the suite of its artifact
+/// type, and every defect of the item a failure. Tag gates form a suite of
+/// their own, so a failed gate shows up as a failed test as well.
+pub struct JunitReporter;
+
+/// A test case and the failures reported for it
+struct TestCase {
+    class_name: String,
+    name: String,
+    file: Option<String>,
+    failures: Vec<(String, String)>,
+}
+
+impl JunitReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the trace result to a JUnit XML file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result))?;
+        Ok(())
+    }
+
+    /// Render the trace result as a JUnit XML document
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let mut suites: BTreeMap<String, Vec<TestCase>> = BTreeMap::new();
+        let mut items: Vec<_> = trace_result.items.iter().collect();
+        items.sort_by_key(|item| item.id().to_string());
+        for item in items {
+            let defects = trace_result
+                .defects
+                .iter()
+                .filter(|defect| defect.item_id.as_ref() == Some(item.id()));
+            suites
+                .entry(item.id().artifact_type.clone())
+                .or_default()
+                .push(Self::item_case(item, defects));
+        }
+
+        // Defects that belong to no item, e.g. of the whole document set
+        let unassigned: Vec<_> = trace_result
+            .defects
+            .iter()
+            .filter(|defect| defect.item_id.is_none())
+            .map(Self::failure)
+            .collect();
+        if !unassigned.is_empty() {
+            suites.entry("trace".to_string()).or_default().push(TestCase {
+                class_name: "trace".to_string(),
+                name: "defects".to_string(),
+                file: None,
+                failures: unassigned,
+            });
+        }
+
+        for gate in &trace_result.gate_results {
+            suites.entry("tag-gates".to_string()).or_default().push(TestCase {
+                class_name: "tag-gates".to_string(),
+                name: gate.tag.clone(),
+                file: None,
+                failures: gate
+                    .violations
+                    .iter()
+                    .map(|violation| ("tag-gate".to_string(), violation.clone()))
+                    .collect(),
+            });
+        }
+
+        let count = |cases: &[TestCase]| {
+            let failed = cases.iter().filter(|case| !case.failures.is_empty()).count();
+            (cases.len(), failed)
+        };
+        let (tests, failures) = suites.values().fold((0, 0), |(tests, failures), cases| {
+            let (suite_tests, suite_failures) = count(cases);
+            (tests + suite_tests, failures + suite_failures)
+        });
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"traceability\" tests=\"{}\" failures=\"{}\">",
+            tests, failures
+        );
+        let metadata = Self::properties(trace_result);
+        for (name, cases) in &suites {
+            let (tests, failures) = count(cases);
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+                Self::escape(name),
+                tests,
+                failures
+            );
+            xml.push_str(&metadata);
+            for case in cases {
+                Self::write_case(&mut xml, case);
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    /// Report metadata as suite properties, since `testsuites` has none
+    fn properties(trace_result: &TraceResult) -> String {
+        let metadata = &trace_result.metadata;
+        if metadata.is_empty() {
+            return String::new();
+        }
+
+        let mut properties = String::from("    <properties>\n");
+        for (name, value) in [
+            ("ovft.version", metadata.tool_version.as_str()),
+            ("ovft.generated-at", metadata.generated_at.as_str()),
+            ("ovft.revision", metadata.revision.as_deref().unwrap_or("unknown")),
+            ("ovft.config-digest", metadata.config_digest.as_str()),
+        ] {
+            let _ = writeln!(
+                properties,
+                "      <property name=\"{}\" value=\"{}\"/>",
+                name,
+                Self::escape(value)
+            );
+        }
+        properties.push_str("    </properties>\n");
+        properties
+    }
+
+    fn item_case<'a>(
+        item: &LinkedSpecificationItem,
+        defects: impl Iterator<Item = &'a Defect>,
+    ) -> TestCase {
+        TestCase {
+            class_name: item.id().artifact_type.clone(),
+            name: item.id().to_string(),
+            file: item
+                .item
+                .location
+                .as_ref()
+                .map(|location| location.path.display().to_string()),
+            failures: defects.map(Self::failure).collect(),
+        }
+    }
+
+    fn failure(defect: &Defect) -> (String, String) {
+        (defect.defect_type.to_string(), defect.description.clone())
+    }
+
+    fn write_case(xml: &mut String, case: &TestCase) {
+        let _ = write!(
+            xml,
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            Self::escape(&case.class_name),
+            Self::escape(&case.name)
+        );
+        if let Some(file) = &case.file {
+            let _ = write!(xml, " file=\"{}\"", Self::escape(file));
+        }
+        if case.failures.is_empty() {
+            xml.push_str("/>\n");
+            return;
+        }
+
+        xml.push_str(">\n");
+        for (kind, message) in &case.failures {
+            let _ = writeln!(
+                xml,
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>",
+                Self::escape(kind),
+                Self::escape(message.lines().next().unwrap_or_default()),
+                Self::escape(message)
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    fn escape(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+impl Default for JunitReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DefectType, Linker, SpecificationItem, SpecificationItemId};
+
+    #[test]
+    fn test_items_become_test_cases() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req.clone())
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult {
+            defect_count: 1,
+            defects: vec![Defect {
+                defect_type: DefectType::UncoveredItem,
+                description: "Missing coverage: utest <& more>".to_string(),
+                item_id: Some(req),
+                location: None,
+            }],
+            is_success: false,
+            ..TraceResult::from_items(linked)
+        };
+
+        let xml = JunitReporter::new().render(&trace_result);
+        roxmltree::Document::parse(&xml).unwrap();
+        assert!(xml.contains("<testsuites name=\"traceability\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("<testcase classname=\"impl\" name=\"impl~export~1\"/>"));
+        assert!(xml.contains(
+            "<failure type=\"uncovered\" message=\"Missing coverage: utest &lt;&amp; more&gt;\">"
+        ));
+    }
+}
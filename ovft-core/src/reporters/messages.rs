@@ -0,0 +1,266 @@
+use crate::config::ReportLanguage;
+
+/// User-facing strings of the HTML and delta reports in one language
+///
+/// Data of the trace, like defect descriptions, item IDs and link statuses,
+/// is not translated, so that it can still be searched for across reports.
+#[derive(Debug)]
+pub struct Messages {
+    /// Language code of the HTML document, e.g. `en`
+    pub lang: &'static str,
+    pub report_title: &'static str,
+    pub overview: &'static str,
+    pub all_traced: &'static str,
+    pub issues_found: &'static str,
+    pub summary: &'static str,
+    pub total_items: &'static str,
+    pub defects: &'static str,
+    pub defect_breakdown: &'static str,
+    pub defects_by_type: &'static str,
+    pub coverage_by_artifact_type: &'static str,
+    pub tag_gates: &'static str,
+    pub release_readiness: &'static str,
+    pub blocking: &'static str,
+    pub waived: &'static str,
+    pub module_readiness: &'static str,
+    pub module: &'static str,
+    pub items: &'static str,
+    pub covered: &'static str,
+    pub coverage: &'static str,
+    pub open_defects: &'static str,
+    pub statuses: &'static str,
+    pub ready: &'static str,
+    pub coverage_by_tag: &'static str,
+    pub tag: &'static str,
+    pub coverage_by_document: &'static str,
+    pub document: &'static str,
+    pub coverage_by_needed_type: &'static str,
+    pub defects_found: &'static str,
+    pub warnings: &'static str,
+    pub suspect_links: &'static str,
+    pub covered_revision: &'static str,
+    pub go_to: &'static str,
+    pub view_item: &'static str,
+    pub copy_link: &'static str,
+    pub link_copied: &'static str,
+    pub pages: &'static str,
+    pub page: &'static str,
+    pub defective_items: &'static str,
+    pub traceability_matrix: &'static str,
+    pub item: &'static str,
+    pub missing: &'static str,
+    pub deep_coverage: &'static str,
+    pub deep: &'static str,
+    pub direct: &'static str,
+    pub covered_by: &'static str,
+    pub down_the_chain: &'static str,
+    pub coverage_tree: &'static str,
+    pub specification_items: &'static str,
+    pub rationale: &'static str,
+    pub comment: &'static str,
+    pub needs: &'static str,
+    pub location: &'static str,
+    pub implementation: &'static str,
+    pub files: &'static str,
+    pub tagged_locations: &'static str,
+    pub lines: &'static str,
+    pub covers: &'static str,
+    pub depends: &'static str,
+    pub outgoing_links: &'static str,
+    pub incoming_links: &'static str,
+    pub unknown: &'static str,
+    pub generated_by: &'static str,
+    pub generated_at: &'static str,
+    pub revision: &'static str,
+    pub configuration: &'static str,
+    pub delta_title: &'static str,
+    pub no_changes: &'static str,
+    pub new_defects: &'static str,
+    pub fixed_defects: &'static str,
+    pub added_items: &'static str,
+    pub removed_items: &'static str,
+    pub new_defect_count: &'static str,
+    pub fixed_defect_count: &'static str,
+    pub added_item_count: &'static str,
+    pub removed_item_count: &'static str,
+    pub artifact_type: &'static str,
+    pub before: &'static str,
+    pub after: &'static str,
+    pub change: &'static str,
+}
+
+impl Messages {
+    /// The message catalog of a language
+    pub fn for_language(language: ReportLanguage) -> &'static Self {
+        match language {
+            ReportLanguage::English => &ENGLISH,
+            ReportLanguage::German => &GERMAN,
+        }
+    }
+}
+
+static ENGLISH: Messages = Messages {
+    lang: "en",
+    report_title: "Requirements Tracing Report",
+    overview: "Overview",
+    all_traced: "All requirements properly traced",
+    issues_found: "Issues found in requirements tracing",
+    summary: "Summary",
+    total_items: "Total Items",
+    defects: "Defects",
+    defect_breakdown: "Defect Breakdown",
+    defects_by_type: "Defects by Type",
+    coverage_by_artifact_type: "Coverage by Artifact Type",
+    tag_gates: "Tag Gates",
+    release_readiness: "Release Readiness",
+    blocking: "Blocking",
+    waived: "Waived",
+    module_readiness: "Module Readiness",
+    module: "Module",
+    items: "Items",
+    covered: "Covered",
+    coverage: "Coverage",
+    open_defects: "Open Defects",
+    statuses: "Statuses",
+    ready: "Ready",
+    coverage_by_tag: "Coverage by Tag",
+    tag: "Tag",
+    coverage_by_document: "Coverage by Document",
+    document: "Document",
+    coverage_by_needed_type: "Coverage by Needed Type",
+    defects_found: "Defects Found",
+    warnings: "Warnings",
+    suspect_links: "Suspect Links to Review",
+    covered_revision: "Covered Revision",
+    go_to: "Go to",
+    view_item: "View Item",
+    copy_link: "Copy link to this item",
+    link_copied: "Link copied",
+    pages: "Pages",
+    page: "Page",
+    defective_items: "Defective Items",
+    traceability_matrix: "Traceability Matrix",
+    item: "Item",
+    missing: "missing",
+    deep_coverage: "Deep Coverage",
+    deep: "deep",
+    direct: "direct",
+    covered_by: "Covered by",
+    down_the_chain: "down the whole chain",
+    coverage_tree: "Coverage Tree",
+    specification_items: "Specification Items",
+    rationale: "Rationale",
+    comment: "Comment",
+    needs: "Needs",
+    location: "Location",
+    implementation: "Implementation",
+    files: "file(s)",
+    tagged_locations: "tagged location(s)",
+    lines: "line(s)",
+    covers: "Covers",
+    depends: "Depends",
+    outgoing_links: "Outgoing Links",
+    incoming_links: "Incoming Links",
+    unknown: "unknown",
+    generated_by: "Generated by",
+    generated_at: "at",
+    revision: "revision",
+    configuration: "configuration",
+    delta_title: "Traceability Delta",
+    no_changes: "No traceability changes.",
+    new_defects: "New Defects",
+    fixed_defects: "Fixed Defects",
+    added_items: "Added Items",
+    removed_items: "Removed Items",
+    new_defect_count: "new defect(s)",
+    fixed_defect_count: "fixed defect(s)",
+    added_item_count: "added item(s)",
+    removed_item_count: "removed item(s)",
+    artifact_type: "Artifact type",
+    before: "Before",
+    after: "After",
+    change: "Change",
+};
+
+static GERMAN: Messages = Messages {
+    lang: "de",
+    report_title: "Bericht zur Anforderungsverfolgung",
+    overview: "Übersicht",
+    all_traced: "Alle Anforderungen vollständig verfolgt",
+    issues_found: "Probleme bei der Anforderungsverfolgung gefunden",
+    summary: "Zusammenfassung",
+    total_items: "Elemente gesamt",
+    defects: "Mängel",
+    defect_breakdown: "Mängel nach Art",
+    defects_by_type: "Mängel nach Art",
+    coverage_by_artifact_type: "Abdeckung nach Artefakttyp",
+    tag_gates: "Tag-Schwellen",
+    release_readiness: "Freigabereife",
+    blocking: "Blockierend",
+    waived: "Ausgenommen",
+    module_readiness: "Modulreife",
+    module: "Modul",
+    items: "Elemente",
+    covered: "Abgedeckt",
+    coverage: "Abdeckung",
+    open_defects: "Offene Mängel",
+    statuses: "Status",
+    ready: "Bereit",
+    coverage_by_tag: "Abdeckung nach Tag",
+    tag: "Tag",
+    coverage_by_document: "Abdeckung nach Dokument",
+    document: "Dokument",
+    coverage_by_needed_type: "Abdeckung nach benötigtem Typ",
+    defects_found: "Gefundene Mängel",
+    warnings: "Warnungen",
+    suspect_links: "Zu prüfende verdächtige Verweise",
+    covered_revision: "Abgedeckte Revision",
+    go_to: "Gehe zu",
+    view_item: "Zum Element",
+    copy_link: "Link zu diesem Element kopieren",
+    link_copied: "Link kopiert",
+    pages: "Seiten",
+    page: "Seite",
+    defective_items: "Mangelhafte Elemente",
+    traceability_matrix: "Verfolgbarkeitsmatrix",
+    item: "Element",
+    missing: "fehlt",
+    deep_coverage: "Tiefe Abdeckung",
+    deep: "tief",
+    direct: "direkt",
+    covered_by: "Abgedeckt durch",
+    down_the_chain: "über die gesamte Kette",
+    coverage_tree: "Abdeckungsbaum",
+    specification_items: "Spezifikationselemente",
+    rationale: "Begründung",
+    comment: "Kommentar",
+    needs: "Benötigt",
+    location: "Fundstelle",
+    implementation: "Implementierung",
+    files: "Datei(en)",
+    tagged_locations: "markierte Stelle(n)",
+    lines: "Zeile(n)",
+    covers: "Deckt ab",
+    depends: "Hängt ab von",
+    outgoing_links: "Ausgehende Verweise",
+    incoming_links: "Eingehende Verweise",
+    unknown: "unbekannt",
+    generated_by: "Erstellt mit",
+    generated_at: "am",
+    revision: "Revision",
+    configuration: "Konfiguration",
+    delta_title: "Änderungen der Verfolgbarkeit",
+    no_changes: "Keine Änderungen der Verfolgbarkeit.",
+    new_defects: "Neue Mängel",
+    fixed_defects: "Behobene Mängel",
+    added_items: "Hinzugefügte Elemente",
+    removed_items: "Entfernte Elemente",
+    new_defect_count: "neue(r) Mangel/Mängel",
+    fixed_defect_count: "behobene(r) Mangel/Mängel",
+    added_item_count: "hinzugefügte(s) Element(e)",
+    removed_item_count: "entfernte(s) Element(e)",
+    artifact_type: "Artefakttyp",
+    before: "Vorher",
+    after: "Nachher",
+    change: "Änderung",
+};
@@ -1,3 +1,35 @@
+pub mod artifact_exporter;
+pub mod aspec_exporter;
+pub mod audit_writer;
+pub mod backlog_exporter;
+pub mod compliance_reporter;
+pub mod console_reporter;
+pub mod csv_exporter;
+pub mod delta_reporter;
+pub mod dot_exporter;
+pub mod github_reporter;
 pub mod html_reporter;
+pub mod json_exporter;
+pub mod junit_reporter;
+pub mod messages;
+pub mod plantuml_exporter;
+pub mod text_reporter;
+pub mod xlsx_exporter;
 
+pub use artifact_exporter::ArtifactExporter;
+pub use aspec_exporter::AspecExporter;
+pub use audit_writer::AuditWriter;
+pub use backlog_exporter::BacklogExporter;
+pub use compliance_reporter::ComplianceReporter;
+pub use console_reporter::{ColorChoice, ConsoleReporter};
+pub use csv_exporter::CsvExporter;
+pub use delta_reporter::DeltaReporter;
+pub use dot_exporter::DotExporter;
+pub use github_reporter::GithubReporter;
 pub use html_reporter::HtmlReporter;
+pub use json_exporter::JsonExporter;
+pub use junit_reporter::JunitReporter;
+pub use messages::Messages;
+pub use plantuml_exporter::PlantumlExporter;
+pub use text_reporter::{TextDetail, TextReporter};
+pub use xlsx_exporter::XlsxExporter;
@@ -0,0 +1,213 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::core::{
+    CoverageStatus, LinkStatus, LinkedSpecificationItem, SpecificationItemId, TraceResult,
+};
+use crate::Result;
+
+/// Exports the trace as a PlantUML diagram of items and their relations
+///
+/// Items are grouped in one package per artifact type and filled by coverage
+/// status. Covers relations point from the covering to the covered item and
+/// are colored by link status, depends relations are drawn dotted. Items that
+/// are linked to but not defined anywhere are outlined in red.
+pub struct PlantumlExporter;
+
+impl PlantumlExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the diagram to a PlantUML file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result))?;
+        Ok(())
+    }
+
+    /// Render the diagram as a PlantUML document
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let mut by_id: HashMap<&SpecificationItemId, &LinkedSpecificationItem> = HashMap::new();
+        for item in &trace_result.items {
+            by_id.entry(item.id()).or_insert(item);
+        }
+
+        // Defined and referenced items per artifact type, sorted for a stable output
+        let mut packages: BTreeMap<&str, BTreeMap<String, &SpecificationItemId>> = BTreeMap::new();
+        let mut relations = Vec::new();
+        let mut items: Vec<_> = trace_result.items.iter().collect();
+        items.sort_by_key(|item| item.id().to_string());
+        for item in items {
+            Self::add(&mut packages, item.id());
+            for link in &item.outgoing_links {
+                if matches!(link.status, LinkStatus::BrokenDependency) {
+                    continue;
+                }
+                Self::add(&mut packages, &link.target_id);
+                relations.push(Self::covers_relation(
+                    item.id(),
+                    &link.target_id,
+                    &link.status,
+                ));
+            }
+            for dependency in &item.item.depends {
+                Self::add(&mut packages, dependency);
+                let broken = item.outgoing_links.iter().any(|link| {
+                    matches!(link.status, LinkStatus::BrokenDependency)
+                        && &link.target_id == dependency
+                });
+                relations.push(format!(
+                    "{} .[{}].> {} : depends",
+                    Self::alias(item.id()),
+                    if broken { "#red" } else { "#gray" },
+                    Self::alias(dependency)
+                ));
+            }
+        }
+
+        let mut uml = String::from("@startuml\n");
+        uml.push_str("left to right direction\n");
+        uml.push_str("skinparam rectangle {\n  RoundCorner 10\n}\n");
+        for (artifact_type, ids) in &packages {
+            let _ = writeln!(uml, "package \"{}\" {{", artifact_type);
+            for id in ids.values() {
+                Self::write_item(&mut uml, id, by_id.get(id).copied());
+            }
+            uml.push_str("}\n");
+        }
+        for relation in relations {
+            uml.push_str(&relation);
+            uml.push('\n');
+        }
+        if !trace_result.metadata.is_empty() {
+            let _ = writeln!(uml, "footer {}", trace_result.metadata.summary());
+        }
+        uml.push_str("@enduml\n");
+        uml
+    }
+
+    fn add<'a>(
+        packages: &mut BTreeMap<&'a str, BTreeMap<String, &'a SpecificationItemId>>,
+        id: &'a SpecificationItemId,
+    ) {
+        packages
+            .entry(id.artifact_type.as_str())
+            .or_default()
+            .insert(id.to_string(), id);
+    }
+
+    fn write_item(
+        uml: &mut String,
+        id: &SpecificationItemId,
+        item: Option<&LinkedSpecificationItem>,
+    ) {
+        let Some(item) = item else {
+            let _ = writeln!(
+                uml,
+                "  rectangle \"{}\" as {} #white;line:red;line.dashed",
+                id,
+                Self::alias(id)
+            );
+            return;
+        };
+
+        let label = match &item.item.title {
+            Some(title) => format!("{}\\n{}", id, title.replace('"', "'")),
+            None => id.to_string(),
+        };
+        let color = match item.coverage_status {
+            CoverageStatus::Covered => "palegreen",
+            CoverageStatus::Partial => "khaki",
+            CoverageStatus::Uncovered => "lightcoral",
+        };
+        let _ = writeln!(
+            uml,
+            "  rectangle \"{}\" <<{}>> as {} #{}",
+            label,
+            id.artifact_type,
+            Self::alias(id),
+            color
+        );
+    }
+
+    fn covers_relation(
+        source: &SpecificationItemId,
+        target: &SpecificationItemId,
+        status: &LinkStatus,
+    ) -> String {
+        let color = match status {
+            LinkStatus::Covers => "#green",
+            LinkStatus::Outdated | LinkStatus::Predated => "#orange",
+            _ => "#red",
+        };
+        format!(
+            "{} -[{}]-> {} : {}",
+            Self::alias(source),
+            color,
+            Self::alias(target),
+            status
+        )
+    }
+
+    /// PlantUML aliases may only contain letters, digits and underscores
+    fn alias(id: &SpecificationItemId) -> String {
+        let name: String = id
+            .to_string()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("item_{}", name)
+    }
+}
+
+impl Default for PlantumlExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+
+    #[test]
+    fn test_covers_and_depends_relations() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .title("Export \"all\"".to_string())
+                .needs("impl".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .depends(SpecificationItemId::new(
+                "impl".to_string(),
+                "storage".to_string(),
+                1,
+            ))
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult::from_items(linked);
+
+        let uml = PlantumlExporter::new().render(&trace_result);
+        assert!(uml.starts_with("@startuml\n"));
+        assert!(uml.ends_with("@enduml\n"));
+        assert!(uml.contains(
+            "  rectangle \"req~export~1\\nExport 'all'\" <<req>> as item_req_export_1 #palegreen\n"
+        ));
+        assert!(uml.contains("item_impl_export_1 -[#green]-> item_req_export_1 : covers\n"));
+        assert!(uml.contains("item_impl_export_1 .[#red].> item_impl_storage_1 : depends\n"));
+        assert!(uml.contains("as item_impl_storage_1 #white;line:red;line.dashed\n"));
+    }
+}
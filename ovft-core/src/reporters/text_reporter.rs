@@ -0,0 +1,246 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::{LinkStatus, LinkedSpecificationItem, TraceResult};
+use crate::Result;
+
+/// How much of the trace the plain-text report shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextDetail {
+    /// Only the summary footer
+    Summary,
+    /// Defective items with their links, and the footer
+    #[default]
+    Failures,
+    /// All items with their links, and the footer
+    All,
+}
+
+impl FromStr for TextDetail {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "summary" => Ok(Self::Summary),
+            "failures" => Ok(Self::Failures),
+            "all" => Ok(Self::All),
+            _ => Err(crate::Error::Config(format!(
+                "Unknown report detail '{}' (expected summary, failures or all)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for TextDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Summary => "summary",
+            Self::Failures => "failures",
+            Self::All => "all",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Renders the trace as the plain-text tracing report of OpenFastTrace
+///
+/// Each item is shown as a line like `not ok - 0/1>0>1/2 - dsn~export~1 (impl, -utest)`:
+/// valid and total incoming links, duplicates, broken and total outgoing links,
+/// then the item and its needed artifact types, uncovered ones marked with `-`.
+pub struct TextReporter {
+    detail: TextDetail,
+}
+
+impl TextReporter {
+    /// Create a reporter showing the given level of detail
+    pub fn new(detail: TextDetail) -> Self {
+        Self { detail }
+    }
+
+    /// Render the report
+    pub fn render(&self, trace_result: &TraceResult) -> String {
+        let mut items: Vec<_> = trace_result
+            .items
+            .iter()
+            .filter(|item| match self.detail {
+                TextDetail::Summary => false,
+                TextDetail::Failures => item.is_defect,
+                TextDetail::All => true,
+            })
+            .collect();
+        items.sort_by_key(|item| item.id().to_string());
+
+        let mut report = String::new();
+        for item in items {
+            report.push_str(&Self::item_line(item));
+            report.push('\n');
+            for link in &item.incoming_links {
+                let source = link.source_id.as_ref().unwrap_or(&link.target_id);
+                report.push_str(&format!("  |<-- ({}) {}\n", link.status, source));
+            }
+            for link in &item.outgoing_links {
+                report.push_str(&format!("  |--> ({}) {}\n", link.status, link.target_id));
+            }
+        }
+
+        for tag in trace_result.tag_summaries() {
+            report.push_str(&format!(
+                "tag {}: {}/{} covered ({:.1}%), {} defect\n",
+                tag.tag, tag.covered, tag.total, tag.percentage, tag.open_defects
+            ));
+        }
+
+        for document in trace_result.document_summaries() {
+            report.push_str(&format!(
+                "document {}: {}/{} covered ({:.1}%), {} defect",
+                document.document,
+                document.covered,
+                document.total,
+                document.percentage,
+                document.open_defects
+            ));
+            if !document.coverage_by_type.is_empty() {
+                report.push_str(&format!(" ({})", document.coverage_by_type_overview()));
+            }
+            report.push('\n');
+        }
+
+        for suspect in trace_result.suspect_links() {
+            report.push_str(&format!("suspect {}\n", suspect));
+        }
+
+        if trace_result.defect_count == 0 {
+            report.push_str(&format!("ok - {} total\n", trace_result.total_items));
+        } else {
+            report.push_str(&format!(
+                "not ok - {} total, {} defect\n",
+                trace_result.total_items, trace_result.defect_count
+            ));
+        }
+        if !trace_result.metadata.is_empty() {
+            report.push_str(&format!("# {}\n", trace_result.metadata.summary()));
+        }
+        report
+    }
+
+    /// Write the report to a file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(output_path, self.render(trace_result))?;
+        Ok(())
+    }
+
+    fn item_line(item: &LinkedSpecificationItem) -> String {
+        let incoming_ok = item
+            .incoming_links
+            .iter()
+            .filter(|link| matches!(link.status, LinkStatus::CoveredShallow))
+            .count();
+        let duplicates = item
+            .outgoing_links
+            .iter()
+            .filter(|link| matches!(link.status, LinkStatus::Duplicate))
+            .count();
+        let outgoing: Vec<_> = item
+            .outgoing_links
+            .iter()
+            .filter(|link| !matches!(link.status, LinkStatus::Duplicate))
+            .collect();
+        let outgoing_bad = outgoing
+            .iter()
+            .filter(|link| !matches!(link.status, LinkStatus::Covers))
+            .count();
+
+        let missing = item.missing_coverage_types();
+        let needs: Vec<_> = item
+            .item
+            .needs
+            .iter()
+            .map(|needed| {
+                if missing.contains(needed) {
+                    format!("-{}", needed)
+                } else {
+                    needed.clone()
+                }
+            })
+            .collect();
+
+        format!(
+            "{} - {}/{}>{}>{}/{} - {} ({})",
+            if item.is_defect { "not ok" } else { "ok" },
+            incoming_ok,
+            item.incoming_links.len(),
+            duplicates,
+            outgoing_bad,
+            outgoing.len(),
+            item.id(),
+            needs.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem, SpecificationItemId};
+
+    fn trace_result() -> TraceResult {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+
+        TraceResult {
+            defect_count: linked.iter().filter(|item| item.is_defect).count(),
+            is_success: false,
+            ..TraceResult::from_items(linked)
+        }
+    }
+
+    #[test]
+    fn test_detail_levels() {
+        let trace_result = trace_result();
+
+        let summary = TextReporter::new(TextDetail::Summary).render(&trace_result);
+        assert_eq!(summary, "not ok - 2 total, 1 defect\n");
+
+        let failures = TextReporter::new(TextDetail::Failures).render(&trace_result);
+        assert!(failures.starts_with("not ok - 1/1>0>0/0 - req~export~1 (impl, -utest)\n"));
+        assert!(failures.contains("  |<-- (covered shallow) impl~export~1\n"));
+        assert!(!failures.contains("ok - 0/0>0>0/1 - impl~export~1"));
+
+        let all = TextReporter::new(TextDetail::All).render(&trace_result);
+        assert!(all.contains("ok - 0/0>0>0/1 - impl~export~1 ()\n"));
+        assert!(all.contains("  |--> (covers) req~export~1\n"));
+    }
+
+    #[test]
+    fn test_tag_coverage_lines() {
+        let mut trace_result = trace_result();
+        for item in &mut trace_result.items {
+            item.item.tags.push("security".to_string());
+        }
+
+        let summary = TextReporter::new(TextDetail::Summary).render(&trace_result);
+        assert_eq!(
+            summary,
+            "tag security: 1/2 covered (50.0%), 0 defect\nnot ok - 2 total, 1 defect\n"
+        );
+    }
+}
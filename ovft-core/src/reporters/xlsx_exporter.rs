@@ -0,0 +1,267 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use rust_xlsxwriter::{Color, DocProperties, Format, Workbook, Worksheet};
+
+use crate::core::{LinkStatus, LinkedSpecificationItem, SpecificationItemId, TraceResult};
+use crate::Result;
+
+/// Columns of the artifact type sheets
+const ITEM_COLUMNS: [&str; 13] = [
+    "ID",
+    "Title",
+    "Status",
+    "Description",
+    "Rationale",
+    "Needs",
+    "Covers",
+    "Depends",
+    "Tags",
+    "Module",
+    "Location",
+    "Coverage",
+    "Missing Coverage",
+];
+
+/// Exports the trace as an Excel workbook for audits
+///
+/// The `Matrix` sheet has one row per item that needs coverage and one column
+/// per needed artifact type, listing the covering items or `MISSING`. It is
+/// followed by one sheet per artifact type with all fields of its items.
+pub struct XlsxExporter;
+
+impl XlsxExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the workbook to a file
+    pub fn write(&self, trace_result: &TraceResult, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut items: Vec<_> = trace_result.items.iter().collect();
+        items.sort_by_key(|item| item.id().to_string());
+
+        let header = Format::new()
+            .set_bold()
+            .set_background_color(Color::RGB(0xD9D9D9));
+        let covered = Format::new().set_background_color(Color::RGB(0xC6EFCE));
+        let missing = Format::new()
+            .set_bold()
+            .set_background_color(Color::RGB(0xFFC7CE));
+
+        let mut workbook = Workbook::new();
+        let metadata = &trace_result.metadata;
+        if !metadata.is_empty() {
+            workbook.set_properties(
+                &DocProperties::new()
+                    .set_comment(metadata.summary())
+                    .set_custom_property("ovft version", &metadata.tool_version)
+                    .set_custom_property("Generated at", &metadata.generated_at)
+                    .set_custom_property(
+                        "Revision",
+                        metadata.revision.as_deref().unwrap_or("unknown"),
+                    )
+                    .set_custom_property("Configuration digest", &metadata.config_digest),
+            );
+        }
+        let matrix = workbook.add_worksheet();
+        matrix.set_name("Matrix")?;
+        Self::write_matrix(matrix, &items, &header, &covered, &missing)?;
+
+        let mut by_type: BTreeMap<&str, Vec<&LinkedSpecificationItem>> = BTreeMap::new();
+        for item in &items {
+            by_type
+                .entry(item.id().artifact_type.as_str())
+                .or_default()
+                .push(item);
+        }
+        for (artifact_type, items) in by_type {
+            let sheet = workbook.add_worksheet();
+            sheet.set_name(Self::sheet_name(artifact_type))?;
+            Self::write_items(sheet, &items, &header, &missing)?;
+        }
+
+        workbook.save(output_path)?;
+        Ok(())
+    }
+
+    fn write_matrix(
+        sheet: &mut Worksheet,
+        items: &[&LinkedSpecificationItem],
+        header: &Format,
+        covered: &Format,
+        missing: &Format,
+    ) -> Result<()> {
+        let needed_types: BTreeSet<&str> = items
+            .iter()
+            .flat_map(|item| item.item.needs.iter().map(String::as_str))
+            .collect();
+
+        let fixed = ["ID", "Title", "Status", "Coverage"];
+        for (col, title) in fixed.iter().chain(needed_types.iter()).enumerate() {
+            sheet.write_string_with_format(0, col as u16, *title, header)?;
+        }
+
+        let mut row = 0;
+        for item in items.iter().filter(|item| !item.item.needs.is_empty()) {
+            row += 1;
+            sheet.write_string(row, 0, item.id().to_string())?;
+            sheet.write_string(row, 1, item.item.title.clone().unwrap_or_default())?;
+            sheet.write_string(row, 2, item.item.status.to_string())?;
+            sheet.write_string(row, 3, item.coverage_status.to_string())?;
+            for (offset, needed_type) in needed_types.iter().enumerate() {
+                let col = (fixed.len() + offset) as u16;
+                if !item.item.needs.iter().any(|needed| needed == needed_type) {
+                    continue;
+                }
+                let covering = Self::covering_items(item, needed_type);
+                if covering.is_empty() {
+                    sheet.write_string_with_format(row, col, "MISSING", missing)?;
+                } else {
+                    sheet.write_string_with_format(row, col, covering.join("\n"), covered)?;
+                }
+            }
+        }
+
+        sheet.set_column_width(0, 40)?;
+        sheet.set_column_width(1, 40)?;
+        sheet.set_freeze_panes(1, 1)?;
+        sheet.autofilter(0, 0, row, (fixed.len() + needed_types.len() - 1) as u16)?;
+        Ok(())
+    }
+
+    fn write_items(
+        sheet: &mut Worksheet,
+        items: &[&LinkedSpecificationItem],
+        header: &Format,
+        missing: &Format,
+    ) -> Result<()> {
+        for (col, title) in ITEM_COLUMNS.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, *title, header)?;
+        }
+
+        let join_ids = |ids: &[SpecificationItemId]| {
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        for (index, linked_item) in items.iter().enumerate() {
+            let row = index as u32 + 1;
+            let item = &linked_item.item;
+            let values = [
+                item.id.to_string(),
+                item.title.clone().unwrap_or_default(),
+                item.status.to_string(),
+                item.description.clone().unwrap_or_default(),
+                item.rationale.clone().unwrap_or_default(),
+                item.needs.join(", "),
+                join_ids(&item.covers),
+                join_ids(&item.depends),
+                item.tags.join(", "),
+                item.module.clone().unwrap_or_default(),
+                item.location
+                    .as_ref()
+                    .map(|location| format!("{}:{}", location.path.display(), location.line))
+                    .unwrap_or_default(),
+                linked_item.coverage_status.to_string(),
+            ];
+            for (col, value) in values.into_iter().enumerate() {
+                sheet.write_string(row, col as u16, value)?;
+            }
+            let missing_types = linked_item.missing_coverage_types();
+            if !missing_types.is_empty() {
+                let col = (ITEM_COLUMNS.len() - 1) as u16;
+                sheet.write_string_with_format(row, col, missing_types.join(", "), missing)?;
+            }
+        }
+
+        sheet.set_column_width(0, 40)?;
+        sheet.set_column_width(1, 40)?;
+        sheet.set_column_width(3, 60)?;
+        sheet.set_freeze_panes(1, 1)?;
+        sheet.autofilter(0, 0, items.len() as u32, (ITEM_COLUMNS.len() - 1) as u16)?;
+        Ok(())
+    }
+
+    /// IDs of the items of the given type that validly cover an item
+    fn covering_items(item: &LinkedSpecificationItem, artifact_type: &str) -> Vec<String> {
+        item.incoming_links
+            .iter()
+            .filter(|link| matches!(link.status, LinkStatus::CoveredShallow))
+            .filter_map(|link| link.source_id.as_ref())
+            .filter(|source| item.satisfies_need(artifact_type, &source.artifact_type))
+            .map(|source| source.to_string())
+            .collect()
+    }
+
+    /// Excel sheet names are limited to 31 characters without `[]:*?/\`
+    fn sheet_name(artifact_type: &str) -> String {
+        artifact_type
+            .chars()
+            .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+            .take(31)
+            .collect()
+    }
+}
+
+impl Default for XlsxExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Linker, SpecificationItem};
+    use calamine::{open_workbook_auto, Reader};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_matrix_and_artifact_type_sheets() {
+        let req = SpecificationItemId::new("req".to_string(), "export".to_string(), 1);
+        let items = vec![
+            SpecificationItem::builder(req.clone())
+                .title("Export".to_string())
+                .needs("impl".to_string())
+                .needs("utest".to_string())
+                .build(),
+            SpecificationItem::builder(SpecificationItemId::new(
+                "impl".to_string(),
+                "export".to_string(),
+                1,
+            ))
+            .covers(req)
+            .build(),
+        ];
+        let linked = Linker::new().link_items(items).unwrap();
+        let trace_result = TraceResult {
+            defect_count: 1,
+            is_success: false,
+            ..TraceResult::from_items(linked)
+        };
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("trace.xlsx");
+        XlsxExporter::new().write(&trace_result, &path).unwrap();
+
+        let mut workbook = open_workbook_auto(&path).unwrap();
+        assert_eq!(workbook.sheet_names(), vec!["Matrix", "impl", "req"]);
+
+        let matrix = workbook.worksheet_range("Matrix").unwrap();
+        let cell = |row: u32, col: u32| matrix.get_value((row, col)).unwrap().to_string();
+        assert_eq!(cell(0, 4), "impl");
+        assert_eq!(cell(0, 5), "utest");
+        assert_eq!(cell(1, 0), "req~export~1");
+        assert_eq!(cell(1, 4), "impl~export~1");
+        assert_eq!(cell(1, 5), "MISSING");
+
+        let req_sheet = workbook.worksheet_range("req").unwrap();
+        assert_eq!(req_sheet.get_value((1, 1)).unwrap().to_string(), "Export");
+        assert_eq!(req_sheet.get_value((1, 12)).unwrap().to_string(), "utest");
+    }
+}
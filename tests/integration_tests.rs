@@ -1,4 +1,4 @@
-use open_very_fast_trace::{Config, Tracer};
+use open_very_fast_trace::{Config, SourceDir, Tracer};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tempfile::TempDir;
@@ -116,8 +116,8 @@ fn test_configuration_management() {
     // Validate configuration was preserved
     assert_eq!(loaded_config.source_dirs.len(), 2);
     assert_eq!(loaded_config.spec_dirs.len(), 2);
-    assert!(loaded_config.source_dirs.contains(&PathBuf::from("src")));
-    assert!(loaded_config.source_dirs.contains(&PathBuf::from("tests")));
+    assert!(loaded_config.source_dirs.contains(&SourceDir::new("src")));
+    assert!(loaded_config.source_dirs.contains(&SourceDir::new("tests")));
     assert!(loaded_config.spec_dirs.contains(&PathBuf::from("docs/requirements")));
     assert!(loaded_config.spec_dirs.contains(&PathBuf::from("docs/specs")));
 }